@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// Writes a zip at `out` from `(archive path, source path)` pairs, deflating every file.
+///
+/// Archive paths use `/` separators and must be relative without `..` components.
+/// If the call fails or times out, `out` is removed rather than left half-written
+/// (after a timeout, once the writer finishes its current entry).
+Future<void>  zipCreate({required List<(String,String)> files , required String out , BigInt? timeoutMs }) => RustLib.instance.api.crateArchiveZipCreate(files: files, out: out, timeoutMs: timeoutMs);
+
+/// Like `zip_create`, streaming progress after every entry.
+Stream<ArchiveProgress>  zipCreateWithProgress({required List<(String,String)> files , required String out , BigInt? timeoutMs }) => RustLib.instance.api.crateArchiveZipCreateWithProgress(files: files, out: out, timeoutMs: timeoutMs);
+
+/// Extracts every entry of `archive` under `dest`, creating directories as needed.
+///
+/// The whole archive is rejected with `InvalidInput`, before anything is written,
+/// if any entry would land outside `dest` (absolute paths, drive letters or `..`, i.e.
+/// zip-slip). After a timeout, extraction stops once the current entry is written;
+/// entries already extracted are left in place.
+Future<void>  zipExtract({required String archive , required String dest , BigInt? timeoutMs }) => RustLib.instance.api.crateArchiveZipExtract(archive: archive, dest: dest, timeoutMs: timeoutMs);
+
+/// Like `zip_extract`, streaming progress after every entry.
+Stream<ArchiveProgress>  zipExtractWithProgress({required String archive , required String dest , BigInt? timeoutMs }) => RustLib.instance.api.crateArchiveZipExtractWithProgress(archive: archive, dest: dest, timeoutMs: timeoutMs);
+
+            /// Entries written or extracted so far, reported after each one.
+class ArchiveProgress  {
+                final int entriesDone;
+final int entriesTotal;
+
+                const ArchiveProgress({required this.entriesDone ,required this.entriesTotal ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => entriesDone.hashCode^entriesTotal.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ArchiveProgress &&
+                runtimeType == other.runtimeType
+                && entriesDone == other.entriesDone&& entriesTotal == other.entriesTotal;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `get`, `is_expired`, `monotonic_ms`, `new`, `non_zero`, `put`, `stats`, `with_cache`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Cache`, `Entry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `eq`, `fmt`
+
+
+            /// Replaces the cache with an empty one holding at most `max_entries` values.
+///
+/// Entries expire `ttl_ms` after they were put; 0 means they never expire.
+void  cacheInit({required BigInt maxEntries , required BigInt ttlMs }) => RustLib.instance.api.crateCacheCacheInit(maxEntries: maxEntries, ttlMs: ttlMs);
+
+/// Stores `value`, evicting the least recently used entry if the cache is full.
+void  cachePut({required String key , required List<int> value }) => RustLib.instance.api.crateCacheCachePut(key: key, value: value);
+
+Uint8List?  cacheGet({required String key }) => RustLib.instance.api.crateCacheCacheGet(key: key);
+
+CacheStats  cacheStats() => RustLib.instance.api.crateCacheCacheStats();
+
+            /// Counters since the last `cache_init`.
+class CacheStats  {
+                final BigInt hits;
+final BigInt misses;
+/// Live entries; expired ones are not counted.
+final BigInt size;
+
+                const CacheStats({required this.hits ,required this.misses ,required this.size ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^misses.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CacheStats &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& misses == other.misses&& size == other.size;
+        
+            }
+            
\ No newline at end of file
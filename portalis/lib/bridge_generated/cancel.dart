@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `guarded`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `guard`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancelToken>>
+                abstract class CancelToken implements RustOpaqueInterface {
+                     void  cancel();
+
+
+static Future<CancelToken>  default_()=>RustLib.instance.api.crateCancelCancelTokenDefault();
+
+
+ bool  isCancelled();
+
+
+factory CancelToken()=>RustLib.instance.api.crateCancelCancelTokenNew();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,97 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `config_from_value`, `migrate_v1_to_v2`, `migrate`, `validate`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            AppConfig  parseConfig({required String json }) => RustLib.instance.api.crateCodecParseConfig(json: json);
+
+String  serializeConfig({required AppConfig cfg }) => RustLib.instance.api.crateCodecSerializeConfig(cfg: cfg);
+
+/// Loads a config file, picking the format from its extension: `.toml`, `.yaml`/`.yml`
+/// or `.json`. Older schema versions are migrated as in `parse_config`.
+///
+/// Not available on web; pass the JSON to `parse_config` instead.
+Future<AppConfig>  loadConfigFile({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateCodecLoadConfigFile(path: path, timeoutMs: timeoutMs);
+
+/// Emits the config at `path` now and again every time the file changes to a new
+/// valid config. Edits that fail to parse are logged and skipped, so the stream only
+/// ever carries usable configs.
+Stream<AppConfig>  watchConfig({required String path }) => RustLib.instance.api.crateCodecWatchConfig(path: path);
+
+/// Streams each element of the JSON array in `path`, holding only one element in memory.
+///
+/// A malformed element is emitted with `error` set (including its byte offset) and
+/// parsing resumes at the next top-level comma. If a broken element swallowed later
+/// ones (it fails to parse, or the file ends inside it), parsing resumes at the first
+/// line within it that starts a `{`/`[` at the element's own column after a comma.
+/// A file that isn't an array at all, or a read failure, ends the stream with an error.
+/// Not available on web.
+Stream<JsonArrayElement>  parseJsonArrayStream({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateCodecParseJsonArrayStream(path: path, timeoutMs: timeoutMs);
+
+            class AppConfig  {
+                final int schemaVersion;
+/// `light`, `dark` or `system`. Replaced the v1 `dark_mode` flag.
+final String theme;
+final String locale;
+final bool telemetryEnabled;
+final List<String> recentFiles;
+
+                const AppConfig({required this.schemaVersion ,required this.theme ,required this.locale ,required this.telemetryEnabled ,required this.recentFiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => schemaVersion.hashCode^theme.hashCode^locale.hashCode^telemetryEnabled.hashCode^recentFiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AppConfig &&
+                runtimeType == other.runtimeType
+                && schemaVersion == other.schemaVersion&& theme == other.theme&& locale == other.locale&& telemetryEnabled == other.telemetryEnabled&& recentFiles == other.recentFiles;
+        
+            }
+
+/// One element of the top-level array read by `parse_json_array_stream`.
+class JsonArrayElement  {
+                /// 0-based position in the array, counting malformed elements too.
+final BigInt index;
+/// Byte offset in the file where the element starts.
+final BigInt offset;
+/// The element re-serialized as compact JSON; empty when `error` is set.
+final String json;
+final String? error;
+
+                const JsonArrayElement({required this.index ,required this.offset ,required this.json ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^offset.hashCode^json.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JsonArrayElement &&
+                runtimeType == other.runtimeType
+                && index == other.index&& offset == other.offset&& json == other.json&& error == other.error;
+        
+            }
+            
\ No newline at end of file
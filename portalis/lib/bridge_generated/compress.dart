@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_level`, `corrupted`, `level_range`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            Future<Uint8List>  compress({required List<int> data , required CompressAlgo algo , required int level }) => RustLib.instance.api.crateCompressCompress(data: data, algo: algo, level: level);
+
+Future<Uint8List>  decompress({required List<int> data , required CompressAlgo algo }) => RustLib.instance.api.crateCompressDecompress(data: data, algo: algo);
+
+            enum CompressAlgo {
+                    /// Native only; web builds return `Unsupported` because zstd is a C library.
+zstd,
+gzip,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `below`
+
+
+            /// `len` bytes from the OS CSPRNG (`crypto.getRandomValues` on web).
+Uint8List  randomBytes({required BigInt len }) => RustLib.instance.api.crateCryptoRandomBytes(len: len);
+
+/// Unpadded URL-safe base64 of `len` random bytes, e.g. 32 for a session token.
+///
+/// `len` is the entropy in bytes; the string is about 4/3 as long.
+String  randomToken({required BigInt len }) => RustLib.instance.api.crateCryptoRandomToken(len: len);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SeededRng>>
+                abstract class SeededRng implements RustOpaqueInterface {
+                    static SeededRng  fromSeed({required BigInt seed })=>RustLib.instance.api.crateCryptoSeededRngFromSeed(seed: seed);
+
+
+/// Uniform in `[0, 1)`.
+ double  nextF64();
+
+
+ BigInt  nextU64();
+
+
+/// Returns `items` in a uniformly random order (Fisher-Yates).
+ List<String>  shuffle({required List<String> items });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
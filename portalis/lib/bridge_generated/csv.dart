@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Streams the records of a CSV file one at a time, so the whole file is never in memory.
+///
+/// Malformed records are emitted with `error` set and parsing carries on with the next
+/// one. Only failing to read the file ends the stream early, with an error.
+Stream<CsvRow>  parseCsvStream({required String path , required bool hasHeader , BigInt? timeoutMs }) => RustLib.instance.api.crateCsvParseCsvStream(path: path, hasHeader: hasHeader, timeoutMs: timeoutMs);
+
+            /// One record from `parse_csv_stream`.
+class CsvRow  {
+                /// 1-based line where the record starts; quoted fields may span several lines.
+final BigInt lineNumber;
+final List<String> fields;
+/// Set for the header record when `has_header` is true.
+final bool isHeader;
+/// Why the record couldn't be parsed (e.g. a different field count than the
+/// first record); `fields` is empty when this is set.
+final String? error;
+
+                const CsvRow({required this.lineNumber ,required this.fields ,required this.isHeader ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => lineNumber.hashCode^fields.hashCode^isHeader.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CsvRow &&
+                runtimeType == other.runtimeType
+                && lineNumber == other.lineNumber&& fields == other.fields&& isHeader == other.isHeader&& error == other.error;
+        
+            }
+            
\ No newline at end of file
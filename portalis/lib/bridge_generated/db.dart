@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'db.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `close`, `with_connection`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Opens (or reopens) the database file at `path`, creating it if needed.
+Future<void>  dbOpen({required String path }) => RustLib.instance.api.crateDbDbOpen(path: path);
+
+/// Runs a statement that returns no rows and reports how many rows it changed.
+Future<BigInt>  dbExecute({required String sql , required List<SqlValue> params }) => RustLib.instance.api.crateDbDbExecute(sql: sql, params: params);
+
+/// Runs a query and returns every row, with columns in `SELECT` order.
+Future<List<List<SqlValue>>>  dbQuery({required String sql , required List<SqlValue> params }) => RustLib.instance.api.crateDbDbQuery(sql: sql, params: params);
+
+            @freezed
+                sealed class SqlValue with _$SqlValue  {
+                    const SqlValue._();
+
+                     const factory SqlValue.null_() = SqlValue_Null;
+ const factory SqlValue.integer(  PlatformInt64 field0,) = SqlValue_Integer;
+ const factory SqlValue.real(  double field0,) = SqlValue_Real;
+ const factory SqlValue.text(  String field0,) = SqlValue_Text;
+ const factory SqlValue.blob(  Uint8List field0,) = SqlValue_Blob;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
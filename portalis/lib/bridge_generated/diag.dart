@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `collect`, `is_sensitive`, `redact_object`, `redact_text`, `redact`
+
+
+            /// Pretty JSON for support tickets: build and platform info, the last `DIAG_LOG_LINES`
+/// log records and the timing metrics, with sensitive values redacted.
+///
+/// Logs are only collected after `init_logging` has run.
+String  exportDiagnostics() => RustLib.instance.api.crateDiagExportDiagnostics();
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'diff.freezed.dart';
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// Diffs `old` against `new` line by line; deletions come before insertions in a change.
+///
+/// Sync for snappy small diffs: inputs up to roughly 1 MB or 10k lines finish in well
+/// under a frame. Larger documents should be diffed off the UI isolate.
+List<DiffOp>  diffLines({required String old , required String new_ }) => RustLib.instance.api.crateDiffDiffLines(old: old, new_: new_);
+
+            @freezed
+                sealed class DiffOp with _$DiffOp  {
+                    const DiffOp._();
+
+                     const factory DiffOp.equal(  String field0,) = DiffOp_Equal;
+ const factory DiffOp.insert(  String field0,) = DiffOp_Insert;
+ const factory DiffOp.delete(  String field0,) = DiffOp_Delete;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
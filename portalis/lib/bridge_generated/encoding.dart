@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `engine`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            String  base64Encode({required List<int> data , required Base64Variant variant }) => RustLib.instance.api.crateEncodingBase64Encode(data: data, variant: variant);
+
+Uint8List  base64Decode({required String s , required Base64Variant variant }) => RustLib.instance.api.crateEncodingBase64Decode(s: s, variant: variant);
+
+/// Lowercase hex, two characters per byte.
+String  hexEncode({required List<int> data }) => RustLib.instance.api.crateEncodingHexEncode(data: data);
+
+/// Accepts upper- or lowercase digits; the length must be even.
+Uint8List  hexDecode({required String s }) => RustLib.instance.api.crateEncodingHexDecode(s: s);
+
+            enum Base64Variant {
+                    /// RFC 4648 alphabet (`+`, `/`) with `=` padding.
+standard,
+/// URL- and filename-safe alphabet (`-`, `_`) without padding, as used in JWTs.
+urlSafe,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// Returns the variable's value, or `None` if it is unset, not valid UTF-8, or not
+/// on [`ALLOWED_ENV_VARS`]. Always `None` on web.
+String?  getEnv({required String name }) => RustLib.instance.api.crateEnvGetEnv(name: name);
+
+/// Per-user directory for app data (e.g. `~/.local/share/portalis` on Linux).
+///
+/// Empty on web or when the platform has no home directory.
+String  getDataDir() => RustLib.instance.api.crateEnvGetDataDir();
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `context`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `from`, `from`, `from`
+
+
+            
+
+            /// Flat `{code, message}` view of a [`PortalisError`] for logging or display.
+class ErrorInfo  {
+                final String code;
+final String message;
+
+                const ErrorInfo({required this.code ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ErrorInfo &&
+                runtimeType == other.runtimeType
+                && code == other.code&& message == other.message;
+        
+            }
+
+@freezed
+                sealed class PortalisError with _$PortalisError implements FrbException {
+                    const PortalisError._();
+
+                     const factory PortalisError.io(  String field0,) = PortalisError_Io;
+ const factory PortalisError.invalidInput(  String field0,) = PortalisError_InvalidInput;
+ const factory PortalisError.notFound(  String field0,) = PortalisError_NotFound;
+ const factory PortalisError.internal(  String field0,) = PortalisError_Internal;
+ /// The operation is not available on this platform (e.g. filesystem on web).
+const factory PortalisError.unsupported(  String field0,) = PortalisError_Unsupported;
+ /// Connecting, sending or receiving over the network failed.
+const factory PortalisError.network(  String field0,) = PortalisError_Network;
+ const factory PortalisError.cancelled(  String field0,) = PortalisError_Cancelled;
+ /// The OS refused access to a file or directory.
+const factory PortalisError.permissionDenied(  String field0,) = PortalisError_PermissionDenied;
+ /// Stored data failed an integrity check (e.g. checksum mismatch).
+const factory PortalisError.corrupted(  String field0,) = PortalisError_Corrupted;
+ /// The operation took longer than the `timeout_ms` its caller allowed.
+const factory PortalisError.timeout(  String field0,) = PortalisError_Timeout;
+
+                    
+
+                     String  code()=>RustLib.instance.api.crateErrorPortalisErrorCode(that: this, );
+
+
+ ErrorInfo  info()=>RustLib.instance.api.crateErrorPortalisErrorInfo(that: this, );
+
+
+                }
+            
\ No newline at end of file
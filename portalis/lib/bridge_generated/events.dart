@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'events.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `bus`, `forward`, `publish`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Streams every event published from now on until Dart cancels the subscription.
+Stream<AppEvent>  subscribeEvents() => RustLib.instance.api.crateEventsSubscribeEvents();
+
+            @freezed
+                sealed class AppEvent with _$AppEvent  {
+                    const AppEvent._();
+
+                     /// A `download_file` call ended, successfully or not.
+const factory AppEvent.downloadFinished({   required String url ,  required String dest ,  required bool succeeded , }) = AppEvent_DownloadFinished;
+ /// `watch_config` delivered a new config read from `path`.
+const factory AppEvent.configReloaded({   required String path , }) = AppEvent_ConfigReloaded;
+ /// A background job from `submit_job` completed or failed.
+const factory AppEvent.jobFinished({   required BigInt id ,  required bool succeeded , }) = AppEvent_JobFinished;
+ /// The subscriber fell behind and `skipped` older events were dropped.
+const factory AppEvent.lagged({   required BigInt skipped , }) = AppEvent_Lagged;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
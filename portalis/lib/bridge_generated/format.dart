@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply`, `for_locale`, `round_tenth`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Grouping`
+
+
+            /// Human-readable size: `1.5 MiB` with `binary` (powers of 1024), else `1.5 MB` (powers of 1000).
+///
+/// Sizes below one kilobyte are exact (`512 B`); larger ones show at most one decimal.
+String  formatBytes({required BigInt n , required bool binary }) => RustLib.instance.api.crateFormatFormatBytes(n: n, binary: binary);
+
+/// Formats `n` with the digit grouping of `locale` (e.g. `en-US`, `de_DE`, `fr`).
+///
+/// Only the language and region matter; unknown locales fall back to `en` (`1,234,567`).
+String  formatNumber({required PlatformInt64 n , required String locale }) => RustLib.instance.api.crateFormatFormatNumber(n: n, locale: locale);
+
+            
+            
\ No newline at end of file
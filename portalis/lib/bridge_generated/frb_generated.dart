@@ -3,210 +3,5082 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'archive.dart';
+import 'cache.dart';
+import 'cancel.dart';
+import 'codec.dart';
+import 'compress.dart';
+import 'crypto.dart';
+import 'csv.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'db.dart';
+import 'diag.dart';
+import 'diff.dart';
+import 'encoding.dart';
+import 'env.dart';
+import 'error.dart';
+import 'events.dart';
+import 'format.dart';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'fs.dart';
+import 'hash.dart';
+import 'ids.dart';
+import 'image.dart';
+import 'jobs.dart';
+import 'kv.dart';
 import 'lib.dart';
+import 'lifecycle.dart';
+import 'logging.dart';
+import 'metrics.dart';
+import 'net.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pipeline.dart';
+import 'platform.dart';
+import 'progress.dart';
+import 'queue.dart';
+import 'regex.dart';
+import 'resources.dart';
+import 'runtime.dart';
+import 'search.dart';
+import 'state.dart';
+import 'time.dart';
+import 'watch.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 75514378;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'backend',
-    ioDirectory: 'rust/backend/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  String crateGetVersion();
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  String crateGetVersion() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateGetVersionConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateGetVersionConstMeta => const TaskConstMeta(
-        debugName: "get_version",
-        argNames: [],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -2046043445;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'backend',
+                    ioDirectory: 'rust/backend/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  void crateCancelCancelTokenCancel({required CancelToken that });
+
+Future<CancelToken> crateCancelCancelTokenDefault();
+
+bool crateCancelCancelTokenIsCancelled({required CancelToken that });
+
+CancelToken crateCancelCancelTokenNew();
+
+String crateHashHasherFinalize({required Hasher that });
+
+Hasher crateHashHasherNew({required HashAlgo algo });
+
+void crateHashHasherUpdate({required Hasher that , required List<int> data });
+
+SeededRng crateCryptoSeededRngFromSeed({required BigInt seed });
+
+double crateCryptoSeededRngNextF64({required SeededRng that });
+
+BigInt crateCryptoSeededRngNextU64({required SeededRng that });
+
+List<String> crateCryptoSeededRngShuffle({required SeededRng that , required List<String> items });
+
+Future<void> cratePipelineTransformHandleFeed({required TransformHandle that , required List<int> chunk });
+
+Future<void> cratePipelineTransformHandleFinish({required TransformHandle that });
+
+Stream<Uint8List> cratePipelineTransformHandleOutput({required TransformHandle that });
+
+Uint8List crateEncodingBase64Decode({required String s , required Base64Variant variant });
+
+String crateEncodingBase64Encode({required List<int> data , required Base64Variant variant });
+
+Uint8List? crateCacheCacheGet({required String key });
+
+void crateCacheCacheInit({required BigInt maxEntries , required BigInt ttlMs });
+
+void crateCacheCachePut({required String key , required List<int> value });
+
+CacheStats crateCacheCacheStats();
+
+bool crateJobsCancelJob({required BigInt id });
+
+Future<bool> crateNetCheckReachable({required String url , required int timeoutMs });
+
+Future<Uint8List> crateCompressCompress({required List<int> data , required CompressAlgo algo , required int level });
+
+Stream<CopyProgress> crateFsCopyFileWithProgress({required String src , required String dst , BigInt? timeoutMs });
+
+PlatformInt64 crateStateCounterGet({required String name });
+
+PlatformInt64 crateStateCounterIncr({required String name , required PlatformInt64 by });
+
+Stream<LogRecord> crateLoggingCreateLogStream();
+
+Future<BigInt> crateDbDbExecute({required String sql , required List<SqlValue> params });
+
+Future<void> crateDbDbOpen({required String path });
+
+Future<List<List<SqlValue>>> crateDbDbQuery({required String sql , required List<SqlValue> params });
+
+Future<Uint8List> crateCompressDecompress({required List<int> data , required CompressAlgo algo });
+
+List<DiffOp> crateDiffDiffLines({required String old , required String new_ });
+
+Stream<DownloadEvent> crateNetDownloadFile({required String url , required String dest , required bool resume , BigInt? expectedSize , ExpectedHash? expectedHash , BigInt? timeoutMs , required CancelToken token });
+
+String crateDiagExportDiagnostics();
+
+List<MetricSummary> crateMetricsExportMetrics();
+
+Future<DirEntry> crateFsFileMetadata({required String path , BigInt? timeoutMs });
+
+bool crateStateFlagGet({required String name });
+
+void crateStateFlagSet({required String name , required bool value });
+
+String crateFormatFormatBytes({required BigInt n , required bool binary });
+
+String crateFormatFormatNumber({required PlatformInt64 n , required String locale });
+
+String crateTimeFormatTimestamp({required PlatformInt64 unixMs , required String fmt , required String tz });
+
+BuildInfo crateGetBuildInfo();
+
+String crateEnvGetDataDir();
+
+String? crateEnvGetEnv({required String name });
+
+PlatformInfo cratePlatformGetPlatformInfo();
+
+ResourceStats crateResourcesGetResourceStats();
+
+String crateGetVersion();
+
+String crateHashHashBytes({required HashAlgo algo , required List<int> data });
+
+Future<String> crateHashHashFile({required String path , required HashAlgo algo , BigInt? timeoutMs });
+
+Uint8List crateEncodingHexDecode({required String s });
+
+String crateEncodingHexEncode({required List<int> data });
+
+Future<HttpResponse> crateNetHttpGet({required String url , required List<(String,String)> headers , BigInt? timeoutMs , required CancelToken token });
+
+Future<HttpResponse> crateNetHttpGetRetry({required String url , required List<(String,String)> headers , required RetryPolicy policy , BigInt? timeoutMs , required CancelToken token });
+
+Future<HttpResponse> crateNetHttpPost({required String url , required List<(String,String)> headers , required List<int> body , BigInt? timeoutMs , required CancelToken token });
+
+Future<void> crateSearchIndexAdd({required String id , required String text });
+
+Future<void> crateSearchIndexCommit();
+
+Future<void> crateSearchIndexOpen({required String path });
+
+void crateLoggingInitLogging({required LogLevel level });
+
+bool crateIdsIsValidUuid({required String s });
+
+Stream<JobEvent> crateJobsJobEvents();
+
+void crateKvKvDelete({required String key });
+
+Uint8List? crateKvKvGet({required String key });
+
+List<Uint8List?> crateKvKvGetBatch({required List<String> keys });
+
+Future<void> crateKvKvOpen({required String path });
+
+Future<void> crateKvKvOpenEncrypted({required String path , required String passphrase });
+
+List<(String,Uint8List)> crateKvKvPrefixScan({required String prefix });
+
+void crateKvKvSet({required String key , required List<int> value });
+
+void crateKvKvSetBatch({required List<(String,Uint8List)> entries });
+
+Future<List<DirEntry>> crateFsListDir({required String path , BigInt? timeoutMs });
+
+Future<AppConfig> crateCodecLoadConfigFile({required String path , BigInt? timeoutMs });
+
+Future<void> crateFsMakeDirAll({required String path , BigInt? timeoutMs });
+
+Future<Uint8List> crateImageMakeThumbnail({required List<int> input , required int maxDim , required ImageFormat format , BigInt? timeoutMs });
+
+String crateIdsNewUuidV4();
+
+String crateIdsNewUuidV7();
+
+PlatformInt64 crateTimeNowUnixMs();
+
+TransformHandle cratePipelineOpenTransform({required TransformOp op });
+
+Future<List<(String,String)>> crateHashParallelHashFiles({required List<String> paths , required HashAlgo algo });
+
+AppConfig crateCodecParseConfig({required String json });
+
+Stream<CsvRow> crateCsvParseCsvStream({required String path , required bool hasHeader , BigInt? timeoutMs });
+
+Stream<JsonArrayElement> crateCodecParseJsonArrayStream({required String path , BigInt? timeoutMs });
+
+PlatformInt64 crateTimeParseTimestamp({required String s , required String fmt });
+
+String crateErrorPortalisErrorCode({required PortalisError that });
+
+ErrorInfo crateErrorPortalisErrorInfo({required PortalisError that });
+
+Future<void> crateQueueQueueAck({required BigInt id });
+
+Stream<QueuedOp> crateQueueQueueDrain();
+
+Future<BigInt> crateQueueQueueEnqueue({required QueuedOp op });
+
+Future<void> crateQueueQueueOpen({required String path });
+
+Future<QueuedOp?> crateQueueQueuePeek();
+
+Uint8List crateCryptoRandomBytes({required BigInt len });
+
+String crateCryptoRandomToken({required BigInt len });
+
+Future<Uint8List> crateFsReadFile({required String path , BigInt? timeoutMs });
+
+Stream<Uint8List> crateFsReadFileChunked({required String path , required BigInt chunkSize , BigInt? timeoutMs });
+
+Future<Uint8List> crateFsReadFileVerified({required String path , BigInt? timeoutMs });
+
+Future<List<String>> crateFsReadLines({required String path , required BigInt startLine , required BigInt maxLines , BigInt? timeoutMs });
+
+void crateMetricsRecordTiming({required String name , required double millis });
+
+Future<List<RegexMatch>> crateRegexRegexFindAll({required String pattern , required String text });
+
+Future<String> crateRegexRegexReplaceAll({required String pattern , required String text , required String replacement });
+
+Future<List<String>> crateNetResolveHost({required String host , BigInt? timeoutMs });
+
+Future<ResourceStats> crateResourcesResourceStatsDefault();
+
+Stream<OperationProgress> crateProgressRunImport({required String path , BigInt? timeoutMs });
+
+void crateRuntimeRuntimeInit({required int workerThreads });
+
+bool crateRuntimeRuntimeShutdown();
+
+Future<List<SearchHit>> crateSearchSearchQuery({required String query , required int limit });
+
+String crateCodecSerializeConfig({required AppConfig cfg });
+
+Future<void> crateLifecycleShutdownAll();
+
+BigInt crateJobsSubmitJob({required JobSpec job });
+
+Stream<AppEvent> crateEventsSubscribeEvents();
+
+Stream<String> crateFsTailFile({required String path });
+
+Stream<AppConfig> crateCodecWatchConfig({required String path });
+
+Stream<WatchEvent> crateWatchWatchPath({required String path , required bool recursive , required BigInt debounceMs });
+
+Future<void> crateFsWriteFile({required String path , required List<int> bytes , BigInt? timeoutMs });
+
+Future<void> crateFsWriteFileAtomic({required String path , required List<int> bytes , required bool verify , BigInt? timeoutMs });
+
+Future<void> crateArchiveZipCreate({required List<(String,String)> files , required String out , BigInt? timeoutMs });
+
+Stream<ArchiveProgress> crateArchiveZipCreateWithProgress({required List<(String,String)> files , required String out , BigInt? timeoutMs });
+
+Future<void> crateArchiveZipExtract({required String archive , required String dest , BigInt? timeoutMs });
+
+Stream<ArchiveProgress> crateArchiveZipExtractWithProgress({required String archive , required String dest , BigInt? timeoutMs });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CancelToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CancelToken;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancelTokenPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SeededRng;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SeededRng;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SeededRngPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TransformHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TransformHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TransformHandlePtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override void crateCancelCancelTokenCancel({required CancelToken that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCancelCancelTokenCancelConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancelCancelTokenCancelConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_cancel",
+            argNames: ["that"],
+        );
+        
+
+@override Future<CancelToken> crateCancelCancelTokenDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCancelCancelTokenDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancelCancelTokenDefaultConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_default",
+            argNames: [],
+        );
+        
+
+@override bool crateCancelCancelTokenIsCancelled({required CancelToken that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCancelCancelTokenIsCancelledConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancelCancelTokenIsCancelledConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_is_cancelled",
+            argNames: ["that"],
+        );
+        
+
+@override CancelToken crateCancelCancelTokenNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCancelCancelTokenNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancelCancelTokenNewConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_new",
+            argNames: [],
+        );
+        
+
+@override String crateHashHasherFinalize({required Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Hasher crateHashHasherNew({required HashAlgo algo })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algo(algo, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashHasherNewConstMeta,
+            argValues: [algo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Hasher_new",
+            argNames: ["algo"],
+        );
+        
+
+@override void crateHashHasherUpdate({required Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override SeededRng crateCryptoSeededRngFromSeed({required BigInt seed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(seed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCryptoSeededRngFromSeedConstMeta,
+            argValues: [seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCryptoSeededRngFromSeedConstMeta => const TaskConstMeta(
+            debugName: "SeededRng_from_seed",
+            argNames: ["seed"],
+        );
+        
+
+@override double crateCryptoSeededRngNextF64({required SeededRng that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCryptoSeededRngNextF64ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCryptoSeededRngNextF64ConstMeta => const TaskConstMeta(
+            debugName: "SeededRng_next_f64",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateCryptoSeededRngNextU64({required SeededRng that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCryptoSeededRngNextU64ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCryptoSeededRngNextU64ConstMeta => const TaskConstMeta(
+            debugName: "SeededRng_next_u64",
+            argNames: ["that"],
+        );
+        
+
+@override List<String> crateCryptoSeededRngShuffle({required SeededRng that , required List<String> items })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(that, serializer);
+sse_encode_list_String(items, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCryptoSeededRngShuffleConstMeta,
+            argValues: [that, items],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCryptoSeededRngShuffleConstMeta => const TaskConstMeta(
+            debugName: "SeededRng_shuffle",
+            argNames: ["that", "items"],
+        );
+        
+
+@override Future<void> cratePipelineTransformHandleFeed({required TransformHandle that , required List<int> chunk })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(that, serializer);
+sse_encode_list_prim_u_8_loose(chunk, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCratePipelineTransformHandleFeedConstMeta,
+            argValues: [that, chunk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePipelineTransformHandleFeedConstMeta => const TaskConstMeta(
+            debugName: "TransformHandle_feed",
+            argNames: ["that", "chunk"],
+        );
+        
+
+@override Future<void> cratePipelineTransformHandleFinish({required TransformHandle that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCratePipelineTransformHandleFinishConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePipelineTransformHandleFinishConstMeta => const TaskConstMeta(
+            debugName: "TransformHandle_finish",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<Uint8List> cratePipelineTransformHandleOutput({required TransformHandle that })  { 
+            final sink = RustStreamSink<Uint8List>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(that, serializer);
+sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCratePipelineTransformHandleOutputConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCratePipelineTransformHandleOutputConstMeta => const TaskConstMeta(
+            debugName: "TransformHandle_output",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Uint8List crateEncodingBase64Decode({required String s , required Base64Variant variant })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(s, serializer);
+sse_encode_base_64_variant(variant, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateEncodingBase64DecodeConstMeta,
+            argValues: [s, variant],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncodingBase64DecodeConstMeta => const TaskConstMeta(
+            debugName: "base64_decode",
+            argNames: ["s", "variant"],
+        );
+        
+
+@override String crateEncodingBase64Encode({required List<int> data , required Base64Variant variant })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_base_64_variant(variant, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEncodingBase64EncodeConstMeta,
+            argValues: [data, variant],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncodingBase64EncodeConstMeta => const TaskConstMeta(
+            debugName: "base64_encode",
+            argNames: ["data", "variant"],
+        );
+        
+
+@override Uint8List? crateCacheCacheGet({required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCacheCacheGetConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCacheCacheGetConstMeta => const TaskConstMeta(
+            debugName: "cache_get",
+            argNames: ["key"],
+        );
+        
+
+@override void crateCacheCacheInit({required BigInt maxEntries , required BigInt ttlMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(maxEntries, serializer);
+sse_encode_u_64(ttlMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCacheCacheInitConstMeta,
+            argValues: [maxEntries, ttlMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCacheCacheInitConstMeta => const TaskConstMeta(
+            debugName: "cache_init",
+            argNames: ["maxEntries", "ttlMs"],
+        );
+        
+
+@override void crateCacheCachePut({required String key , required List<int> value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+sse_encode_list_prim_u_8_loose(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCacheCachePutConstMeta,
+            argValues: [key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCacheCachePutConstMeta => const TaskConstMeta(
+            debugName: "cache_put",
+            argNames: ["key", "value"],
+        );
+        
+
+@override CacheStats crateCacheCacheStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cache_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCacheCacheStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCacheCacheStatsConstMeta => const TaskConstMeta(
+            debugName: "cache_stats",
+            argNames: [],
+        );
+        
+
+@override bool crateJobsCancelJob({required BigInt id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateJobsCancelJobConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJobsCancelJobConstMeta => const TaskConstMeta(
+            debugName: "cancel_job",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateNetCheckReachable({required String url , required int timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_u_32(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateNetCheckReachableConstMeta,
+            argValues: [url, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateNetCheckReachableConstMeta => const TaskConstMeta(
+            debugName: "check_reachable",
+            argNames: ["url", "timeoutMs"],
+        );
+        
+
+@override Future<Uint8List> crateCompressCompress({required List<int> data , required CompressAlgo algo , required int level })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_compress_algo(algo, serializer);
+sse_encode_i_32(level, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCompressCompressConstMeta,
+            argValues: [data, algo, level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCompressCompressConstMeta => const TaskConstMeta(
+            debugName: "compress",
+            argNames: ["data", "algo", "level"],
+        );
+        
+
+@override Stream<CopyProgress> crateFsCopyFileWithProgress({required String src , required String dst , BigInt? timeoutMs })  { 
+            final sink = RustStreamSink<CopyProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(src, serializer);
+sse_encode_String(dst, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_StreamSink_copy_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsCopyFileWithProgressConstMeta,
+            argValues: [src, dst, timeoutMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateFsCopyFileWithProgressConstMeta => const TaskConstMeta(
+            debugName: "copy_file_with_progress",
+            argNames: ["src", "dst", "timeoutMs", "sink"],
+        );
+        
+
+@override PlatformInt64 crateStateCounterGet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateStateCounterGetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStateCounterGetConstMeta => const TaskConstMeta(
+            debugName: "counter_get",
+            argNames: ["name"],
+        );
+        
+
+@override PlatformInt64 crateStateCounterIncr({required String name , required PlatformInt64 by })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_i_64(by, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateStateCounterIncrConstMeta,
+            argValues: [name, by],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStateCounterIncrConstMeta => const TaskConstMeta(
+            debugName: "counter_incr",
+            argNames: ["name", "by"],
+        );
+        
+
+@override Stream<LogRecord> crateLoggingCreateLogStream()  { 
+            final sink = RustStreamSink<LogRecord>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_log_record_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateLoggingCreateLogStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateLoggingCreateLogStreamConstMeta => const TaskConstMeta(
+            debugName: "create_log_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<BigInt> crateDbDbExecute({required String sql , required List<SqlValue> params })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sql, serializer);
+sse_encode_list_sql_value(params, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateDbDbExecuteConstMeta,
+            argValues: [sql, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateDbDbExecuteConstMeta => const TaskConstMeta(
+            debugName: "db_execute",
+            argNames: ["sql", "params"],
+        );
+        
+
+@override Future<void> crateDbDbOpen({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateDbDbOpenConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateDbDbOpenConstMeta => const TaskConstMeta(
+            debugName: "db_open",
+            argNames: ["path"],
+        );
+        
+
+@override Future<List<List<SqlValue>>> crateDbDbQuery({required String sql , required List<SqlValue> params })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sql, serializer);
+sse_encode_list_sql_value(params, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_sql_value,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateDbDbQueryConstMeta,
+            argValues: [sql, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateDbDbQueryConstMeta => const TaskConstMeta(
+            debugName: "db_query",
+            argNames: ["sql", "params"],
+        );
+        
+
+@override Future<Uint8List> crateCompressDecompress({required List<int> data , required CompressAlgo algo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_compress_algo(algo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCompressDecompressConstMeta,
+            argValues: [data, algo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCompressDecompressConstMeta => const TaskConstMeta(
+            debugName: "decompress",
+            argNames: ["data", "algo"],
+        );
+        
+
+@override List<DiffOp> crateDiffDiffLines({required String old , required String new_ })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(old, serializer);
+sse_encode_String(new_, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_diff_op,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateDiffDiffLinesConstMeta,
+            argValues: [old, new_],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateDiffDiffLinesConstMeta => const TaskConstMeta(
+            debugName: "diff_lines",
+            argNames: ["old", "new_"],
+        );
+        
+
+@override Stream<DownloadEvent> crateNetDownloadFile({required String url , required String dest , required bool resume , BigInt? expectedSize , ExpectedHash? expectedHash , BigInt? timeoutMs , required CancelToken token })  { 
+            final sink = RustStreamSink<DownloadEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(dest, serializer);
+sse_encode_bool(resume, serializer);
+sse_encode_opt_box_autoadd_u_64(expectedSize, serializer);
+sse_encode_opt_box_autoadd_expected_hash(expectedHash, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(token, serializer);
+sse_encode_StreamSink_download_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateNetDownloadFileConstMeta,
+            argValues: [url, dest, resume, expectedSize, expectedHash, timeoutMs, token, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateNetDownloadFileConstMeta => const TaskConstMeta(
+            debugName: "download_file",
+            argNames: ["url", "dest", "resume", "expectedSize", "expectedHash", "timeoutMs", "token", "sink"],
+        );
+        
+
+@override String crateDiagExportDiagnostics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateDiagExportDiagnosticsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateDiagExportDiagnosticsConstMeta => const TaskConstMeta(
+            debugName: "export_diagnostics",
+            argNames: [],
+        );
+        
+
+@override List<MetricSummary> crateMetricsExportMetrics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_metric_summary,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMetricsExportMetricsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMetricsExportMetricsConstMeta => const TaskConstMeta(
+            debugName: "export_metrics",
+            argNames: [],
+        );
+        
+
+@override Future<DirEntry> crateFsFileMetadata({required String path , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dir_entry,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsFileMetadataConstMeta,
+            argValues: [path, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsFileMetadataConstMeta => const TaskConstMeta(
+            debugName: "file_metadata",
+            argNames: ["path", "timeoutMs"],
+        );
+        
+
+@override bool crateStateFlagGet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateStateFlagGetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStateFlagGetConstMeta => const TaskConstMeta(
+            debugName: "flag_get",
+            argNames: ["name"],
+        );
+        
+
+@override void crateStateFlagSet({required String name , required bool value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_bool(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateStateFlagSetConstMeta,
+            argValues: [name, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStateFlagSetConstMeta => const TaskConstMeta(
+            debugName: "flag_set",
+            argNames: ["name", "value"],
+        );
+        
+
+@override String crateFormatFormatBytes({required BigInt n , required bool binary })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(n, serializer);
+sse_encode_bool(binary, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateFormatFormatBytesConstMeta,
+            argValues: [n, binary],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFormatFormatBytesConstMeta => const TaskConstMeta(
+            debugName: "format_bytes",
+            argNames: ["n", "binary"],
+        );
+        
+
+@override String crateFormatFormatNumber({required PlatformInt64 n , required String locale })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(n, serializer);
+sse_encode_String(locale, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateFormatFormatNumberConstMeta,
+            argValues: [n, locale],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFormatFormatNumberConstMeta => const TaskConstMeta(
+            debugName: "format_number",
+            argNames: ["n", "locale"],
+        );
+        
+
+@override String crateTimeFormatTimestamp({required PlatformInt64 unixMs , required String fmt , required String tz })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(unixMs, serializer);
+sse_encode_String(fmt, serializer);
+sse_encode_String(tz, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateTimeFormatTimestampConstMeta,
+            argValues: [unixMs, fmt, tz],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateTimeFormatTimestampConstMeta => const TaskConstMeta(
+            debugName: "format_timestamp",
+            argNames: ["unixMs", "fmt", "tz"],
+        );
+        
+
+@override BuildInfo crateGetBuildInfo()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_build_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateGetBuildInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateGetBuildInfoConstMeta => const TaskConstMeta(
+            debugName: "get_build_info",
+            argNames: [],
+        );
+        
+
+@override String crateEnvGetDataDir()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEnvGetDataDirConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEnvGetDataDirConstMeta => const TaskConstMeta(
+            debugName: "get_data_dir",
+            argNames: [],
+        );
+        
+
+@override String? crateEnvGetEnv({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEnvGetEnvConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEnvGetEnvConstMeta => const TaskConstMeta(
+            debugName: "get_env",
+            argNames: ["name"],
+        );
+        
+
+@override PlatformInfo cratePlatformGetPlatformInfo()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_platform_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePlatformGetPlatformInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePlatformGetPlatformInfoConstMeta => const TaskConstMeta(
+            debugName: "get_platform_info",
+            argNames: [],
+        );
+        
+
+@override ResourceStats crateResourcesGetResourceStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resource_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateResourcesGetResourceStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateResourcesGetResourceStatsConstMeta => const TaskConstMeta(
+            debugName: "get_resource_stats",
+            argNames: [],
+        );
+        
+
+@override String crateGetVersion()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateGetVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateGetVersionConstMeta => const TaskConstMeta(
+            debugName: "get_version",
+            argNames: [],
+        );
+        
+
+@override String crateHashHashBytes({required HashAlgo algo , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algo(algo, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashHashBytesConstMeta,
+            argValues: [algo, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashHashBytesConstMeta => const TaskConstMeta(
+            debugName: "hash_bytes",
+            argNames: ["algo", "data"],
+        );
+        
+
+@override Future<String> crateHashHashFile({required String path , required HashAlgo algo , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_hash_algo(algo, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateHashHashFileConstMeta,
+            argValues: [path, algo, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashHashFileConstMeta => const TaskConstMeta(
+            debugName: "hash_file",
+            argNames: ["path", "algo", "timeoutMs"],
+        );
+        
+
+@override Uint8List crateEncodingHexDecode({required String s })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(s, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateEncodingHexDecodeConstMeta,
+            argValues: [s],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncodingHexDecodeConstMeta => const TaskConstMeta(
+            debugName: "hex_decode",
+            argNames: ["s"],
+        );
+        
+
+@override String crateEncodingHexEncode({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEncodingHexEncodeConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncodingHexEncodeConstMeta => const TaskConstMeta(
+            debugName: "hex_encode",
+            argNames: ["data"],
+        );
+        
+
+@override Future<HttpResponse> crateNetHttpGet({required String url , required List<(String,String)> headers , BigInt? timeoutMs , required CancelToken token })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_list_record_string_string(headers, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(token, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_http_response,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateNetHttpGetConstMeta,
+            argValues: [url, headers, timeoutMs, token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateNetHttpGetConstMeta => const TaskConstMeta(
+            debugName: "http_get",
+            argNames: ["url", "headers", "timeoutMs", "token"],
+        );
+        
+
+@override Future<HttpResponse> crateNetHttpGetRetry({required String url , required List<(String,String)> headers , required RetryPolicy policy , BigInt? timeoutMs , required CancelToken token })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_list_record_string_string(headers, serializer);
+sse_encode_box_autoadd_retry_policy(policy, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(token, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_http_response,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateNetHttpGetRetryConstMeta,
+            argValues: [url, headers, policy, timeoutMs, token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateNetHttpGetRetryConstMeta => const TaskConstMeta(
+            debugName: "http_get_retry",
+            argNames: ["url", "headers", "policy", "timeoutMs", "token"],
+        );
+        
+
+@override Future<HttpResponse> crateNetHttpPost({required String url , required List<(String,String)> headers , required List<int> body , BigInt? timeoutMs , required CancelToken token })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_list_record_string_string(headers, serializer);
+sse_encode_list_prim_u_8_loose(body, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(token, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_http_response,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateNetHttpPostConstMeta,
+            argValues: [url, headers, body, timeoutMs, token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateNetHttpPostConstMeta => const TaskConstMeta(
+            debugName: "http_post",
+            argNames: ["url", "headers", "body", "timeoutMs", "token"],
+        );
+        
+
+@override Future<void> crateSearchIndexAdd({required String id , required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateSearchIndexAddConstMeta,
+            argValues: [id, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSearchIndexAddConstMeta => const TaskConstMeta(
+            debugName: "index_add",
+            argNames: ["id", "text"],
+        );
+        
+
+@override Future<void> crateSearchIndexCommit()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateSearchIndexCommitConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSearchIndexCommitConstMeta => const TaskConstMeta(
+            debugName: "index_commit",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateSearchIndexOpen({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateSearchIndexOpenConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSearchIndexOpenConstMeta => const TaskConstMeta(
+            debugName: "index_open",
+            argNames: ["path"],
+        );
+        
+
+@override void crateLoggingInitLogging({required LogLevel level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_log_level(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateLoggingInitLoggingConstMeta,
+            argValues: [level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateLoggingInitLoggingConstMeta => const TaskConstMeta(
+            debugName: "init_logging",
+            argNames: ["level"],
+        );
+        
+
+@override bool crateIdsIsValidUuid({required String s })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(s, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateIdsIsValidUuidConstMeta,
+            argValues: [s],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateIdsIsValidUuidConstMeta => const TaskConstMeta(
+            debugName: "is_valid_uuid",
+            argNames: ["s"],
+        );
+        
+
+@override Stream<JobEvent> crateJobsJobEvents()  { 
+            final sink = RustStreamSink<JobEvent>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_job_event_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateJobsJobEventsConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateJobsJobEventsConstMeta => const TaskConstMeta(
+            debugName: "job_events",
+            argNames: ["sink"],
+        );
+        
+
+@override void crateKvKvDelete({required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvDeleteConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvDeleteConstMeta => const TaskConstMeta(
+            debugName: "kv_delete",
+            argNames: ["key"],
+        );
+        
+
+@override Uint8List? crateKvKvGet({required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvGetConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvGetConstMeta => const TaskConstMeta(
+            debugName: "kv_get",
+            argNames: ["key"],
+        );
+        
+
+@override List<Uint8List?> crateKvKvGetBatch({required List<String> keys })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(keys, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvGetBatchConstMeta,
+            argValues: [keys],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvGetBatchConstMeta => const TaskConstMeta(
+            debugName: "kv_get_batch",
+            argNames: ["keys"],
+        );
+        
+
+@override Future<void> crateKvKvOpen({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvOpenConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvOpenConstMeta => const TaskConstMeta(
+            debugName: "kv_open",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateKvKvOpenEncrypted({required String path , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_String(passphrase, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvOpenEncryptedConstMeta,
+            argValues: [path, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvOpenEncryptedConstMeta => const TaskConstMeta(
+            debugName: "kv_open_encrypted",
+            argNames: ["path", "passphrase"],
+        );
+        
+
+@override List<(String,Uint8List)> crateKvKvPrefixScan({required String prefix })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(prefix, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_record_string_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvPrefixScanConstMeta,
+            argValues: [prefix],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvPrefixScanConstMeta => const TaskConstMeta(
+            debugName: "kv_prefix_scan",
+            argNames: ["prefix"],
+        );
+        
+
+@override void crateKvKvSet({required String key , required List<int> value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(key, serializer);
+sse_encode_list_prim_u_8_loose(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvSetConstMeta,
+            argValues: [key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvSetConstMeta => const TaskConstMeta(
+            debugName: "kv_set",
+            argNames: ["key", "value"],
+        );
+        
+
+@override void crateKvKvSetBatch({required List<(String,Uint8List)> entries })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_string_list_prim_u_8_strict(entries, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateKvKvSetBatchConstMeta,
+            argValues: [entries],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKvKvSetBatchConstMeta => const TaskConstMeta(
+            debugName: "kv_set_batch",
+            argNames: ["entries"],
+        );
+        
+
+@override Future<List<DirEntry>> crateFsListDir({required String path , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_dir_entry,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsListDirConstMeta,
+            argValues: [path, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsListDirConstMeta => const TaskConstMeta(
+            debugName: "list_dir",
+            argNames: ["path", "timeoutMs"],
+        );
+        
+
+@override Future<AppConfig> crateCodecLoadConfigFile({required String path , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_app_config,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCodecLoadConfigFileConstMeta,
+            argValues: [path, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCodecLoadConfigFileConstMeta => const TaskConstMeta(
+            debugName: "load_config_file",
+            argNames: ["path", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateFsMakeDirAll({required String path , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsMakeDirAllConstMeta,
+            argValues: [path, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsMakeDirAllConstMeta => const TaskConstMeta(
+            debugName: "make_dir_all",
+            argNames: ["path", "timeoutMs"],
+        );
+        
+
+@override Future<Uint8List> crateImageMakeThumbnail({required List<int> input , required int maxDim , required ImageFormat format , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(input, serializer);
+sse_encode_u_32(maxDim, serializer);
+sse_encode_image_format(format, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateImageMakeThumbnailConstMeta,
+            argValues: [input, maxDim, format, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateImageMakeThumbnailConstMeta => const TaskConstMeta(
+            debugName: "make_thumbnail",
+            argNames: ["input", "maxDim", "format", "timeoutMs"],
+        );
+        
+
+@override String crateIdsNewUuidV4()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateIdsNewUuidV4ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateIdsNewUuidV4ConstMeta => const TaskConstMeta(
+            debugName: "new_uuid_v4",
+            argNames: [],
+        );
+        
+
+@override String crateIdsNewUuidV7()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateIdsNewUuidV7ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateIdsNewUuidV7ConstMeta => const TaskConstMeta(
+            debugName: "new_uuid_v7",
+            argNames: [],
+        );
+        
+
+@override PlatformInt64 crateTimeNowUnixMs()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateTimeNowUnixMsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateTimeNowUnixMsConstMeta => const TaskConstMeta(
+            debugName: "now_unix_ms",
+            argNames: [],
+        );
+        
+
+@override TransformHandle cratePipelineOpenTransform({required TransformOp op })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_transform_op(op, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCratePipelineOpenTransformConstMeta,
+            argValues: [op],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePipelineOpenTransformConstMeta => const TaskConstMeta(
+            debugName: "open_transform",
+            argNames: ["op"],
+        );
+        
+
+@override Future<List<(String,String)>> crateHashParallelHashFiles({required List<String> paths , required HashAlgo algo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(paths, serializer);
+sse_encode_hash_algo(algo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_record_string_string,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateHashParallelHashFilesConstMeta,
+            argValues: [paths, algo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashParallelHashFilesConstMeta => const TaskConstMeta(
+            debugName: "parallel_hash_files",
+            argNames: ["paths", "algo"],
+        );
+        
+
+@override AppConfig crateCodecParseConfig({required String json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_app_config,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCodecParseConfigConstMeta,
+            argValues: [json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCodecParseConfigConstMeta => const TaskConstMeta(
+            debugName: "parse_config",
+            argNames: ["json"],
+        );
+        
+
+@override Stream<CsvRow> crateCsvParseCsvStream({required String path , required bool hasHeader , BigInt? timeoutMs })  { 
+            final sink = RustStreamSink<CsvRow>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_bool(hasHeader, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_StreamSink_csv_row_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCsvParseCsvStreamConstMeta,
+            argValues: [path, hasHeader, timeoutMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateCsvParseCsvStreamConstMeta => const TaskConstMeta(
+            debugName: "parse_csv_stream",
+            argNames: ["path", "hasHeader", "timeoutMs", "sink"],
+        );
+        
+
+@override Stream<JsonArrayElement> crateCodecParseJsonArrayStream({required String path , BigInt? timeoutMs })  { 
+            final sink = RustStreamSink<JsonArrayElement>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_StreamSink_json_array_element_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCodecParseJsonArrayStreamConstMeta,
+            argValues: [path, timeoutMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateCodecParseJsonArrayStreamConstMeta => const TaskConstMeta(
+            debugName: "parse_json_array_stream",
+            argNames: ["path", "timeoutMs", "sink"],
+        );
+        
+
+@override PlatformInt64 crateTimeParseTimestamp({required String s , required String fmt })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(s, serializer);
+sse_encode_String(fmt, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateTimeParseTimestampConstMeta,
+            argValues: [s, fmt],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateTimeParseTimestampConstMeta => const TaskConstMeta(
+            debugName: "parse_timestamp",
+            argNames: ["s", "fmt"],
+        );
+        
+
+@override String crateErrorPortalisErrorCode({required PortalisError that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_portalis_error(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateErrorPortalisErrorCodeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateErrorPortalisErrorCodeConstMeta => const TaskConstMeta(
+            debugName: "portalis_error_code",
+            argNames: ["that"],
+        );
+        
+
+@override ErrorInfo crateErrorPortalisErrorInfo({required PortalisError that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_portalis_error(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_error_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateErrorPortalisErrorInfoConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateErrorPortalisErrorInfoConstMeta => const TaskConstMeta(
+            debugName: "portalis_error_info",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateQueueQueueAck({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateQueueQueueAckConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateQueueQueueAckConstMeta => const TaskConstMeta(
+            debugName: "queue_ack",
+            argNames: ["id"],
+        );
+        
+
+@override Stream<QueuedOp> crateQueueQueueDrain()  { 
+            final sink = RustStreamSink<QueuedOp>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_queued_op_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateQueueQueueDrainConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateQueueQueueDrainConstMeta => const TaskConstMeta(
+            debugName: "queue_drain",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<BigInt> crateQueueQueueEnqueue({required QueuedOp op })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_queued_op(op, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateQueueQueueEnqueueConstMeta,
+            argValues: [op],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateQueueQueueEnqueueConstMeta => const TaskConstMeta(
+            debugName: "queue_enqueue",
+            argNames: ["op"],
+        );
+        
+
+@override Future<void> crateQueueQueueOpen({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateQueueQueueOpenConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateQueueQueueOpenConstMeta => const TaskConstMeta(
+            debugName: "queue_open",
+            argNames: ["path"],
+        );
+        
+
+@override Future<QueuedOp?> crateQueueQueuePeek()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_queued_op,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateQueueQueuePeekConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateQueueQueuePeekConstMeta => const TaskConstMeta(
+            debugName: "queue_peek",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateCryptoRandomBytes({required BigInt len })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(len, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCryptoRandomBytesConstMeta,
+            argValues: [len],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCryptoRandomBytesConstMeta => const TaskConstMeta(
+            debugName: "random_bytes",
+            argNames: ["len"],
+        );
+        
+
+@override String crateCryptoRandomToken({required BigInt len })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(len, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCryptoRandomTokenConstMeta,
+            argValues: [len],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCryptoRandomTokenConstMeta => const TaskConstMeta(
+            debugName: "random_token",
+            argNames: ["len"],
+        );
+        
+
+@override Future<Uint8List> crateFsReadFile({required String path , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsReadFileConstMeta,
+            argValues: [path, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsReadFileConstMeta => const TaskConstMeta(
+            debugName: "read_file",
+            argNames: ["path", "timeoutMs"],
+        );
+        
+
+@override Stream<Uint8List> crateFsReadFileChunked({required String path , required BigInt chunkSize , BigInt? timeoutMs })  { 
+            final sink = RustStreamSink<Uint8List>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_usize(chunkSize, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsReadFileChunkedConstMeta,
+            argValues: [path, chunkSize, timeoutMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateFsReadFileChunkedConstMeta => const TaskConstMeta(
+            debugName: "read_file_chunked",
+            argNames: ["path", "chunkSize", "timeoutMs", "sink"],
+        );
+        
+
+@override Future<Uint8List> crateFsReadFileVerified({required String path , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsReadFileVerifiedConstMeta,
+            argValues: [path, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsReadFileVerifiedConstMeta => const TaskConstMeta(
+            debugName: "read_file_verified",
+            argNames: ["path", "timeoutMs"],
+        );
+        
+
+@override Future<List<String>> crateFsReadLines({required String path , required BigInt startLine , required BigInt maxLines , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_u_64(startLine, serializer);
+sse_encode_u_64(maxLines, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsReadLinesConstMeta,
+            argValues: [path, startLine, maxLines, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsReadLinesConstMeta => const TaskConstMeta(
+            debugName: "read_lines",
+            argNames: ["path", "startLine", "maxLines", "timeoutMs"],
+        );
+        
+
+@override void crateMetricsRecordTiming({required String name , required double millis })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_f_64(millis, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMetricsRecordTimingConstMeta,
+            argValues: [name, millis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMetricsRecordTimingConstMeta => const TaskConstMeta(
+            debugName: "record_timing",
+            argNames: ["name", "millis"],
+        );
+        
+
+@override Future<List<RegexMatch>> crateRegexRegexFindAll({required String pattern , required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pattern, serializer);
+sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_regex_match,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateRegexRegexFindAllConstMeta,
+            argValues: [pattern, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRegexRegexFindAllConstMeta => const TaskConstMeta(
+            debugName: "regex_find_all",
+            argNames: ["pattern", "text"],
+        );
+        
+
+@override Future<String> crateRegexRegexReplaceAll({required String pattern , required String text , required String replacement })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pattern, serializer);
+sse_encode_String(text, serializer);
+sse_encode_String(replacement, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateRegexRegexReplaceAllConstMeta,
+            argValues: [pattern, text, replacement],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRegexRegexReplaceAllConstMeta => const TaskConstMeta(
+            debugName: "regex_replace_all",
+            argNames: ["pattern", "text", "replacement"],
+        );
+        
+
+@override Future<List<String>> crateNetResolveHost({required String host , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateNetResolveHostConstMeta,
+            argValues: [host, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateNetResolveHostConstMeta => const TaskConstMeta(
+            debugName: "resolve_host",
+            argNames: ["host", "timeoutMs"],
+        );
+        
+
+@override Future<ResourceStats> crateResourcesResourceStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resource_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateResourcesResourceStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateResourcesResourceStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "resource_stats_default",
+            argNames: [],
+        );
+        
+
+@override Stream<OperationProgress> crateProgressRunImport({required String path , BigInt? timeoutMs })  { 
+            final sink = RustStreamSink<OperationProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_StreamSink_operation_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateProgressRunImportConstMeta,
+            argValues: [path, timeoutMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateProgressRunImportConstMeta => const TaskConstMeta(
+            debugName: "run_import",
+            argNames: ["path", "timeoutMs", "sink"],
+        );
+        
+
+@override void crateRuntimeRuntimeInit({required int workerThreads })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(workerThreads, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateRuntimeRuntimeInitConstMeta,
+            argValues: [workerThreads],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRuntimeRuntimeInitConstMeta => const TaskConstMeta(
+            debugName: "runtime_init",
+            argNames: ["workerThreads"],
+        );
+        
+
+@override bool crateRuntimeRuntimeShutdown()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateRuntimeRuntimeShutdownConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRuntimeRuntimeShutdownConstMeta => const TaskConstMeta(
+            debugName: "runtime_shutdown",
+            argNames: [],
+        );
+        
+
+@override Future<List<SearchHit>> crateSearchSearchQuery({required String query , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_search_hit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateSearchSearchQueryConstMeta,
+            argValues: [query, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSearchSearchQueryConstMeta => const TaskConstMeta(
+            debugName: "search_query",
+            argNames: ["query", "limit"],
+        );
+        
+
+@override String crateCodecSerializeConfig({required AppConfig cfg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_app_config(cfg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCodecSerializeConfigConstMeta,
+            argValues: [cfg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCodecSerializeConfigConstMeta => const TaskConstMeta(
+            debugName: "serialize_config",
+            argNames: ["cfg"],
+        );
+        
+
+@override Future<void> crateLifecycleShutdownAll()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateLifecycleShutdownAllConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateLifecycleShutdownAllConstMeta => const TaskConstMeta(
+            debugName: "shutdown_all",
+            argNames: [],
+        );
+        
+
+@override BigInt crateJobsSubmitJob({required JobSpec job })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_job_spec(job, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateJobsSubmitJobConstMeta,
+            argValues: [job],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJobsSubmitJobConstMeta => const TaskConstMeta(
+            debugName: "submit_job",
+            argNames: ["job"],
+        );
+        
+
+@override Stream<AppEvent> crateEventsSubscribeEvents()  { 
+            final sink = RustStreamSink<AppEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_app_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateEventsSubscribeEventsConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateEventsSubscribeEventsConstMeta => const TaskConstMeta(
+            debugName: "subscribe_events",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<String> crateFsTailFile({required String path })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsTailFileConstMeta,
+            argValues: [path, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateFsTailFileConstMeta => const TaskConstMeta(
+            debugName: "tail_file",
+            argNames: ["path", "sink"],
+        );
+        
+
+@override Stream<AppConfig> crateCodecWatchConfig({required String path })  { 
+            final sink = RustStreamSink<AppConfig>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_StreamSink_app_config_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateCodecWatchConfigConstMeta,
+            argValues: [path, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateCodecWatchConfigConstMeta => const TaskConstMeta(
+            debugName: "watch_config",
+            argNames: ["path", "sink"],
+        );
+        
+
+@override Stream<WatchEvent> crateWatchWatchPath({required String path , required bool recursive , required BigInt debounceMs })  { 
+            final sink = RustStreamSink<WatchEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_bool(recursive, serializer);
+sse_encode_u_64(debounceMs, serializer);
+sse_encode_StreamSink_watch_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateWatchWatchPathConstMeta,
+            argValues: [path, recursive, debounceMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateWatchWatchPathConstMeta => const TaskConstMeta(
+            debugName: "watch_path",
+            argNames: ["path", "recursive", "debounceMs", "sink"],
+        );
+        
+
+@override Future<void> crateFsWriteFile({required String path , required List<int> bytes , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_prim_u_8_loose(bytes, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsWriteFileConstMeta,
+            argValues: [path, bytes, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsWriteFileConstMeta => const TaskConstMeta(
+            debugName: "write_file",
+            argNames: ["path", "bytes", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateFsWriteFileAtomic({required String path , required List<int> bytes , required bool verify , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_prim_u_8_loose(bytes, serializer);
+sse_encode_bool(verify, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateFsWriteFileAtomicConstMeta,
+            argValues: [path, bytes, verify, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFsWriteFileAtomicConstMeta => const TaskConstMeta(
+            debugName: "write_file_atomic",
+            argNames: ["path", "bytes", "verify", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateArchiveZipCreate({required List<(String,String)> files , required String out , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_string_string(files, serializer);
+sse_encode_String(out, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateArchiveZipCreateConstMeta,
+            argValues: [files, out, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateArchiveZipCreateConstMeta => const TaskConstMeta(
+            debugName: "zip_create",
+            argNames: ["files", "out", "timeoutMs"],
+        );
+        
+
+@override Stream<ArchiveProgress> crateArchiveZipCreateWithProgress({required List<(String,String)> files , required String out , BigInt? timeoutMs })  { 
+            final sink = RustStreamSink<ArchiveProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_string_string(files, serializer);
+sse_encode_String(out, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_StreamSink_archive_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateArchiveZipCreateWithProgressConstMeta,
+            argValues: [files, out, timeoutMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateArchiveZipCreateWithProgressConstMeta => const TaskConstMeta(
+            debugName: "zip_create_with_progress",
+            argNames: ["files", "out", "timeoutMs", "sink"],
+        );
+        
+
+@override Future<void> crateArchiveZipExtract({required String archive , required String dest , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(archive, serializer);
+sse_encode_String(dest, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateArchiveZipExtractConstMeta,
+            argValues: [archive, dest, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateArchiveZipExtractConstMeta => const TaskConstMeta(
+            debugName: "zip_extract",
+            argNames: ["archive", "dest", "timeoutMs"],
+        );
+        
+
+@override Stream<ArchiveProgress> crateArchiveZipExtractWithProgress({required String archive , required String dest , BigInt? timeoutMs })  { 
+            final sink = RustStreamSink<ArchiveProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(archive, serializer);
+sse_encode_String(dest, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_StreamSink_archive_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_portalis_error,
+        )
+        ,
+            constMeta: kCrateArchiveZipExtractWithProgressConstMeta,
+            argValues: [archive, dest, timeoutMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateArchiveZipExtractWithProgressConstMeta => const TaskConstMeta(
+            debugName: "zip_extract_with_progress",
+            argNames: ["archive", "dest", "timeoutMs", "sink"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CancelToken => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CancelToken => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SeededRng => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SeededRng => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TransformHandle => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TransformHandle => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected CancelToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SeededRng dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SeededRngImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TransformHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TransformHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SeededRng dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SeededRngImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CancelToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TransformHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TransformHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CancelToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SeededRng dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SeededRngImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TransformHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TransformHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<AppConfig> dco_decode_StreamSink_app_config_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<AppEvent> dco_decode_StreamSink_app_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ArchiveProgress> dco_decode_StreamSink_archive_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<CopyProgress> dco_decode_StreamSink_copy_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<CsvRow> dco_decode_StreamSink_csv_row_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<DownloadEvent> dco_decode_StreamSink_download_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<JobEvent> dco_decode_StreamSink_job_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<JsonArrayElement> dco_decode_StreamSink_json_array_element_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<OperationProgress> dco_decode_StreamSink_operation_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<QueuedOp> dco_decode_StreamSink_queued_op_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<WatchEvent> dco_decode_StreamSink_watch_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AppConfig dco_decode_app_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AppConfig(schemaVersion: dco_decode_u_32(arr[0]),
+theme: dco_decode_String(arr[1]),
+locale: dco_decode_String(arr[2]),
+telemetryEnabled: dco_decode_bool(arr[3]),
+recentFiles: dco_decode_list_String(arr[4]),); }
+
+@protected AppEvent dco_decode_app_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return AppEvent_DownloadFinished(url: dco_decode_String(raw[1]),dest: dco_decode_String(raw[2]),succeeded: dco_decode_bool(raw[3]),);
+case 1: return AppEvent_ConfigReloaded(path: dco_decode_String(raw[1]),);
+case 2: return AppEvent_JobFinished(id: dco_decode_u_64(raw[1]),succeeded: dco_decode_bool(raw[2]),);
+case 3: return AppEvent_Lagged(skipped: dco_decode_u_64(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ArchiveProgress dco_decode_archive_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ArchiveProgress(entriesDone: dco_decode_u_32(arr[0]),
+entriesTotal: dco_decode_u_32(arr[1]),); }
+
+@protected Base64Variant dco_decode_base_64_variant(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Base64Variant.values[raw as int]; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AppConfig dco_decode_box_autoadd_app_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_app_config(raw); }
+
+@protected ExpectedHash dco_decode_box_autoadd_expected_hash(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_expected_hash(raw); }
+
+@protected JobSpec dco_decode_box_autoadd_job_spec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_job_spec(raw); }
+
+@protected PortalisError dco_decode_box_autoadd_portalis_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_portalis_error(raw); }
+
+@protected QueuedOp dco_decode_box_autoadd_queued_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_queued_op(raw); }
+
+@protected RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_retry_policy(raw); }
+
+@protected TransformOp dco_decode_box_autoadd_transform_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_transform_op(raw); }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected BuildInfo dco_decode_build_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return BuildInfo(version: dco_decode_String(arr[0]),
+gitSha: dco_decode_String(arr[1]),
+profile: dco_decode_String(arr[2]),
+rustcVersion: dco_decode_String(arr[3]),
+builtAtUnix: dco_decode_u_64(arr[4]),); }
+
+@protected CacheStats dco_decode_cache_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CacheStats(hits: dco_decode_u_64(arr[0]),
+misses: dco_decode_u_64(arr[1]),
+size: dco_decode_u_64(arr[2]),); }
+
+@protected CompressAlgo dco_decode_compress_algo(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CompressAlgo.values[raw as int]; }
+
+@protected CopyProgress dco_decode_copy_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CopyProgress(bytesDone: dco_decode_u_64(arr[0]),
+bytesTotal: dco_decode_u_64(arr[1]),); }
+
+@protected CsvRow dco_decode_csv_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CsvRow(lineNumber: dco_decode_u_64(arr[0]),
+fields: dco_decode_list_String(arr[1]),
+isHeader: dco_decode_bool(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected DiffOp dco_decode_diff_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return DiffOp_Equal(dco_decode_String(raw[1]),);
+case 1: return DiffOp_Insert(dco_decode_String(raw[1]),);
+case 2: return DiffOp_Delete(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected DirEntry dco_decode_dir_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DirEntry(name: dco_decode_String(arr[0]),
+isDir: dco_decode_bool(arr[1]),
+sizeBytes: dco_decode_u_64(arr[2]),
+modifiedUnix: dco_decode_i_64(arr[3]),); }
+
+@protected DownloadEvent dco_decode_download_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return DownloadEvent_Progress(done: dco_decode_i_64(raw[1]),total: dco_decode_i_64(raw[2]),);
+case 1: return DownloadEvent_Restarted();
+case 2: return DownloadEvent_Done();
+case 3: return DownloadEvent_Error(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ErrorInfo dco_decode_error_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ErrorInfo(code: dco_decode_String(arr[0]),
+message: dco_decode_String(arr[1]),); }
+
+@protected ExpectedHash dco_decode_expected_hash(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ExpectedHash(algo: dco_decode_hash_algo(arr[0]),
+hex: dco_decode_String(arr[1]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected HashAlgo dco_decode_hash_algo(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HashAlgo.values[raw as int]; }
+
+@protected HttpResponse dco_decode_http_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return HttpResponse(status: dco_decode_u_16(arr[0]),
+headers: dco_decode_list_record_string_string(arr[1]),
+body: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImageFormat dco_decode_image_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ImageFormat.values[raw as int]; }
+
+@protected JobEvent dco_decode_job_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return JobEvent_Started(id: dco_decode_u_64(raw[1]),);
+case 1: return JobEvent_Completed(id: dco_decode_u_64(raw[1]),result: dco_decode_String(raw[2]),);
+case 2: return JobEvent_Failed(id: dco_decode_u_64(raw[1]),error: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected JobSpec dco_decode_job_spec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return JobSpec_Hash(algo: dco_decode_hash_algo(raw[1]),data: dco_decode_list_prim_u_8_strict(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected JsonArrayElement dco_decode_json_array_element(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return JsonArrayElement(index: dco_decode_u_64(arr[0]),
+offset: dco_decode_u_64(arr[1]),
+json: dco_decode_String(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<DiffOp> dco_decode_list_diff_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_diff_op).toList(); }
+
+@protected List<DirEntry> dco_decode_list_dir_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_dir_entry).toList(); }
+
+@protected List<List<SqlValue>> dco_decode_list_list_sql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_sql_value).toList(); }
+
+@protected List<MetricSummary> dco_decode_list_metric_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_metric_summary).toList(); }
+
+@protected List<String?> dco_decode_list_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_String).toList(); }
+
+@protected List<Uint8List?> dco_decode_list_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_list_prim_u_8_strict).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(String,Uint8List)> dco_decode_list_record_string_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_list_prim_u_8_strict).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<RegexMatch> dco_decode_list_regex_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_regex_match).toList(); }
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_hit).toList(); }
+
+@protected List<SqlValue> dco_decode_list_sql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sql_value).toList(); }
+
+@protected LogLevel dco_decode_log_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogLevel.values[raw as int]; }
+
+@protected LogRecord dco_decode_log_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LogRecord(level: dco_decode_log_level(arr[0]),
+target: dco_decode_String(arr[1]),
+message: dco_decode_String(arr[2]),
+timestampMs: dco_decode_i_64(arr[3]),); }
+
+@protected MetricSummary dco_decode_metric_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MetricSummary(name: dco_decode_String(arr[0]),
+count: dco_decode_u_64(arr[1]),
+min: dco_decode_f_64(arr[2]),
+max: dco_decode_f_64(arr[3]),
+mean: dco_decode_f_64(arr[4]),
+p95: dco_decode_f_64(arr[5]),); }
+
+@protected OperationProgress dco_decode_operation_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return OperationProgress(stage: dco_decode_String(arr[0]),
+stageIndex: dco_decode_u_32(arr[1]),
+stageCount: dco_decode_u_32(arr[2]),
+percent: dco_decode_f_32(arr[3]),
+finished: dco_decode_bool(arr[4]),
+error: dco_decode_opt_String(arr[5]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected ExpectedHash? dco_decode_opt_box_autoadd_expected_hash(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_expected_hash(raw); }
+
+@protected QueuedOp? dco_decode_opt_box_autoadd_queued_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_queued_op(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PlatformInfo dco_decode_platform_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PlatformInfo(os: dco_decode_String(arr[0]),
+arch: dco_decode_String(arr[1]),
+isWeb: dco_decode_bool(arr[2]),
+hasFilesystem: dco_decode_bool(arr[3]),
+hasThreads: dco_decode_bool(arr[4]),
+numCpus: dco_decode_u_32(arr[5]),); }
+
+@protected PortalisError dco_decode_portalis_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PortalisError_Io(dco_decode_String(raw[1]),);
+case 1: return PortalisError_InvalidInput(dco_decode_String(raw[1]),);
+case 2: return PortalisError_NotFound(dco_decode_String(raw[1]),);
+case 3: return PortalisError_Internal(dco_decode_String(raw[1]),);
+case 4: return PortalisError_Unsupported(dco_decode_String(raw[1]),);
+case 5: return PortalisError_Network(dco_decode_String(raw[1]),);
+case 6: return PortalisError_Cancelled(dco_decode_String(raw[1]),);
+case 7: return PortalisError_PermissionDenied(dco_decode_String(raw[1]),);
+case 8: return PortalisError_Corrupted(dco_decode_String(raw[1]),);
+case 9: return PortalisError_Timeout(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected QueuedOp dco_decode_queued_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return QueuedOp(id: dco_decode_u_64(arr[0]),
+kind: dco_decode_String(arr[1]),
+payload: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected (String,Uint8List) dco_decode_record_string_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected RegexMatch dco_decode_regex_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RegexMatch(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),
+matched: dco_decode_String(arr[2]),
+groups: dco_decode_list_opt_String(arr[3]),); }
+
+@protected ResourceStats dco_decode_resource_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ResourceStats(available: dco_decode_bool(arr[0]),
+rssBytes: dco_decode_u_64(arr[1]),
+virtualBytes: dco_decode_u_64(arr[2]),
+numThreads: dco_decode_u_32(arr[3]),); }
+
+@protected RetryPolicy dco_decode_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RetryPolicy(maxAttempts: dco_decode_u_32(arr[0]),
+baseDelayMs: dco_decode_u_64(arr[1]),
+jitter: dco_decode_bool(arr[2]),); }
+
+@protected SearchHit dco_decode_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SearchHit(id: dco_decode_String(arr[0]),
+score: dco_decode_f_32(arr[1]),); }
+
+@protected SqlValue dco_decode_sql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SqlValue_Null();
+case 1: return SqlValue_Integer(dco_decode_i_64(raw[1]),);
+case 2: return SqlValue_Real(dco_decode_f_64(raw[1]),);
+case 3: return SqlValue_Text(dco_decode_String(raw[1]),);
+case 4: return SqlValue_Blob(dco_decode_list_prim_u_8_strict(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected TransformOp dco_decode_transform_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return TransformOp_ZstdCompress(level: dco_decode_i_32(raw[1]),);
+case 1: return TransformOp_ZstdDecompress();
+                default: throw Exception("unreachable");
+            } }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected WatchEvent dco_decode_watch_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return WatchEvent(path: dco_decode_String(arr[0]),
+kind: dco_decode_watch_event_kind(arr[1]),); }
+
+@protected WatchEventKind dco_decode_watch_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return WatchEventKind.values[raw as int]; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected CancelToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancelTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SeededRng sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SeededRngImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TransformHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TransformHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SeededRng sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SeededRngImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CancelToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancelTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TransformHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TransformHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CancelToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancelTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SeededRng sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SeededRngImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TransformHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TransformHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<AppConfig> sse_decode_StreamSink_app_config_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<AppEvent> sse_decode_StreamSink_app_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ArchiveProgress> sse_decode_StreamSink_archive_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<CopyProgress> sse_decode_StreamSink_copy_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<CsvRow> sse_decode_StreamSink_csv_row_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<DownloadEvent> sse_decode_StreamSink_download_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<JobEvent> sse_decode_StreamSink_job_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<JsonArrayElement> sse_decode_StreamSink_json_array_element_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<OperationProgress> sse_decode_StreamSink_operation_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<QueuedOp> sse_decode_StreamSink_queued_op_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<WatchEvent> sse_decode_StreamSink_watch_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AppConfig sse_decode_app_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_schemaVersion = sse_decode_u_32(deserializer);
+var var_theme = sse_decode_String(deserializer);
+var var_locale = sse_decode_String(deserializer);
+var var_telemetryEnabled = sse_decode_bool(deserializer);
+var var_recentFiles = sse_decode_list_String(deserializer);
+return AppConfig(schemaVersion: var_schemaVersion, theme: var_theme, locale: var_locale, telemetryEnabled: var_telemetryEnabled, recentFiles: var_recentFiles); }
+
+@protected AppEvent sse_decode_app_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_url = sse_decode_String(deserializer);
+var var_dest = sse_decode_String(deserializer);
+var var_succeeded = sse_decode_bool(deserializer);
+return AppEvent_DownloadFinished(url: var_url, dest: var_dest, succeeded: var_succeeded);case 1: var var_path = sse_decode_String(deserializer);
+return AppEvent_ConfigReloaded(path: var_path);case 2: var var_id = sse_decode_u_64(deserializer);
+var var_succeeded = sse_decode_bool(deserializer);
+return AppEvent_JobFinished(id: var_id, succeeded: var_succeeded);case 3: var var_skipped = sse_decode_u_64(deserializer);
+return AppEvent_Lagged(skipped: var_skipped); default: throw UnimplementedError(''); }
+             }
+
+@protected ArchiveProgress sse_decode_archive_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entriesDone = sse_decode_u_32(deserializer);
+var var_entriesTotal = sse_decode_u_32(deserializer);
+return ArchiveProgress(entriesDone: var_entriesDone, entriesTotal: var_entriesTotal); }
+
+@protected Base64Variant sse_decode_base_64_variant(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Base64Variant.values[inner]; }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AppConfig sse_decode_box_autoadd_app_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_app_config(deserializer)); }
+
+@protected ExpectedHash sse_decode_box_autoadd_expected_hash(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_expected_hash(deserializer)); }
+
+@protected JobSpec sse_decode_box_autoadd_job_spec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_job_spec(deserializer)); }
+
+@protected PortalisError sse_decode_box_autoadd_portalis_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_portalis_error(deserializer)); }
+
+@protected QueuedOp sse_decode_box_autoadd_queued_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_queued_op(deserializer)); }
+
+@protected RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_retry_policy(deserializer)); }
+
+@protected TransformOp sse_decode_box_autoadd_transform_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_transform_op(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected BuildInfo sse_decode_build_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_version = sse_decode_String(deserializer);
+var var_gitSha = sse_decode_String(deserializer);
+var var_profile = sse_decode_String(deserializer);
+var var_rustcVersion = sse_decode_String(deserializer);
+var var_builtAtUnix = sse_decode_u_64(deserializer);
+return BuildInfo(version: var_version, gitSha: var_gitSha, profile: var_profile, rustcVersion: var_rustcVersion, builtAtUnix: var_builtAtUnix); }
+
+@protected CacheStats sse_decode_cache_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_u_64(deserializer);
+var var_misses = sse_decode_u_64(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+return CacheStats(hits: var_hits, misses: var_misses, size: var_size); }
+
+@protected CompressAlgo sse_decode_compress_algo(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CompressAlgo.values[inner]; }
+
+@protected CopyProgress sse_decode_copy_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bytesDone = sse_decode_u_64(deserializer);
+var var_bytesTotal = sse_decode_u_64(deserializer);
+return CopyProgress(bytesDone: var_bytesDone, bytesTotal: var_bytesTotal); }
+
+@protected CsvRow sse_decode_csv_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_lineNumber = sse_decode_u_64(deserializer);
+var var_fields = sse_decode_list_String(deserializer);
+var var_isHeader = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return CsvRow(lineNumber: var_lineNumber, fields: var_fields, isHeader: var_isHeader, error: var_error); }
+
+@protected DiffOp sse_decode_diff_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return DiffOp_Equal(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return DiffOp_Insert(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return DiffOp_Delete(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected DirEntry sse_decode_dir_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_isDir = sse_decode_bool(deserializer);
+var var_sizeBytes = sse_decode_u_64(deserializer);
+var var_modifiedUnix = sse_decode_i_64(deserializer);
+return DirEntry(name: var_name, isDir: var_isDir, sizeBytes: var_sizeBytes, modifiedUnix: var_modifiedUnix); }
+
+@protected DownloadEvent sse_decode_download_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_done = sse_decode_i_64(deserializer);
+var var_total = sse_decode_i_64(deserializer);
+return DownloadEvent_Progress(done: var_done, total: var_total);case 1: return DownloadEvent_Restarted();case 2: return DownloadEvent_Done();case 3: var var_message = sse_decode_String(deserializer);
+return DownloadEvent_Error(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected ErrorInfo sse_decode_error_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return ErrorInfo(code: var_code, message: var_message); }
+
+@protected ExpectedHash sse_decode_expected_hash(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_algo = sse_decode_hash_algo(deserializer);
+var var_hex = sse_decode_String(deserializer);
+return ExpectedHash(algo: var_algo, hex: var_hex); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected HashAlgo sse_decode_hash_algo(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HashAlgo.values[inner]; }
+
+@protected HttpResponse sse_decode_http_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_status = sse_decode_u_16(deserializer);
+var var_headers = sse_decode_list_record_string_string(deserializer);
+var var_body = sse_decode_list_prim_u_8_strict(deserializer);
+return HttpResponse(status: var_status, headers: var_headers, body: var_body); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImageFormat sse_decode_image_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ImageFormat.values[inner]; }
+
+@protected JobEvent sse_decode_job_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_id = sse_decode_u_64(deserializer);
+return JobEvent_Started(id: var_id);case 1: var var_id = sse_decode_u_64(deserializer);
+var var_result = sse_decode_String(deserializer);
+return JobEvent_Completed(id: var_id, result: var_result);case 2: var var_id = sse_decode_u_64(deserializer);
+var var_error = sse_decode_String(deserializer);
+return JobEvent_Failed(id: var_id, error: var_error); default: throw UnimplementedError(''); }
+             }
+
+@protected JobSpec sse_decode_job_spec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_algo = sse_decode_hash_algo(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return JobSpec_Hash(algo: var_algo, data: var_data); default: throw UnimplementedError(''); }
+             }
+
+@protected JsonArrayElement sse_decode_json_array_element(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_64(deserializer);
+var var_offset = sse_decode_u_64(deserializer);
+var var_json = sse_decode_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return JsonArrayElement(index: var_index, offset: var_offset, json: var_json, error: var_error); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DiffOp> sse_decode_list_diff_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DiffOp>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_diff_op(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DirEntry> sse_decode_list_dir_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DirEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_dir_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<SqlValue>> sse_decode_list_list_sql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<SqlValue>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_sql_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MetricSummary> sse_decode_list_metric_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MetricSummary>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_metric_summary(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String?> sse_decode_list_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Uint8List?> sse_decode_list_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Uint8List?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(String,Uint8List)> sse_decode_list_record_string_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,Uint8List)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RegexMatch> sse_decode_list_regex_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RegexMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_regex_match(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SqlValue> sse_decode_list_sql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SqlValue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sql_value(deserializer)); }
+        return ans_;
+         }
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogLevel.values[inner]; }
+
+@protected LogRecord sse_decode_log_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_level = sse_decode_log_level(deserializer);
+var var_target = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_timestampMs = sse_decode_i_64(deserializer);
+return LogRecord(level: var_level, target: var_target, message: var_message, timestampMs: var_timestampMs); }
+
+@protected MetricSummary sse_decode_metric_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+var var_min = sse_decode_f_64(deserializer);
+var var_max = sse_decode_f_64(deserializer);
+var var_mean = sse_decode_f_64(deserializer);
+var var_p95 = sse_decode_f_64(deserializer);
+return MetricSummary(name: var_name, count: var_count, min: var_min, max: var_max, mean: var_mean, p95: var_p95); }
+
+@protected OperationProgress sse_decode_operation_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stage = sse_decode_String(deserializer);
+var var_stageIndex = sse_decode_u_32(deserializer);
+var var_stageCount = sse_decode_u_32(deserializer);
+var var_percent = sse_decode_f_32(deserializer);
+var var_finished = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return OperationProgress(stage: var_stage, stageIndex: var_stageIndex, stageCount: var_stageCount, percent: var_percent, finished: var_finished, error: var_error); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ExpectedHash? sse_decode_opt_box_autoadd_expected_hash(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_expected_hash(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected QueuedOp? sse_decode_opt_box_autoadd_queued_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_queued_op(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInfo sse_decode_platform_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_os = sse_decode_String(deserializer);
+var var_arch = sse_decode_String(deserializer);
+var var_isWeb = sse_decode_bool(deserializer);
+var var_hasFilesystem = sse_decode_bool(deserializer);
+var var_hasThreads = sse_decode_bool(deserializer);
+var var_numCpus = sse_decode_u_32(deserializer);
+return PlatformInfo(os: var_os, arch: var_arch, isWeb: var_isWeb, hasFilesystem: var_hasFilesystem, hasThreads: var_hasThreads, numCpus: var_numCpus); }
+
+@protected PortalisError sse_decode_portalis_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_Io(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_InvalidInput(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_NotFound(var_field0);case 3: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_Internal(var_field0);case 4: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_Unsupported(var_field0);case 5: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_Network(var_field0);case 6: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_Cancelled(var_field0);case 7: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_PermissionDenied(var_field0);case 8: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_Corrupted(var_field0);case 9: var var_field0 = sse_decode_String(deserializer);
+return PortalisError_Timeout(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected QueuedOp sse_decode_queued_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_64(deserializer);
+var var_kind = sse_decode_String(deserializer);
+var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+return QueuedOp(id: var_id, kind: var_kind, payload: var_payload); }
+
+@protected (String,Uint8List) sse_decode_record_string_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected RegexMatch sse_decode_regex_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+var var_matched = sse_decode_String(deserializer);
+var var_groups = sse_decode_list_opt_String(deserializer);
+return RegexMatch(start: var_start, end: var_end, matched: var_matched, groups: var_groups); }
+
+@protected ResourceStats sse_decode_resource_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_available = sse_decode_bool(deserializer);
+var var_rssBytes = sse_decode_u_64(deserializer);
+var var_virtualBytes = sse_decode_u_64(deserializer);
+var var_numThreads = sse_decode_u_32(deserializer);
+return ResourceStats(available: var_available, rssBytes: var_rssBytes, virtualBytes: var_virtualBytes, numThreads: var_numThreads); }
+
+@protected RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_baseDelayMs = sse_decode_u_64(deserializer);
+var var_jitter = sse_decode_bool(deserializer);
+return RetryPolicy(maxAttempts: var_maxAttempts, baseDelayMs: var_baseDelayMs, jitter: var_jitter); }
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_score = sse_decode_f_32(deserializer);
+return SearchHit(id: var_id, score: var_score); }
+
+@protected SqlValue sse_decode_sql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SqlValue_Null();case 1: var var_field0 = sse_decode_i_64(deserializer);
+return SqlValue_Integer(var_field0);case 2: var var_field0 = sse_decode_f_64(deserializer);
+return SqlValue_Real(var_field0);case 3: var var_field0 = sse_decode_String(deserializer);
+return SqlValue_Text(var_field0);case 4: var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+return SqlValue_Blob(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected TransformOp sse_decode_transform_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_level = sse_decode_i_32(deserializer);
+return TransformOp_ZstdCompress(level: var_level);case 1: return TransformOp_ZstdDecompress(); default: throw UnimplementedError(''); }
+             }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected WatchEvent sse_decode_watch_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_kind = sse_decode_watch_event_kind(deserializer);
+return WatchEvent(path: var_path, kind: var_kind); }
+
+@protected WatchEventKind sse_decode_watch_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return WatchEventKind.values[inner]; }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancelTokenImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SeededRng self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SeededRngImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(TransformHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TransformHandleImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SeededRng self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SeededRngImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancelTokenImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(TransformHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TransformHandleImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancelTokenImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SeededRng self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SeededRngImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(TransformHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TransformHandleImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_app_config_Sse(RustStreamSink<AppConfig> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_app_config,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_app_event_Sse(RustStreamSink<AppEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_app_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_archive_progress_Sse(RustStreamSink<ArchiveProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_archive_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_copy_progress_Sse(RustStreamSink<CopyProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_copy_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_csv_row_Sse(RustStreamSink<CsvRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_csv_row,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_download_event_Sse(RustStreamSink<DownloadEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_download_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_job_event_Sse(RustStreamSink<JobEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_job_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_json_array_element_Sse(RustStreamSink<JsonArrayElement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_json_array_element,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_list_prim_u_8_strict_Sse(RustStreamSink<Uint8List> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_list_prim_u_8_strict,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_log_record_Sse(RustStreamSink<LogRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_log_record,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_operation_progress_Sse(RustStreamSink<OperationProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_operation_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_queued_op_Sse(RustStreamSink<QueuedOp> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_queued_op,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_watch_event_Sse(RustStreamSink<WatchEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_watch_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_app_config(AppConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.schemaVersion, serializer);
+sse_encode_String(self.theme, serializer);
+sse_encode_String(self.locale, serializer);
+sse_encode_bool(self.telemetryEnabled, serializer);
+sse_encode_list_String(self.recentFiles, serializer);
+ }
+
+@protected void sse_encode_app_event(AppEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case AppEvent_DownloadFinished(url: final url,dest: final dest,succeeded: final succeeded): sse_encode_i_32(0, serializer); sse_encode_String(url, serializer);
+sse_encode_String(dest, serializer);
+sse_encode_bool(succeeded, serializer);
+case AppEvent_ConfigReloaded(path: final path): sse_encode_i_32(1, serializer); sse_encode_String(path, serializer);
+case AppEvent_JobFinished(id: final id,succeeded: final succeeded): sse_encode_i_32(2, serializer); sse_encode_u_64(id, serializer);
+sse_encode_bool(succeeded, serializer);
+case AppEvent_Lagged(skipped: final skipped): sse_encode_i_32(3, serializer); sse_encode_u_64(skipped, serializer);
+  } }
+
+@protected void sse_encode_archive_progress(ArchiveProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.entriesDone, serializer);
+sse_encode_u_32(self.entriesTotal, serializer);
+ }
+
+@protected void sse_encode_base_64_variant(Base64Variant self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_app_config(AppConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_app_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_expected_hash(ExpectedHash self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_expected_hash(self, serializer); }
+
+@protected void sse_encode_box_autoadd_job_spec(JobSpec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_job_spec(self, serializer); }
+
+@protected void sse_encode_box_autoadd_portalis_error(PortalisError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_portalis_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_queued_op(QueuedOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_queued_op(self, serializer); }
+
+@protected void sse_encode_box_autoadd_retry_policy(RetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_retry_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_transform_op(TransformOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_transform_op(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_build_info(BuildInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.version, serializer);
+sse_encode_String(self.gitSha, serializer);
+sse_encode_String(self.profile, serializer);
+sse_encode_String(self.rustcVersion, serializer);
+sse_encode_u_64(self.builtAtUnix, serializer);
+ }
+
+@protected void sse_encode_cache_stats(CacheStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.hits, serializer);
+sse_encode_u_64(self.misses, serializer);
+sse_encode_u_64(self.size, serializer);
+ }
+
+@protected void sse_encode_compress_algo(CompressAlgo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_copy_progress(CopyProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.bytesDone, serializer);
+sse_encode_u_64(self.bytesTotal, serializer);
+ }
+
+@protected void sse_encode_csv_row(CsvRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.lineNumber, serializer);
+sse_encode_list_String(self.fields, serializer);
+sse_encode_bool(self.isHeader, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_diff_op(DiffOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case DiffOp_Equal(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case DiffOp_Insert(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case DiffOp_Delete(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_dir_entry(DirEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_bool(self.isDir, serializer);
+sse_encode_u_64(self.sizeBytes, serializer);
+sse_encode_i_64(self.modifiedUnix, serializer);
+ }
+
+@protected void sse_encode_download_event(DownloadEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case DownloadEvent_Progress(done: final done,total: final total): sse_encode_i_32(0, serializer); sse_encode_i_64(done, serializer);
+sse_encode_i_64(total, serializer);
+case DownloadEvent_Restarted(): sse_encode_i_32(1, serializer); case DownloadEvent_Done(): sse_encode_i_32(2, serializer); case DownloadEvent_Error(message: final message): sse_encode_i_32(3, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_error_info(ErrorInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_expected_hash(ExpectedHash self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hash_algo(self.algo, serializer);
+sse_encode_String(self.hex, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_hash_algo(HashAlgo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_http_response(HttpResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self.status, serializer);
+sse_encode_list_record_string_string(self.headers, serializer);
+sse_encode_list_prim_u_8_strict(self.body, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_image_format(ImageFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_job_event(JobEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case JobEvent_Started(id: final id): sse_encode_i_32(0, serializer); sse_encode_u_64(id, serializer);
+case JobEvent_Completed(id: final id,result: final result): sse_encode_i_32(1, serializer); sse_encode_u_64(id, serializer);
+sse_encode_String(result, serializer);
+case JobEvent_Failed(id: final id,error: final error): sse_encode_i_32(2, serializer); sse_encode_u_64(id, serializer);
+sse_encode_String(error, serializer);
+  } }
+
+@protected void sse_encode_job_spec(JobSpec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case JobSpec_Hash(algo: final algo,data: final data): sse_encode_i_32(0, serializer); sse_encode_hash_algo(algo, serializer);
+sse_encode_list_prim_u_8_strict(data, serializer);
+  } }
+
+@protected void sse_encode_json_array_element(JsonArrayElement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.index, serializer);
+sse_encode_u_64(self.offset, serializer);
+sse_encode_String(self.json, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_diff_op(List<DiffOp> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_diff_op(item, serializer); } }
+
+@protected void sse_encode_list_dir_entry(List<DirEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_dir_entry(item, serializer); } }
+
+@protected void sse_encode_list_list_sql_value(List<List<SqlValue>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_sql_value(item, serializer); } }
+
+@protected void sse_encode_list_metric_summary(List<MetricSummary> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_metric_summary(item, serializer); } }
+
+@protected void sse_encode_list_opt_String(List<String?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_String(item, serializer); } }
+
+@protected void sse_encode_list_opt_list_prim_u_8_strict(List<Uint8List?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_string_list_prim_u_8_strict(List<(String,Uint8List)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_regex_match(List<RegexMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_regex_match(item, serializer); } }
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_sql_value(List<SqlValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sql_value(item, serializer); } }
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_log_record(LogRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_log_level(self.level, serializer);
+sse_encode_String(self.target, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_i_64(self.timestampMs, serializer);
+ }
+
+@protected void sse_encode_metric_summary(MetricSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.count, serializer);
+sse_encode_f_64(self.min, serializer);
+sse_encode_f_64(self.max, serializer);
+sse_encode_f_64(self.mean, serializer);
+sse_encode_f_64(self.p95, serializer);
+ }
+
+@protected void sse_encode_operation_progress(OperationProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.stage, serializer);
+sse_encode_u_32(self.stageIndex, serializer);
+sse_encode_u_32(self.stageCount, serializer);
+sse_encode_f_32(self.percent, serializer);
+sse_encode_bool(self.finished, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_expected_hash(ExpectedHash? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_expected_hash(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_queued_op(QueuedOp? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_queued_op(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_platform_info(PlatformInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.os, serializer);
+sse_encode_String(self.arch, serializer);
+sse_encode_bool(self.isWeb, serializer);
+sse_encode_bool(self.hasFilesystem, serializer);
+sse_encode_bool(self.hasThreads, serializer);
+sse_encode_u_32(self.numCpus, serializer);
+ }
+
+@protected void sse_encode_portalis_error(PortalisError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PortalisError_Io(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case PortalisError_InvalidInput(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case PortalisError_NotFound(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case PortalisError_Internal(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case PortalisError_Unsupported(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+case PortalisError_Network(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_String(field0, serializer);
+case PortalisError_Cancelled(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_String(field0, serializer);
+case PortalisError_PermissionDenied(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_String(field0, serializer);
+case PortalisError_Corrupted(field0: final field0): sse_encode_i_32(8, serializer); sse_encode_String(field0, serializer);
+case PortalisError_Timeout(field0: final field0): sse_encode_i_32(9, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_queued_op(QueuedOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.id, serializer);
+sse_encode_String(self.kind, serializer);
+sse_encode_list_prim_u_8_strict(self.payload, serializer);
+ }
+
+@protected void sse_encode_record_string_list_prim_u_8_strict((String,Uint8List) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_list_prim_u_8_strict(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_regex_match(RegexMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+sse_encode_String(self.matched, serializer);
+sse_encode_list_opt_String(self.groups, serializer);
+ }
+
+@protected void sse_encode_resource_stats(ResourceStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.available, serializer);
+sse_encode_u_64(self.rssBytes, serializer);
+sse_encode_u_64(self.virtualBytes, serializer);
+sse_encode_u_32(self.numThreads, serializer);
+ }
+
+@protected void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxAttempts, serializer);
+sse_encode_u_64(self.baseDelayMs, serializer);
+sse_encode_bool(self.jitter, serializer);
+ }
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_f_32(self.score, serializer);
+ }
+
+@protected void sse_encode_sql_value(SqlValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SqlValue_Null(): sse_encode_i_32(0, serializer); case SqlValue_Integer(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_i_64(field0, serializer);
+case SqlValue_Real(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_f_64(field0, serializer);
+case SqlValue_Text(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case SqlValue_Blob(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_list_prim_u_8_strict(field0, serializer);
+  } }
+
+@protected void sse_encode_transform_op(TransformOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case TransformOp_ZstdCompress(level: final level): sse_encode_i_32(0, serializer); sse_encode_i_32(level, serializer);
+case TransformOp_ZstdDecompress(): sse_encode_i_32(1, serializer);   } }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_watch_event(WatchEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_watch_event_kind(self.kind, serializer);
+ }
+
+@protected void sse_encode_watch_event_kind(WatchEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+                }
+                
+
+            @sealed class CancelTokenImpl extends RustOpaque implements CancelToken {
+                // Not to be used by end users
+                CancelTokenImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CancelTokenImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CancelToken,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CancelToken,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CancelTokenPtr,
+                );
+
+                 void  cancel()=>RustLib.instance.api.crateCancelCancelTokenCancel(that: this, );
+
+
+ bool  isCancelled()=>RustLib.instance.api.crateCancelCancelTokenIsCancelled(that: this, );
+
+
+            }
+            @sealed class HasherImpl extends RustOpaque implements Hasher {
+                // Not to be used by end users
+                HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_HasherPtr,
+                );
+
+                /// Lowercase hex digest of everything fed so far.
+///
+/// The handle keeps its state, so more data can still be added afterwards.
+ String  finalize()=>RustLib.instance.api.crateHashHasherFinalize(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateHashHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class SeededRngImpl extends RustOpaque implements SeededRng {
+                // Not to be used by end users
+                SeededRngImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SeededRngImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SeededRng,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SeededRng,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SeededRngPtr,
+                );
+
+                /// Uniform in `[0, 1)`.
+ double  nextF64()=>RustLib.instance.api.crateCryptoSeededRngNextF64(that: this, );
+
+
+ BigInt  nextU64()=>RustLib.instance.api.crateCryptoSeededRngNextU64(that: this, );
+
+
+/// Returns `items` in a uniformly random order (Fisher-Yates).
+ List<String>  shuffle({required List<String> items })=>RustLib.instance.api.crateCryptoSeededRngShuffle(that: this, items: items);
+
+
+            }
+            @sealed class TransformHandleImpl extends RustOpaque implements TransformHandle {
+                // Not to be used by end users
+                TransformHandleImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                TransformHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_TransformHandle,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_TransformHandle,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_TransformHandlePtr,
+                );
+
+                 Future<void>  feed({required List<int> chunk })=>RustLib.instance.api.cratePipelineTransformHandleFeed(that: this, chunk: chunk);
+
+
+/// Flushes the remaining output and closes the `output` stream.
+///
+/// Fails with `InvalidInput` if decompression input ends in the middle of a frame.
+ Future<void>  finish()=>RustLib.instance.api.cratePipelineTransformHandleFinish(that: this, );
+
+
+/// Registers the stream receiving transformed chunks; it closes after `finish`.
+ Stream<Uint8List>  output()=>RustLib.instance.api.cratePipelineTransformHandleOutput(that: this, );
+
+
+            }
\ No newline at end of file
@@ -3,82 +3,795 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'archive.dart';
+import 'cache.dart';
+import 'cancel.dart';
+import 'codec.dart';
+import 'compress.dart';
+import 'crypto.dart';
+import 'csv.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
+import 'db.dart';
+import 'diag.dart';
+import 'diff.dart';
+import 'encoding.dart';
+import 'env.dart';
+import 'error.dart';
+import 'events.dart';
+import 'format.dart';
 import 'frb_generated.dart';
+import 'fs.dart';
+import 'hash.dart';
+import 'ids.dart';
+import 'image.dart';
+import 'jobs.dart';
+import 'kv.dart';
 import 'lib.dart';
+import 'lifecycle.dart';
+import 'logging.dart';
+import 'metrics.dart';
+import 'net.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
+import 'pipeline.dart';
+import 'platform.dart';
+import 'progress.dart';
+import 'queue.dart';
+import 'regex.dart';
+import 'resources.dart';
+import 'runtime.dart';
+import 'search.dart';
+import 'state.dart';
+import 'time.dart';
+import 'watch.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancelTokenPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelTokenPtr;
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HasherPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasherPtr;
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SeededRngPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRngPtr;
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TransformHandlePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandlePtr;
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected CancelToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected SeededRng dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected TransformHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected SeededRng dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(dynamic raw);
+
+@protected CancelToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw);
+
+@protected Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw);
+
+@protected TransformHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(dynamic raw);
+
+@protected CancelToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw);
+
+@protected Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw);
+
+@protected SeededRng dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(dynamic raw);
+
+@protected TransformHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(dynamic raw);
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+@protected RustStreamSink<AppConfig> dco_decode_StreamSink_app_config_Sse(dynamic raw);
+
+@protected RustStreamSink<AppEvent> dco_decode_StreamSink_app_event_Sse(dynamic raw);
+
+@protected RustStreamSink<ArchiveProgress> dco_decode_StreamSink_archive_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<CopyProgress> dco_decode_StreamSink_copy_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<CsvRow> dco_decode_StreamSink_csv_row_Sse(dynamic raw);
+
+@protected RustStreamSink<DownloadEvent> dco_decode_StreamSink_download_event_Sse(dynamic raw);
+
+@protected RustStreamSink<JobEvent> dco_decode_StreamSink_job_event_Sse(dynamic raw);
+
+@protected RustStreamSink<JsonArrayElement> dco_decode_StreamSink_json_array_element_Sse(dynamic raw);
+
+@protected RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(dynamic raw);
+
+@protected RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw);
+
+@protected RustStreamSink<OperationProgress> dco_decode_StreamSink_operation_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<QueuedOp> dco_decode_StreamSink_queued_op_Sse(dynamic raw);
+
+@protected RustStreamSink<WatchEvent> dco_decode_StreamSink_watch_event_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected AppConfig dco_decode_app_config(dynamic raw);
+
+@protected AppEvent dco_decode_app_event(dynamic raw);
+
+@protected ArchiveProgress dco_decode_archive_progress(dynamic raw);
+
+@protected Base64Variant dco_decode_base_64_variant(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected AppConfig dco_decode_box_autoadd_app_config(dynamic raw);
+
+@protected ExpectedHash dco_decode_box_autoadd_expected_hash(dynamic raw);
+
+@protected JobSpec dco_decode_box_autoadd_job_spec(dynamic raw);
+
+@protected PortalisError dco_decode_box_autoadd_portalis_error(dynamic raw);
+
+@protected QueuedOp dco_decode_box_autoadd_queued_op(dynamic raw);
+
+@protected RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw);
+
+@protected TransformOp dco_decode_box_autoadd_transform_op(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected BuildInfo dco_decode_build_info(dynamic raw);
+
+@protected CacheStats dco_decode_cache_stats(dynamic raw);
+
+@protected CompressAlgo dco_decode_compress_algo(dynamic raw);
+
+@protected CopyProgress dco_decode_copy_progress(dynamic raw);
+
+@protected CsvRow dco_decode_csv_row(dynamic raw);
+
+@protected DiffOp dco_decode_diff_op(dynamic raw);
+
+@protected DirEntry dco_decode_dir_entry(dynamic raw);
+
+@protected DownloadEvent dco_decode_download_event(dynamic raw);
+
+@protected ErrorInfo dco_decode_error_info(dynamic raw);
+
+@protected ExpectedHash dco_decode_expected_hash(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected HashAlgo dco_decode_hash_algo(dynamic raw);
+
+@protected HttpResponse dco_decode_http_response(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected ImageFormat dco_decode_image_format(dynamic raw);
+
+@protected JobEvent dco_decode_job_event(dynamic raw);
+
+@protected JobSpec dco_decode_job_spec(dynamic raw);
+
+@protected JsonArrayElement dco_decode_json_array_element(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<DiffOp> dco_decode_list_diff_op(dynamic raw);
+
+@protected List<DirEntry> dco_decode_list_dir_entry(dynamic raw);
+
+@protected List<List<SqlValue>> dco_decode_list_list_sql_value(dynamic raw);
+
+@protected List<MetricSummary> dco_decode_list_metric_summary(dynamic raw);
+
+@protected List<String?> dco_decode_list_opt_String(dynamic raw);
+
+@protected List<Uint8List?> dco_decode_list_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(String,Uint8List)> dco_decode_list_record_string_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected List<RegexMatch> dco_decode_list_regex_match(dynamic raw);
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+@protected List<SqlValue> dco_decode_list_sql_value(dynamic raw);
+
+@protected LogLevel dco_decode_log_level(dynamic raw);
+
+@protected LogRecord dco_decode_log_record(dynamic raw);
+
+@protected MetricSummary dco_decode_metric_summary(dynamic raw);
+
+@protected OperationProgress dco_decode_operation_progress(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected ExpectedHash? dco_decode_opt_box_autoadd_expected_hash(dynamic raw);
+
+@protected QueuedOp? dco_decode_opt_box_autoadd_queued_op(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected PlatformInfo dco_decode_platform_info(dynamic raw);
+
+@protected PortalisError dco_decode_portalis_error(dynamic raw);
+
+@protected QueuedOp dco_decode_queued_op(dynamic raw);
+
+@protected (String,Uint8List) dco_decode_record_string_list_prim_u_8_strict(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected RegexMatch dco_decode_regex_match(dynamic raw);
+
+@protected ResourceStats dco_decode_resource_stats(dynamic raw);
+
+@protected RetryPolicy dco_decode_retry_policy(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SqlValue dco_decode_sql_value(dynamic raw);
+
+@protected TransformOp dco_decode_transform_op(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected WatchEvent dco_decode_watch_event(dynamic raw);
+
+@protected WatchEventKind dco_decode_watch_event_kind(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected CancelToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer);
+
+@protected Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer);
+
+@protected SeededRng sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SseDeserializer deserializer);
+
+@protected TransformHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(SseDeserializer deserializer);
+
+@protected SeededRng sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SseDeserializer deserializer);
+
+@protected CancelToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer);
+
+@protected Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer);
+
+@protected TransformHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(SseDeserializer deserializer);
+
+@protected CancelToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer);
+
+@protected Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer);
+
+@protected SeededRng sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SseDeserializer deserializer);
+
+@protected TransformHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<AppConfig> sse_decode_StreamSink_app_config_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<AppEvent> sse_decode_StreamSink_app_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ArchiveProgress> sse_decode_StreamSink_archive_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<CopyProgress> sse_decode_StreamSink_copy_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<CsvRow> sse_decode_StreamSink_csv_row_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<DownloadEvent> sse_decode_StreamSink_download_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<JobEvent> sse_decode_StreamSink_job_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<JsonArrayElement> sse_decode_StreamSink_json_array_element_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<OperationProgress> sse_decode_StreamSink_operation_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<QueuedOp> sse_decode_StreamSink_queued_op_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<WatchEvent> sse_decode_StreamSink_watch_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AppConfig sse_decode_app_config(SseDeserializer deserializer);
+
+@protected AppEvent sse_decode_app_event(SseDeserializer deserializer);
+
+@protected ArchiveProgress sse_decode_archive_progress(SseDeserializer deserializer);
+
+@protected Base64Variant sse_decode_base_64_variant(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AppConfig sse_decode_box_autoadd_app_config(SseDeserializer deserializer);
+
+@protected ExpectedHash sse_decode_box_autoadd_expected_hash(SseDeserializer deserializer);
+
+@protected JobSpec sse_decode_box_autoadd_job_spec(SseDeserializer deserializer);
+
+@protected PortalisError sse_decode_box_autoadd_portalis_error(SseDeserializer deserializer);
+
+@protected QueuedOp sse_decode_box_autoadd_queued_op(SseDeserializer deserializer);
+
+@protected RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer);
+
+@protected TransformOp sse_decode_box_autoadd_transform_op(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected BuildInfo sse_decode_build_info(SseDeserializer deserializer);
+
+@protected CacheStats sse_decode_cache_stats(SseDeserializer deserializer);
+
+@protected CompressAlgo sse_decode_compress_algo(SseDeserializer deserializer);
+
+@protected CopyProgress sse_decode_copy_progress(SseDeserializer deserializer);
+
+@protected CsvRow sse_decode_csv_row(SseDeserializer deserializer);
+
+@protected DiffOp sse_decode_diff_op(SseDeserializer deserializer);
+
+@protected DirEntry sse_decode_dir_entry(SseDeserializer deserializer);
+
+@protected DownloadEvent sse_decode_download_event(SseDeserializer deserializer);
+
+@protected ErrorInfo sse_decode_error_info(SseDeserializer deserializer);
+
+@protected ExpectedHash sse_decode_expected_hash(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected HashAlgo sse_decode_hash_algo(SseDeserializer deserializer);
+
+@protected HttpResponse sse_decode_http_response(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected ImageFormat sse_decode_image_format(SseDeserializer deserializer);
+
+@protected JobEvent sse_decode_job_event(SseDeserializer deserializer);
+
+@protected JobSpec sse_decode_job_spec(SseDeserializer deserializer);
+
+@protected JsonArrayElement sse_decode_json_array_element(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<DiffOp> sse_decode_list_diff_op(SseDeserializer deserializer);
+
+@protected List<DirEntry> sse_decode_list_dir_entry(SseDeserializer deserializer);
+
+@protected List<List<SqlValue>> sse_decode_list_list_sql_value(SseDeserializer deserializer);
+
+@protected List<MetricSummary> sse_decode_list_metric_summary(SseDeserializer deserializer);
+
+@protected List<String?> sse_decode_list_opt_String(SseDeserializer deserializer);
+
+@protected List<Uint8List?> sse_decode_list_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<(String,Uint8List)> sse_decode_list_record_string_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<RegexMatch> sse_decode_list_regex_match(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<SqlValue> sse_decode_list_sql_value(SseDeserializer deserializer);
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+@protected LogRecord sse_decode_log_record(SseDeserializer deserializer);
+
+@protected MetricSummary sse_decode_metric_summary(SseDeserializer deserializer);
+
+@protected OperationProgress sse_decode_operation_progress(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected ExpectedHash? sse_decode_opt_box_autoadd_expected_hash(SseDeserializer deserializer);
+
+@protected QueuedOp? sse_decode_opt_box_autoadd_queued_op(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PlatformInfo sse_decode_platform_info(SseDeserializer deserializer);
+
+@protected PortalisError sse_decode_portalis_error(SseDeserializer deserializer);
+
+@protected QueuedOp sse_decode_queued_op(SseDeserializer deserializer);
+
+@protected (String,Uint8List) sse_decode_record_string_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected RegexMatch sse_decode_regex_match(SseDeserializer deserializer);
+
+@protected ResourceStats sse_decode_resource_stats(SseDeserializer deserializer);
+
+@protected RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SqlValue sse_decode_sql_value(SseDeserializer deserializer);
+
+@protected TransformOp sse_decode_transform_op(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected WatchEvent sse_decode_watch_event(SseDeserializer deserializer);
+
+@protected WatchEventKind sse_decode_watch_event_kind(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SeededRng self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(TransformHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SeededRng self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(TransformHandle self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(SeededRng self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(TransformHandle self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_app_config_Sse(RustStreamSink<AppConfig> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_app_event_Sse(RustStreamSink<AppEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_archive_progress_Sse(RustStreamSink<ArchiveProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_copy_progress_Sse(RustStreamSink<CopyProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_csv_row_Sse(RustStreamSink<CsvRow> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_download_event_Sse(RustStreamSink<DownloadEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_job_event_Sse(RustStreamSink<JobEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_json_array_element_Sse(RustStreamSink<JsonArrayElement> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_list_prim_u_8_strict_Sse(RustStreamSink<Uint8List> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_log_record_Sse(RustStreamSink<LogRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_operation_progress_Sse(RustStreamSink<OperationProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_queued_op_Sse(RustStreamSink<QueuedOp> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_watch_event_Sse(RustStreamSink<WatchEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_app_config(AppConfig self, SseSerializer serializer);
+
+@protected void sse_encode_app_event(AppEvent self, SseSerializer serializer);
+
+@protected void sse_encode_archive_progress(ArchiveProgress self, SseSerializer serializer);
+
+@protected void sse_encode_base_64_variant(Base64Variant self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_app_config(AppConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_expected_hash(ExpectedHash self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_job_spec(JobSpec self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_portalis_error(PortalisError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_queued_op(QueuedOp self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_retry_policy(RetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_transform_op(TransformOp self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_build_info(BuildInfo self, SseSerializer serializer);
+
+@protected void sse_encode_cache_stats(CacheStats self, SseSerializer serializer);
+
+@protected void sse_encode_compress_algo(CompressAlgo self, SseSerializer serializer);
+
+@protected void sse_encode_copy_progress(CopyProgress self, SseSerializer serializer);
+
+@protected void sse_encode_csv_row(CsvRow self, SseSerializer serializer);
+
+@protected void sse_encode_diff_op(DiffOp self, SseSerializer serializer);
+
+@protected void sse_encode_dir_entry(DirEntry self, SseSerializer serializer);
+
+@protected void sse_encode_download_event(DownloadEvent self, SseSerializer serializer);
+
+@protected void sse_encode_error_info(ErrorInfo self, SseSerializer serializer);
+
+@protected void sse_encode_expected_hash(ExpectedHash self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_hash_algo(HashAlgo self, SseSerializer serializer);
+
+@protected void sse_encode_http_response(HttpResponse self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_image_format(ImageFormat self, SseSerializer serializer);
+
+@protected void sse_encode_job_event(JobEvent self, SseSerializer serializer);
+
+@protected void sse_encode_job_spec(JobSpec self, SseSerializer serializer);
+
+@protected void sse_encode_json_array_element(JsonArrayElement self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_diff_op(List<DiffOp> self, SseSerializer serializer);
+
+@protected void sse_encode_list_dir_entry(List<DirEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_sql_value(List<List<SqlValue>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_metric_summary(List<MetricSummary> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_String(List<String?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_list_prim_u_8_strict(List<Uint8List?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_list_prim_u_8_strict(List<(String,Uint8List)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_regex_match(List<RegexMatch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sql_value(List<SqlValue> self, SseSerializer serializer);
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+@protected void sse_encode_log_record(LogRecord self, SseSerializer serializer);
+
+@protected void sse_encode_metric_summary(MetricSummary self, SseSerializer serializer);
+
+@protected void sse_encode_operation_progress(OperationProgress self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_expected_hash(ExpectedHash? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_queued_op(QueuedOp? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_platform_info(PlatformInfo self, SseSerializer serializer);
+
+@protected void sse_encode_portalis_error(PortalisError self, SseSerializer serializer);
+
+@protected void sse_encode_queued_op(QueuedOp self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_list_prim_u_8_strict((String,Uint8List) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_regex_match(RegexMatch self, SseSerializer serializer);
+
+@protected void sse_encode_resource_stats(ResourceStats self, SseSerializer serializer);
+
+@protected void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+@protected void sse_encode_sql_value(SqlValue self, SseSerializer serializer);
+
+@protected void sse_encode_transform_op(TransformOp self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_watch_event(WatchEvent self, SseSerializer serializer);
+
+@protected void sse_encode_watch_event_kind(WatchEventKind self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelTokenPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelTokenPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelTokenPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelTokenPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasherPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasherPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasherPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasherPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRngPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRngPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRngPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRng = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSeededRngPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandlePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandlePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle(
+                ptr,
+              );
+            }
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-}
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandlePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_portalis_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandle = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTransformHandlePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
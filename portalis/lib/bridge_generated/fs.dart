@@ -0,0 +1,116 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `sidecar_path`, `sort_entries`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `eq`, `fmt`
+
+
+            Future<Uint8List>  readFile({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateFsReadFile(path: path, timeoutMs: timeoutMs);
+
+Future<void>  writeFile({required String path , required List<int> bytes , BigInt? timeoutMs }) => RustLib.instance.api.crateFsWriteFile(path: path, bytes: bytes, timeoutMs: timeoutMs);
+
+/// Streams the contents of `path` in order, `chunk_size` bytes at a time.
+///
+/// Every chunk is full except possibly the last; an empty chunk marks the end of the
+/// file. Pass 0 for [`DEFAULT_READ_CHUNK_SIZE`]. A read error part-way through ends
+/// the stream with that error, after the chunks already read.
+Stream<Uint8List>  readFileChunked({required String path , required BigInt chunkSize , BigInt? timeoutMs }) => RustLib.instance.api.crateFsReadFileChunked(path: path, chunkSize: chunkSize, timeoutMs: timeoutMs);
+
+/// Replaces `path` with `bytes` so readers see the old or the new contents, never a mix.
+///
+/// The data goes to a temporary file in the same directory, is fsynced, then renamed
+/// over `path`. With `verify`, a `<path>.sha256` sidecar is written for
+/// `read_file_verified`; without it any existing sidecar is removed so it can't go stale.
+Future<void>  writeFileAtomic({required String path , required List<int> bytes , required bool verify , BigInt? timeoutMs }) => RustLib.instance.api.crateFsWriteFileAtomic(path: path, bytes: bytes, verify: verify, timeoutMs: timeoutMs);
+
+/// Reads a file saved with `write_file_atomic(.., verify: true)`.
+///
+/// Fails with `Corrupted` if the contents no longer match the sidecar checksum, and
+/// with `NotFound` if the sidecar is missing.
+Future<Uint8List>  readFileVerified({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateFsReadFileVerified(path: path, timeoutMs: timeoutMs);
+
+/// Copies `src` to `dst`, reporting progress after every chunk.
+Stream<CopyProgress>  copyFileWithProgress({required String src , required String dst , BigInt? timeoutMs }) => RustLib.instance.api.crateFsCopyFileWithProgress(src: src, dst: dst, timeoutMs: timeoutMs);
+
+/// Lists the direct children of `path`, directories first, then by name.
+Future<List<DirEntry>>  listDir({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateFsListDir(path: path, timeoutMs: timeoutMs);
+
+Future<DirEntry>  fileMetadata({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateFsFileMetadata(path: path, timeoutMs: timeoutMs);
+
+/// Creates `path` and any missing parents; succeeds if it already exists.
+Future<void>  makeDirAll({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateFsMakeDirAll(path: path, timeoutMs: timeoutMs);
+
+/// Returns up to `max_lines` lines starting at zero-based `start_line`.
+///
+/// Only the requested page is kept in memory. The byte offset of every 1024th line is
+/// remembered per file, so paging deep into a big file reads at most 1024 extra lines;
+/// the first call, or any call after the file changed, still scans from the top.
+Future<List<String>>  readLines({required String path , required BigInt startLine , required BigInt maxLines , BigInt? timeoutMs }) => RustLib.instance.api.crateFsReadLines(path: path, startLine: startLine, maxLines: maxLines, timeoutMs: timeoutMs);
+
+/// Emits every complete line appended to `path` after the call, like `tail -f`.
+///
+/// A final line without a trailing newline is held back until it is completed. If the
+/// file is truncated, rewritten or replaced (e.g. by log rotation), following restarts
+/// from the top of whatever is there now.
+Stream<String>  tailFile({required String path }) => RustLib.instance.api.crateFsTailFile(path: path);
+
+            class CopyProgress  {
+                final BigInt bytesDone;
+final BigInt bytesTotal;
+
+                const CopyProgress({required this.bytesDone ,required this.bytesTotal ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bytesDone.hashCode^bytesTotal.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CopyProgress &&
+                runtimeType == other.runtimeType
+                && bytesDone == other.bytesDone&& bytesTotal == other.bytesTotal;
+        
+            }
+
+/// A file or directory as shown in a file browser.
+class DirEntry  {
+                final String name;
+final bool isDir;
+/// Zero for directories.
+final BigInt sizeBytes;
+/// Seconds since the Unix epoch; 0 when the platform doesn't record it.
+final PlatformInt64 modifiedUnix;
+
+                const DirEntry({required this.name ,required this.isDir ,required this.sizeBytes ,required this.modifiedUnix ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^isDir.hashCode^sizeBytes.hashCode^modifiedUnix.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DirEntry &&
+                runtimeType == other.runtimeType
+                && name == other.name&& isDir == other.isDir&& sizeBytes == other.sizeBytes&& modifiedUnix == other.modifiedUnix;
+        
+            }
+            
\ No newline at end of file
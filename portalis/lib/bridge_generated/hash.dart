@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `finalize`, `hash_slice`, `lock`, `new`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Digest`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`
+
+
+            /// Hashes an in-memory buffer and returns the lowercase hex digest.
+String  hashBytes({required HashAlgo algo , required List<int> data }) => RustLib.instance.api.crateHashHashBytes(algo: algo, data: data);
+
+/// Hashes a file in 64KB chunks so it is never fully loaded into memory.
+Future<String>  hashFile({required String path , required HashAlgo algo , BigInt? timeoutMs }) => RustLib.instance.api.crateHashHashFile(path: path, algo: algo, timeoutMs: timeoutMs);
+
+/// Hashes many files at once, spread across all CPU cores on native.
+///
+/// Results are `(path, hex digest)` in the same order as `paths`. The first file that
+/// can't be read aborts the batch with an error naming that path.
+Future<List<(String,String)>>  parallelHashFiles({required List<String> paths , required HashAlgo algo }) => RustLib.instance.api.crateHashParallelHashFiles(paths: paths, algo: algo);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Hasher>>
+                abstract class Hasher implements RustOpaqueInterface {
+                    /// Lowercase hex digest of everything fed so far.
+///
+/// The handle keeps its state, so more data can still be added afterwards.
+ String  finalize();
+
+
+factory Hasher({required HashAlgo algo })=>RustLib.instance.api.crateHashHasherNew(algo: algo);
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+enum HashAlgo {
+                    sha256,
+blake3,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,24 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// Random UUID in lowercase hyphenated form.
+String  newUuidV4() => RustLib.instance.api.crateIdsNewUuidV4();
+
+/// Time-ordered UUID, so ids sort by creation time when used as database keys.
+///
+/// Values from one process are strictly increasing, even within the same millisecond.
+String  newUuidV7() => RustLib.instance.api.crateIdsNewUuidV7();
+
+/// Accepts hyphenated, simple, braced and `urn:uuid:` forms in either case.
+bool  isValidUuid({required String s }) => RustLib.instance.api.crateIdsIsValidUuid(s: s);
+
+            
+            
\ No newline at end of file
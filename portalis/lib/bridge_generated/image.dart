@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `thumbnail`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// Scales `input` (PNG or JPEG) to fit within `max_dim`×`max_dim`, keeping the
+/// aspect ratio, and encodes it as `format`. Images already small enough are
+/// re-encoded at their original size, never upscaled.
+Future<Uint8List>  makeThumbnail({required List<int> input , required int maxDim , required ImageFormat format , BigInt? timeoutMs }) => RustLib.instance.api.crateImageMakeThumbnail(input: input, maxDim: maxDim, format: format, timeoutMs: timeoutMs);
+
+            enum ImageFormat {
+                    png,
+/// Quality 85; any transparency is flattened to black.
+jpeg,
+                    ;
+                    
+                }
+            
\ No newline at end of file
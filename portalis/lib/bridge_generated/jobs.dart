@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'hash.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'jobs.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `emit`, `run`
+
+
+            /// Queues `job` and returns its id; progress is reported on `job_events`.
+///
+/// On web there are no threads, so the job runs to completion before this returns.
+BigInt  submitJob({required JobSpec job }) => RustLib.instance.api.crateJobsSubmitJob(job: job);
+
+/// Registers the Dart stream receiving events for every job, replacing any previous one.
+Stream<JobEvent>  jobEvents() => RustLib.instance.api.crateJobsJobEvents();
+
+/// Cancels a job that has not started yet. Running or finished jobs return `false`.
+bool  cancelJob({required BigInt id }) => RustLib.instance.api.crateJobsCancelJob(id: id);
+
+            @freezed
+                sealed class JobEvent with _$JobEvent  {
+                    const JobEvent._();
+
+                     const factory JobEvent.started({   required BigInt id , }) = JobEvent_Started;
+ const factory JobEvent.completed({   required BigInt id ,  required String result , }) = JobEvent_Completed;
+ const factory JobEvent.failed({   required BigInt id ,  required String error , }) = JobEvent_Failed;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class JobSpec with _$JobSpec  {
+                    const JobSpec._();
+
+                     const factory JobSpec.hash({   required HashAlgo algo ,  required Uint8List data , }) = JobSpec_Hash;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `close`, `decode`, `derive`, `encode`, `lock_store`, `open_encrypted`, `open`, `seal`, `seal`, `unseal`, `with_store`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Cipher`, `Header`, `KdfParams`, `Open`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// Opens (or reopens) the store at `path`. On web it lives in memory until the page
+/// reloads, but reopening the same `path` still finds its values.
+///
+/// Fails if the store was created by `kv_open_encrypted`.
+Future<void>  kvOpen({required String path }) => RustLib.instance.api.crateKvKvOpen(path: path);
+
+/// Like `kv_open`, but values are encrypted at rest with a key derived from `passphrase`.
+///
+/// The key comes from Argon2id and values are sealed with ChaCha20-Poly1305, bound to
+/// their key so they can't be swapped around. Keys themselves stay plaintext, so
+/// prefix scans still work. A new store saves its random salt and KDF parameters in a
+/// header; reopening with another passphrase fails with `PermissionDenied`, and a value
+/// altered on disk makes `kv_get` fail with `Corrupted`. An existing unencrypted store
+/// with values in it is refused rather than half-encrypted.
+Future<void>  kvOpenEncrypted({required String path , required String passphrase }) => RustLib.instance.api.crateKvKvOpenEncrypted(path: path, passphrase: passphrase);
+
+void  kvSet({required String key , required List<int> value }) => RustLib.instance.api.crateKvKvSet(key: key, value: value);
+
+Uint8List?  kvGet({required String key }) => RustLib.instance.api.crateKvKvGet(key: key);
+
+void  kvDelete({required String key }) => RustLib.instance.api.crateKvKvDelete(key: key);
+
+/// Writes every entry in one transaction: either all of them land or none do.
+void  kvSetBatch({required List<(String,Uint8List)> entries }) => RustLib.instance.api.crateKvKvSetBatch(entries: entries);
+
+/// Looks up several keys at once; the result lines up with `keys`.
+List<Uint8List?>  kvGetBatch({required List<String> keys }) => RustLib.instance.api.crateKvKvGetBatch(keys: keys);
+
+/// Returns every entry whose key starts with `prefix`, sorted by key.
+List<(String,Uint8List)>  kvPrefixScan({required String prefix }) => RustLib.instance.api.crateKvKvPrefixScan(prefix: prefix);
+
+            
+            
\ No newline at end of file
@@ -6,4 +6,39 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-String getVersion() => RustLib.instance.api.crateGetVersion();
+
+            
+
+            String  getVersion() => RustLib.instance.api.crateGetVersion();
+
+BuildInfo  getBuildInfo() => RustLib.instance.api.crateGetBuildInfo();
+
+            /// Build metadata for the "About" screen. Values are baked in by `build.rs`.
+class BuildInfo  {
+                final String version;
+final String gitSha;
+/// Cargo profile the backend was compiled with (`debug` or `release`).
+final String profile;
+final String rustcVersion;
+final BigInt builtAtUnix;
+
+                const BuildInfo({required this.version ,required this.gitSha ,required this.profile ,required this.rustcVersion ,required this.builtAtUnix ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => version.hashCode^gitSha.hashCode^profile.hashCode^rustcVersion.hashCode^builtAtUnix.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BuildInfo &&
+                runtimeType == other.runtimeType
+                && version == other.version&& gitSha == other.gitSha&& profile == other.profile&& rustcVersion == other.rustcVersion&& builtAtUnix == other.builtAtUnix;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `close_stores`, `shutdown`
+
+
+            /// Call on app exit: lets in-flight async calls finish (up to `SHUTDOWN_DRAIN_MS`),
+/// then flushes and closes the kv store and the queue and checkpoints and closes the
+/// SQLite database.
+///
+/// Subsystems that were never opened are skipped, so this is safe to call at any
+/// time and more than once. Every subsystem is attempted even if an earlier one
+/// fails; the error then names each one that couldn't be flushed. Each can be
+/// reopened afterwards with its `_open` function.
+Future<void>  shutdownAll() => RustLib.instance.api.crateLifecycleShutdownAll();
+
+            
+            
\ No newline at end of file
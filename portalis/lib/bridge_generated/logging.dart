@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `as_str`, `from_record`, `now_unix_ms`, `recent_logs`, `remember`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BridgeLogger`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `enabled`, `flush`, `fmt`, `fmt`, `from`, `from`, `log`
+
+
+            /// Installs the bridge logger on first call; later calls only adjust the level.
+void  initLogging({required LogLevel level }) => RustLib.instance.api.crateLoggingInitLogging(level: level);
+
+/// Registers the Dart stream that receives log records, replacing any previous one.
+Stream<LogRecord>  createLogStream() => RustLib.instance.api.crateLoggingCreateLogStream();
+
+            enum LogLevel {
+                    error,
+warn,
+info,
+debug,
+trace,
+                    ;
+                    
+                }
+
+class LogRecord  {
+                final LogLevel level;
+final String target;
+final String message;
+final PlatformInt64 timestampMs;
+
+                const LogRecord({required this.level ,required this.target ,required this.message ,required this.timestampMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => level.hashCode^target.hashCode^message.hashCode^timestampMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogRecord &&
+                runtimeType == other.runtimeType
+                && level == other.level&& target == other.target&& message == other.message&& timestampMs == other.timestampMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `percentile`, `record`, `summary`, `time_scope`, `with_metrics`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Histogram`, `TimeScope`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `eq`, `fmt`
+
+
+            /// Adds one sample to metric `name`. Negative and non-finite values are ignored.
+void  recordTiming({required String name , required double millis }) => RustLib.instance.api.crateMetricsRecordTiming(name: name, millis: millis);
+
+/// One summary per metric recorded so far, sorted by name.
+List<MetricSummary>  exportMetrics() => RustLib.instance.api.crateMetricsExportMetrics();
+
+            /// Aggregates for one metric name, in milliseconds.
+class MetricSummary  {
+                final String name;
+final BigInt count;
+final double min;
+final double max;
+final double mean;
+/// Taken over the most recent `MAX_SAMPLES` values, so it follows current behaviour.
+final double p95;
+
+                const MetricSummary({required this.name ,required this.count ,required this.min ,required this.max ,required this.mean ,required this.p95 ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^count.hashCode^min.hashCode^max.hashCode^mean.hashCode^p95.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MetricSummary &&
+                runtimeType == other.runtimeType
+                && name == other.name&& count == other.count&& min == other.min&& max == other.max&& mean == other.mean&& p95 == other.p95;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'cancel.dart';
+import 'error.dart';
+import 'frb_generated.dart';
+import 'hash.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'net.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `backoff_ms`, `client`, `fetch`, `is_retryable_status`, `retry_after_ms`, `send`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `from`
+
+
+            /// Cancelling `token` drops the request and fails the call with `Cancelled`.
+Future<HttpResponse>  httpGet({required String url , required List<(String,String)> headers , BigInt? timeoutMs , required CancelToken token }) => RustLib.instance.api.crateNetHttpGet(url: url, headers: headers, timeoutMs: timeoutMs, token: token);
+
+/// Cancelling `token` drops the request and fails the call with `Cancelled`.
+Future<HttpResponse>  httpPost({required String url , required List<(String,String)> headers , required List<int> body , BigInt? timeoutMs , required CancelToken token }) => RustLib.instance.api.crateNetHttpPost(url: url, headers: headers, body: body, timeoutMs: timeoutMs, token: token);
+
+/// Looks up the IP addresses of `host`, in the order the resolver returned them.
+///
+/// Not available on web, where the browser never exposes DNS.
+Future<List<String>>  resolveHost({required String host , BigInt? timeoutMs }) => RustLib.instance.api.crateNetResolveHost(host: host, timeoutMs: timeoutMs);
+
+/// Returns `true` if a `HEAD` request to `url` gets any HTTP response within `timeout_ms`.
+///
+/// Error statuses still count: the server answered, so it is reachable.
+Future<bool>  checkReachable({required String url , required int timeoutMs }) => RustLib.instance.api.crateNetCheckReachable(url: url, timeoutMs: timeoutMs);
+
+/// GETs `url`, retrying connection failures, timeouts, 429 and 5xx responses.
+///
+/// A `Retry-After` header (in seconds) overrides the backoff delay. Once attempts run
+/// out, the last failure is returned with the attempt count in its message: `Network`
+/// for a retryable status (`giving up after 3 attempts: HTTP 503`) or a connection
+/// failure, `Timeout` if the last request timed out. Cancelling `token` also stops a
+/// pending backoff wait.
+Future<HttpResponse>  httpGetRetry({required String url , required List<(String,String)> headers , required RetryPolicy policy , BigInt? timeoutMs , required CancelToken token }) => RustLib.instance.api.crateNetHttpGetRetry(url: url, headers: headers, policy: policy, timeoutMs: timeoutMs, token: token);
+
+/// Downloads `url` into `dest`, streaming progress and ending with `Done` or `Error`.
+///
+/// With `resume`, an existing `dest` is treated as the start of the file: only the rest
+/// is requested (a `Range` request) and appended. If the server answers with anything
+/// but the matching partial content, `dest` is rewritten from scratch after a
+/// `Restarted` event. Without `resume` the partially written file is removed if the
+/// download fails or is cancelled; with it, the file is kept for the next attempt.
+///
+/// When given, `expected_size` and `expected_hash` are checked once the last byte is
+/// written; a mismatch is a `Corrupted` error and removes the file either way.
+///
+/// Cancelling `token` or running past `timeout_ms` ends the stream with an `Error`
+/// event and cleans up like any other failure.
+Stream<DownloadEvent>  downloadFile({required String url , required String dest , required bool resume , BigInt? expectedSize , ExpectedHash? expectedHash , BigInt? timeoutMs , required CancelToken token }) => RustLib.instance.api.crateNetDownloadFile(url: url, dest: dest, resume: resume, expectedSize: expectedSize, expectedHash: expectedHash, timeoutMs: timeoutMs, token: token);
+
+            @freezed
+                sealed class DownloadEvent with _$DownloadEvent  {
+                    const DownloadEvent._();
+
+                     /// `total` is -1 when the server does not send `Content-Length`.
+const factory DownloadEvent.progress({   required PlatformInt64 done ,  required PlatformInt64 total , }) = DownloadEvent_Progress;
+ /// The server couldn't continue a resumed download, so it started over from byte 0.
+const factory DownloadEvent.restarted() = DownloadEvent_Restarted;
+ const factory DownloadEvent.done() = DownloadEvent_Done;
+ const factory DownloadEvent.error({   required String message , }) = DownloadEvent_Error;
+
+                    
+
+                    
+                }
+
+/// Digest a finished download must have.
+class ExpectedHash  {
+                final HashAlgo algo;
+/// Hex digest; case doesn't matter.
+final String hex;
+
+                const ExpectedHash({required this.algo ,required this.hex ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => algo.hashCode^hex.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExpectedHash &&
+                runtimeType == other.runtimeType
+                && algo == other.algo&& hex == other.hex;
+        
+            }
+
+/// Response of an HTTP call. Non-2xx statuses are returned here, not as errors.
+class HttpResponse  {
+                final int status;
+final List<(String,String)> headers;
+final Uint8List body;
+
+                const HttpResponse({required this.status ,required this.headers ,required this.body ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => status.hashCode^headers.hashCode^body.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HttpResponse &&
+                runtimeType == other.runtimeType
+                && status == other.status&& headers == other.headers&& body == other.body;
+        
+            }
+
+/// How `http_get_retry` spaces out repeated attempts.
+class RetryPolicy  {
+                /// Total tries including the first; 0 is treated as 1.
+final int maxAttempts;
+/// Delay before the first retry, doubled for each one after.
+final BigInt baseDelayMs;
+/// Randomise each delay between half and all of its value so clients don't retry in sync.
+final bool jitter;
+
+                const RetryPolicy({required this.maxAttempts ,required this.baseDelayMs ,required this.jitter ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxAttempts.hashCode^baseDelayMs.hashCode^jitter.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetryPolicy &&
+                runtimeType == other.runtimeType
+                && maxAttempts == other.maxAttempts&& baseDelayMs == other.baseDelayMs&& jitter == other.jitter;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'pipeline.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `attach`, `emit`, `finished`, `has_room`, `lock`, `output_full`, `transform`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `State`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            TransformHandle  openTransform({required TransformOp op }) => RustLib.instance.api.cratePipelineOpenTransform(op: op);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TransformHandle>>
+                abstract class TransformHandle implements RustOpaqueInterface {
+                     Future<void>  feed({required List<int> chunk });
+
+
+/// Flushes the remaining output and closes the `output` stream.
+///
+/// Fails with `InvalidInput` if decompression input ends in the middle of a frame.
+ Future<void>  finish();
+
+
+/// Registers the stream receiving transformed chunks; it closes after `finish`.
+ Stream<Uint8List>  output();
+
+
+
+                    
+                }
+                
+
+@freezed
+                sealed class TransformOp with _$TransformOp  {
+                    const TransformOp._();
+
+                     /// Native only, like `CompressAlgo::Zstd`.
+const factory TransformOp.zstdCompress({   required int level , }) = TransformOp_ZstdCompress;
+ const factory TransformOp.zstdDecompress() = TransformOp_ZstdDecompress;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
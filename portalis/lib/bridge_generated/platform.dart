@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `num_cpus`
+
+
+            PlatformInfo  getPlatformInfo() => RustLib.instance.api.cratePlatformGetPlatformInfo();
+
+            /// Runtime capabilities so the UI can hide features the current platform lacks.
+class PlatformInfo  {
+                /// `std::env::consts::OS`, or `web` for wasm builds.
+final String os;
+final String arch;
+final bool isWeb;
+final bool hasFilesystem;
+final bool hasThreads;
+final int numCpus;
+
+                const PlatformInfo({required this.os ,required this.arch ,required this.isWeb ,required this.hasFilesystem ,required this.hasThreads ,required this.numCpus ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => os.hashCode^arch.hashCode^isWeb.hashCode^hasFilesystem.hashCode^hasThreads.hashCode^numCpus.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlatformInfo &&
+                runtimeType == other.runtimeType
+                && os == other.os&& arch == other.arch&& isWeb == other.isWeb&& hasFilesystem == other.hasFilesystem&& hasThreads == other.hasThreads&& numCpus == other.numCpus;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `begin`, `finish`, `import_stages`, `import`, `lock`, `new`, `report`, `send`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ProgressReporter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Sample three-stage import of a JSON Lines file into `<path>.imported.json`.
+///
+/// Validates that the file is UTF-8, parses every non-empty line as JSON, then
+/// stores them as one JSON array with `write_file_atomic`. A timeout ends the stream
+/// with a terminal event carrying the `Timeout` error, like any other failure.
+Stream<OperationProgress>  runImport({required String path , BigInt? timeoutMs }) => RustLib.instance.api.crateProgressRunImport(path: path, timeoutMs: timeoutMs);
+
+            /// Progress of a multi-stage operation, reported on a single stream.
+///
+/// `percent` covers the whole operation, never decreases and stays within 0..=100.
+/// The last event of every stream has `finished` set; `error` is filled in if the
+/// operation failed.
+class OperationProgress  {
+                final String stage;
+final int stageIndex;
+final int stageCount;
+final double percent;
+final bool finished;
+final String? error;
+
+                const OperationProgress({required this.stage ,required this.stageIndex ,required this.stageCount ,required this.percent ,required this.finished ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stage.hashCode^stageIndex.hashCode^stageCount.hashCode^percent.hashCode^finished.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OperationProgress &&
+                runtimeType == other.runtimeType
+                && stage == other.stage&& stageIndex == other.stageIndex&& stageCount == other.stageCount&& percent == other.percent&& finished == other.finished&& error == other.error;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `close`, `lock_queue`, `with_queue`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Opens (or reopens) the queue stored at `path`. On web it lives in memory until the
+/// page reloads, but reopening the same `path` still finds its ops.
+Future<void>  queueOpen({required String path }) => RustLib.instance.api.crateQueueQueueOpen(path: path);
+
+/// Appends `op` and returns its id. Ids increase, so they also give the FIFO order.
+Future<BigInt>  queueEnqueue({required QueuedOp op }) => RustLib.instance.api.crateQueueQueueEnqueue(op: op);
+
+/// The oldest op not yet acked, without removing it.
+Future<QueuedOp?>  queuePeek() => RustLib.instance.api.crateQueueQueuePeek();
+
+/// Removes op `id` once it has been replayed. Acking an unknown or already acked id is a no-op.
+Future<void>  queueAck({required BigInt id }) => RustLib.instance.api.crateQueueQueueAck(id: id);
+
+/// Streams every pending op oldest first, then closes.
+///
+/// Ops stay queued until acked, so a replay interrupted half-way resumes where it stopped.
+Stream<QueuedOp>  queueDrain() => RustLib.instance.api.crateQueueQueueDrain();
+
+            /// An action saved for later, e.g. a request made while offline.
+class QueuedOp  {
+                /// Assigned by `queue_enqueue`; whatever the caller passes in is ignored.
+final BigInt id;
+/// App-defined tag saying how to replay `payload`.
+final String kind;
+final Uint8List payload;
+
+                const QueuedOp({required this.id ,required this.kind ,required this.payload ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^kind.hashCode^payload.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueuedOp &&
+                runtimeType == other.runtimeType
+                && id == other.id&& kind == other.kind&& payload == other.payload;
+        
+            }
+            
\ No newline at end of file
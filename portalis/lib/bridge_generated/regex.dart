@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `at`, `cache`, `compile`, `new`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Utf16Offsets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `eq`, `fmt`
+
+
+            /// Returns every non-overlapping match of `pattern` in `text`, left to right.
+Future<List<RegexMatch>>  regexFindAll({required String pattern , required String text }) => RustLib.instance.api.crateRegexRegexFindAll(pattern: pattern, text: text);
+
+/// Replaces every match of `pattern` in `text`.
+///
+/// `replacement` may refer to groups as `$1` or `${name}`; write `$$` for a literal `$`.
+Future<String>  regexReplaceAll({required String pattern , required String text , required String replacement }) => RustLib.instance.api.crateRegexRegexReplaceAll(pattern: pattern, text: text, replacement: replacement);
+
+            /// One match of a pattern inside the searched text.
+///
+/// Offsets are in UTF-16 code units, the same indexing Dart `String`s use, so
+/// `text.substring(start, end)` on the Dart side yields `matched`.
+class RegexMatch  {
+                final int start;
+final int end;
+final String matched;
+/// Capture groups 1.., `None` where a group did not take part in the match.
+final List<String?> groups;
+
+                const RegexMatch({required this.start ,required this.end ,required this.matched ,required this.groups ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^matched.hashCode^groups.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RegexMatch &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& matched == other.matched&& groups == other.groups;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+
+
+            ResourceStats  getResourceStats() => RustLib.instance.api.crateResourcesGetResourceStats();
+
+            /// Snapshot of this process's resource usage for diagnostics screens.
+///
+/// What the numbers mean depends on the OS:
+/// - Linux/Android: `VmRSS`, `VmSize` and `Threads` from `/proc/self/status`.
+/// - macOS: resident and virtual size from `proc_pidinfo`; virtual size includes the
+///   shared system regions, so it is far larger than on other platforms.
+/// - Windows: working set and pagefile-backed commit charge, threads via a toolhelp
+///   snapshot.
+/// - Web, iOS and anything else: all zeros with `available: false`.
+class ResourceStats  {
+                final bool available;
+final BigInt rssBytes;
+final BigInt virtualBytes;
+final int numThreads;
+
+                const ResourceStats({required this.available ,required this.rssBytes ,required this.virtualBytes ,required this.numThreads ,});
+
+                static Future<ResourceStats>  default_()=>RustLib.instance.api.crateResourcesResourceStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => available.hashCode^rssBytes.hashCode^virtualBytes.hashCode^numThreads.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ResourceStats &&
+                runtimeType == other.runtimeType
+                && available == other.available&& rssBytes == other.rssBytes&& virtualBytes == other.virtualBytes&& numThreads == other.numThreads;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `timed_out`
+
+
+            /// Starts the shared async runtime with `worker_threads` workers (0 picks one per CPU).
+///
+/// Optional: the first async call starts it with the default size. Fails if it is
+/// already running; call `runtime_shutdown` first to resize it. A no-op on web,
+/// where everything runs on the browser's event loop.
+void  runtimeInit({required int workerThreads }) => RustLib.instance.api.crateRuntimeRuntimeInit(workerThreads: workerThreads);
+
+/// Stops the shared runtime, cancelling every task still running on it.
+///
+/// Calls waiting on those tasks fail with `PortalisError::Cancelled`; the next async
+/// call (or `runtime_init`) starts a fresh runtime. Returns `false` if none was running.
+bool  runtimeShutdown() => RustLib.instance.api.crateRuntimeRuntimeShutdown();
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `with_index`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+
+
+            /// Opens (or creates) the index stored in the directory `path`.
+Future<void>  indexOpen({required String path }) => RustLib.instance.api.crateSearchIndexOpen(path: path);
+
+/// Queues `text` under `id`, replacing any document already indexed with that id.
+///
+/// Nothing becomes searchable until `index_commit`.
+Future<void>  indexAdd({required String id , required String text }) => RustLib.instance.api.crateSearchIndexAdd(id: id, text: text);
+
+/// Persists queued documents and makes them visible to `search_query`.
+Future<void>  indexCommit() => RustLib.instance.api.crateSearchIndexCommit();
+
+/// Returns the best `limit` matches, highest score first.
+///
+/// `query` uses tantivy's syntax: words are OR-ed, `+word` is required, `"a b"` is a phrase.
+Future<List<SearchHit>>  searchQuery({required String query , required int limit }) => RustLib.instance.api.crateSearchSearchQuery(query: query, limit: limit);
+
+            class SearchHit  {
+                final String id;
+/// BM25 relevance; only meaningful relative to other hits of the same query.
+final double score;
+
+                const SearchHit({required this.id ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && id == other.id&& score == other.score;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `counters`, `flags`, `new`, `read`, `with`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Registry`
+
+
+            /// Adds `by` (which may be negative) to counter `name` and returns the new value.
+///
+/// Counters start at 0 and wrap around on overflow.
+PlatformInt64  counterIncr({required String name , required PlatformInt64 by }) => RustLib.instance.api.crateStateCounterIncr(name: name, by: by);
+
+PlatformInt64  counterGet({required String name }) => RustLib.instance.api.crateStateCounterGet(name: name);
+
+void  flagSet({required String name , required bool value }) => RustLib.instance.api.crateStateFlagSet(name: name, value: value);
+
+/// `false` for flags never set.
+bool  flagGet({required String name }) => RustLib.instance.api.crateStateFlagGet(name: name);
+
+            
+            
\ No newline at end of file
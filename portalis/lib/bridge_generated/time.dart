@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `format_items`
+
+
+            /// Milliseconds since the Unix epoch, read from the system clock.
+PlatformInt64  nowUnixMs() => RustLib.instance.api.crateTimeNowUnixMs();
+
+/// Formats `unix_ms` with a strftime-style `fmt` (e.g. `%Y-%m-%d %H:%M %Z`) in the
+/// IANA timezone `tz` (e.g. `Europe/Rome`; `UTC` also works).
+String  formatTimestamp({required PlatformInt64 unixMs , required String fmt , required String tz }) => RustLib.instance.api.crateTimeFormatTimestamp(unixMs: unixMs, fmt: fmt, tz: tz);
+
+/// Parses `s` with strftime-style `fmt` into milliseconds since the Unix epoch.
+///
+/// If `fmt` has no offset (`%z`/`%:z`), the time is taken to be UTC.
+PlatformInt64  parseTimestamp({required String s , required String fmt }) => RustLib.instance.api.crateTimeParseTimestamp(s: s, fmt: fmt);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            /// Streams changes under `path` (a file, or a directory and, with `recursive`, its subtree).
+///
+/// The first raw event opens a `debounce_ms` window; everything that arrives within it
+/// is merged into one event per path, emitted when the window closes. A file created
+/// and then written reports `Created`; one deleted and recreated reports `Modified`.
+/// Runs until the Dart stream is closed. Not available on web.
+Stream<WatchEvent>  watchPath({required String path , required bool recursive , required BigInt debounceMs }) => RustLib.instance.api.crateWatchWatchPath(path: path, recursive: recursive, debounceMs: debounceMs);
+
+            /// A change to one path, standing for every raw event it saw within the debounce window.
+class WatchEvent  {
+                final String path;
+final WatchEventKind kind;
+
+                const WatchEvent({required this.path ,required this.kind ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^kind.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WatchEvent &&
+                runtimeType == other.runtimeType
+                && path == other.path&& kind == other.kind;
+        
+            }
+
+enum WatchEventKind {
+                    created,
+modified,
+removed,
+                    ;
+                    
+                }
+            
\ No newline at end of file
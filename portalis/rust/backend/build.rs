@@ -0,0 +1,55 @@
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Capture build metadata as compile-time env vars so `get_build_info` works
+// the same on every target, including web where there is no filesystem or git.
+fn main() {
+    println!("cargo:rerun-if-env-changed=PORTALIS_GIT_SHA");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    let git_sha = std::env::var("PORTALIS_GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.is_empty())
+        .or_else(git_head_sha)
+        .unwrap_or_else(|| "unknown".to_string());
+
+    // Honour SOURCE_DATE_EPOCH for reproducible builds.
+    let built_at = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default()
+        });
+
+    let profile = std::env::var("PROFILE").unwrap_or_else(|_| "unknown".to_string());
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version =
+        command_output(&rustc, &["--version"]).unwrap_or_else(|| "unknown".to_string());
+
+    if let Some(git_dir) = command_output("git", &["rev-parse", "--absolute-git-dir"]) {
+        println!("cargo:rerun-if-changed={git_dir}/HEAD");
+        println!("cargo:rerun-if-changed={git_dir}/refs");
+    }
+
+    println!("cargo:rustc-env=PORTALIS_GIT_SHA={git_sha}");
+    println!("cargo:rustc-env=PORTALIS_BUILT_AT_UNIX={built_at}");
+    println!("cargo:rustc-env=PORTALIS_PROFILE={profile}");
+    println!("cargo:rustc-env=PORTALIS_RUSTC_VERSION={rustc_version}");
+}
+
+fn git_head_sha() -> Option<String> {
+    command_output("git", &["rev-parse", "HEAD"])
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
@@ -0,0 +1,442 @@
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::{metrics, runtime};
+
+/// Entries written or extracted so far, reported after each one.
+pub struct ArchiveProgress {
+    pub entries_done: u32,
+    pub entries_total: u32,
+}
+
+/// Writes a zip at `out` from `(archive path, source path)` pairs, deflating every file.
+///
+/// Archive paths use `/` separators and must be relative without `..` components.
+/// If the call fails or times out, `out` is removed rather than left half-written
+/// (after a timeout, once the writer finishes its current entry).
+pub async fn zip_create(
+    files: Vec<(String, String)>,
+    out: String,
+    timeout_ms: Option<u64>,
+) -> Result<(), PortalisError> {
+    let _timing = metrics::time_scope("zip_create");
+    runtime::with_timeout(timeout_ms, sys::zip_create(files, out, |_| {})).await
+}
+
+/// Like `zip_create`, streaming progress after every entry.
+pub async fn zip_create_with_progress(
+    files: Vec<(String, String)>,
+    out: String,
+    timeout_ms: Option<u64>,
+    sink: StreamSink<ArchiveProgress>,
+) -> Result<(), PortalisError> {
+    let _timing = metrics::time_scope("zip_create");
+    runtime::with_timeout(
+        timeout_ms,
+        sys::zip_create(files, out, move |progress| {
+            let _ = sink.add(progress);
+        }),
+    )
+    .await
+}
+
+/// Extracts every entry of `archive` under `dest`, creating directories as needed.
+///
+/// The whole archive is rejected with `InvalidInput`, before anything is written,
+/// if any entry would land outside `dest` (absolute paths, drive letters or `..`, i.e.
+/// zip-slip). After a timeout, extraction stops once the current entry is written;
+/// entries already extracted are left in place.
+pub async fn zip_extract(
+    archive: String,
+    dest: String,
+    timeout_ms: Option<u64>,
+) -> Result<(), PortalisError> {
+    let _timing = metrics::time_scope("zip_extract");
+    runtime::with_timeout(timeout_ms, sys::zip_extract(archive, dest, |_| {})).await
+}
+
+/// Like `zip_extract`, streaming progress after every entry.
+pub async fn zip_extract_with_progress(
+    archive: String,
+    dest: String,
+    timeout_ms: Option<u64>,
+    sink: StreamSink<ArchiveProgress>,
+) -> Result<(), PortalisError> {
+    let _timing = metrics::time_scope("zip_extract");
+    runtime::with_timeout(
+        timeout_ms,
+        sys::zip_extract(archive, dest, move |progress| {
+            let _ = sink.add(progress);
+        }),
+    )
+    .await
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::path::Path;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use zip::result::ZipError;
+    use zip::write::SimpleFileOptions;
+
+    use super::ArchiveProgress;
+    use crate::error::PortalisError;
+
+    impl From<ZipError> for PortalisError {
+        fn from(err: ZipError) -> Self {
+            match err {
+                ZipError::Io(err) => err.into(),
+                ZipError::InvalidArchive(_) => PortalisError::Corrupted(err.to_string()),
+                ZipError::UnsupportedArchive(_) => PortalisError::Unsupported(err.to_string()),
+                ZipError::FileNotFound => PortalisError::NotFound(err.to_string()),
+                other => PortalisError::Internal(other.to_string()),
+            }
+        }
+    }
+
+    /// Rejects names that would escape the extraction directory.
+    fn check_entry_name(name: &str) -> Result<(), PortalisError> {
+        // A drive prefix like `C:` is absolute on Windows; a colon elsewhere is just a
+        // character, as in `12:30 notes.txt`.
+        let drive = matches!(name.as_bytes(), [letter, b':', ..] if letter.is_ascii_alphabetic());
+        let escapes = name.starts_with('/')
+            || name.starts_with('\\')
+            || drive
+            || name.split(['/', '\\']).any(|part| part == "..");
+        if name.is_empty() || escapes {
+            return Err(PortalisError::InvalidInput(format!(
+                "archive entry {name:?} escapes the destination directory"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Raises its flag when dropped, i.e. when the caller stops waiting for the writer.
+    struct SetOnDrop(Arc<AtomicBool>);
+
+    impl Drop for SetOnDrop {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Runs `work` on a blocking thread with a flag that is raised once the caller stops
+    /// waiting, e.g. after a timeout. Dropping the task's handle doesn't stop the thread,
+    /// so `work` has to check for itself whether anyone still wants the result.
+    async fn spawn_abandonable<T: Send + 'static>(
+        work: impl FnOnce(&AtomicBool) -> Result<T, PortalisError> + Send + 'static,
+    ) -> Result<T, PortalisError> {
+        let abandoned = Arc::new(AtomicBool::new(false));
+        let _abandon = SetOnDrop(abandoned.clone());
+        tokio::task::spawn_blocking(move || work(&abandoned)).await?
+    }
+
+    /// Checked between entries, so abandoned work stops at the next entry boundary.
+    fn check_abandoned(abandoned: &AtomicBool, what: &str) -> Result<(), PortalisError> {
+        if abandoned.load(Ordering::SeqCst) {
+            return Err(PortalisError::Cancelled(what.to_string()));
+        }
+        Ok(())
+    }
+
+    pub(super) async fn zip_create(
+        files: Vec<(String, String)>,
+        out: String,
+        on_progress: impl FnMut(ArchiveProgress) + Send + 'static,
+    ) -> Result<(), PortalisError> {
+        spawn_abandonable(move |abandoned| {
+            let result = create_blocking(&files, &out, abandoned, on_progress);
+            if result.is_err() || abandoned.load(Ordering::SeqCst) {
+                let _ = std::fs::remove_file(&out);
+            }
+            result
+        })
+        .await
+    }
+
+    fn create_blocking(
+        files: &[(String, String)],
+        out: &str,
+        abandoned: &AtomicBool,
+        mut on_progress: impl FnMut(ArchiveProgress),
+    ) -> Result<(), PortalisError> {
+        for (name, _) in files {
+            check_entry_name(name)?;
+        }
+        let entries_total = files.len() as u32;
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(out)?);
+        on_progress(ArchiveProgress {
+            entries_done: 0,
+            entries_total,
+        });
+        for (i, (name, source)) in files.iter().enumerate() {
+            check_abandoned(abandoned, &format!("writing {out}"))?;
+            let mut reader =
+                std::fs::File::open(source).map_err(|e| PortalisError::from(e).context(source))?;
+            writer.start_file(name.as_str(), options)?;
+            std::io::copy(&mut reader, &mut writer)?;
+            on_progress(ArchiveProgress {
+                entries_done: i as u32 + 1,
+                entries_total,
+            });
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    pub(super) async fn zip_extract(
+        archive: String,
+        dest: String,
+        on_progress: impl FnMut(ArchiveProgress) + Send + 'static,
+    ) -> Result<(), PortalisError> {
+        spawn_abandonable(move |abandoned| {
+            extract_blocking(&archive, Path::new(&dest), abandoned, on_progress)
+        })
+        .await
+    }
+
+    fn extract_blocking(
+        archive: &str,
+        dest: &Path,
+        abandoned: &AtomicBool,
+        mut on_progress: impl FnMut(ArchiveProgress),
+    ) -> Result<(), PortalisError> {
+        let mut zip = zip::ZipArchive::new(std::fs::File::open(archive)?)?;
+        // Check every name before writing anything so a bad archive leaves no trace.
+        for name in zip.file_names() {
+            check_entry_name(name)?;
+        }
+        let entries_total = zip.len() as u32;
+        on_progress(ArchiveProgress {
+            entries_done: 0,
+            entries_total,
+        });
+        for i in 0..zip.len() {
+            check_abandoned(abandoned, &format!("extracting {archive}"))?;
+            let mut entry = zip.by_index(i)?;
+            // Belt and braces: the crate's own check also catches names we don't anticipate.
+            let relative = entry.enclosed_name().ok_or_else(|| {
+                PortalisError::InvalidInput(format!(
+                    "archive entry {:?} escapes the destination directory",
+                    entry.name()
+                ))
+            })?;
+            let target = dest.join(relative);
+            if entry.is_dir() {
+                std::fs::create_dir_all(&target)?;
+            } else {
+                if let Some(parent) = target.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let mut file = std::fs::File::create(&target)?;
+                std::io::copy(&mut entry, &mut file)?;
+            }
+            on_progress(ArchiveProgress {
+                entries_done: i as u32 + 1,
+                entries_total,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::ArchiveProgress;
+    use crate::error::PortalisError;
+
+    pub(super) async fn zip_create(
+        _files: Vec<(String, String)>,
+        _out: String,
+        _on_progress: impl FnMut(ArchiveProgress),
+    ) -> Result<(), PortalisError> {
+        Err(PortalisError::unsupported_on_web("zip archives"))
+    }
+
+    pub(super) async fn zip_extract(
+        _archive: String,
+        _dest: String,
+        _on_progress: impl FnMut(ArchiveProgress),
+    ) -> Result<(), PortalisError> {
+        Err(PortalisError::unsupported_on_web("zip archives"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn path_str(path: &std::path::Path) -> String {
+        path.to_string_lossy().into_owned()
+    }
+
+    #[tokio::test]
+    async fn create_then_extract_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let notes = dir.path().join("notes.txt");
+        let blob = dir.path().join("blob.bin");
+        let blob_data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&notes, "héllo").unwrap();
+        std::fs::write(&blob, &blob_data).unwrap();
+        let out = path_str(&dir.path().join("export.zip"));
+
+        sys::zip_create(
+            vec![
+                ("notes.txt".into(), path_str(&notes)),
+                ("data/nested/blob.bin".into(), path_str(&blob)),
+            ],
+            out.clone(),
+            |_| {},
+        )
+        .await
+        .unwrap();
+        let dest = dir.path().join("unpacked");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        sys::zip_extract(out, path_str(&dest), move |p: ArchiveProgress| {
+            let _ = tx.send((p.entries_done, p.entries_total));
+        })
+        .await
+        .unwrap();
+        let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+
+        assert_eq!(events, [(0, 2), (1, 2), (2, 2)]);
+        assert_eq!(
+            std::fs::read_to_string(dest.join("notes.txt")).unwrap(),
+            "héllo"
+        );
+        assert_eq!(
+            std::fs::read(dest.join("data/nested/blob.bin")).unwrap(),
+            blob_data
+        );
+    }
+
+    #[tokio::test]
+    async fn zip_slip_entries_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("evil.zip");
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(&archive).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file("fine.txt", options).unwrap();
+        writer.write_all(b"ok").unwrap();
+        writer.start_file("../../escaped.txt", options).unwrap();
+        writer.write_all(b"pwned").unwrap();
+        writer.finish().unwrap();
+        let dest = dir.path().join("out");
+
+        let err = zip_extract(path_str(&archive), path_str(&dest), None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code(), "invalid_input");
+        assert!(err.to_string().contains("escaped.txt"), "{err}");
+        assert!(!dest.join("fine.txt").exists());
+        assert!(!dir.path().join("escaped.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn create_rejects_escaping_names_and_missing_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = path_str(&dir.path().join("out.zip"));
+        let source = dir.path().join("a.txt");
+        std::fs::write(&source, "a").unwrap();
+
+        for name in ["../a.txt", "/etc/a.txt", "C:\\a.txt"] {
+            let err = zip_create(vec![(name.into(), path_str(&source))], out.clone(), None)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), "invalid_input", "{name}");
+        }
+
+        let missing = path_str(&dir.path().join("missing.txt"));
+        let err = zip_create(vec![("m.txt".into(), missing.clone())], out.clone(), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "not_found");
+        assert!(err.to_string().contains(&missing), "{err}");
+        assert!(!std::path::Path::new(&out).exists());
+    }
+
+    #[tokio::test]
+    async fn timed_out_create_leaves_no_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("chunk.bin");
+        let noise: Vec<u8> = (0..1_000_000u32).map(|_| fastrand::u8(..)).collect();
+        std::fs::write(&source, noise).unwrap();
+        let files = (0..200)
+            .map(|i| (format!("part{i}.bin"), path_str(&source)))
+            .collect();
+        let out = dir.path().join("slow.zip");
+
+        let err = zip_create(files, path_str(&out), Some(20))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "timeout");
+
+        // The writer notices between entries, so give it a moment to clean up.
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while out.exists() && std::time::Instant::now() < deadline {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert!(!out.exists());
+    }
+
+    #[tokio::test]
+    async fn colons_are_fine_outside_a_drive_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("a.txt");
+        std::fs::write(&source, "lunch").unwrap();
+        let out = path_str(&dir.path().join("notes.zip"));
+
+        let files = vec![("12:30 notes.txt".into(), path_str(&source))];
+        zip_create(files, out.clone(), None).await.unwrap();
+        let dest = dir.path().join("out");
+        zip_extract(out, path_str(&dest), None).await.unwrap();
+
+        let text = std::fs::read_to_string(dest.join("12:30 notes.txt")).unwrap();
+        assert_eq!(text, "lunch");
+    }
+
+    #[tokio::test]
+    async fn timed_out_extract_stops_writing_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("big.zip");
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(&archive).unwrap());
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        let noise: Vec<u8> = (0..256 * 1024).map(|_| fastrand::u8(..)).collect();
+        let entries = 400;
+        for i in 0..entries {
+            writer.start_file(format!("part{i}.bin"), options).unwrap();
+            writer.write_all(&noise).unwrap();
+        }
+        writer.finish().unwrap();
+        let dest = dir.path().join("out");
+
+        let err = zip_extract(path_str(&archive), path_str(&dest), Some(20))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "timeout");
+
+        // Wait until the extracting thread has stopped, then check it stopped early.
+        let count = || std::fs::read_dir(&dest).map_or(0, |d| d.count());
+        let mut last = usize::MAX;
+        while count() != last {
+            last = count();
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        }
+        assert!(
+            last < entries,
+            "extracted all {last} entries after the timeout"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, OnceLock};
+
+use flutter_rust_bridge::frb;
+
+use crate::error::PortalisError;
+
+/// Counters since the last `cache_init`.
+#[derive(Debug, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Live entries; expired ones are not counted.
+    pub size: u64,
+}
+
+/// Settings used when `cache_put`/`cache_get` run before any `cache_init`.
+const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+// The cache is a process-wide global so every call, from any isolate, shares it.
+static CACHE: Mutex<Option<Cache>> = Mutex::new(None);
+
+fn with_cache<T>(f: impl FnOnce(&mut Cache) -> T) -> T {
+    // Losing cached values to a panic is harmless, so a poisoned lock is fine.
+    let mut guard = CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(guard.get_or_insert_with(|| {
+        Cache::new(non_zero(DEFAULT_MAX_ENTRIES), 0, Box::new(monotonic_ms))
+    }))
+}
+
+// All sync: each call is a map lookup under a lock, cheaper than a worker hop.
+
+/// Replaces the cache with an empty one holding at most `max_entries` values.
+///
+/// Entries expire `ttl_ms` after they were put; 0 means they never expire.
+#[frb(sync)]
+pub fn cache_init(max_entries: usize, ttl_ms: u64) -> Result<(), PortalisError> {
+    let max_entries = NonZeroUsize::new(max_entries)
+        .ok_or_else(|| PortalisError::InvalidInput("max_entries must be at least 1".into()))?;
+    let cache = Cache::new(max_entries, ttl_ms, Box::new(monotonic_ms));
+    *CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(cache);
+    Ok(())
+}
+
+/// Stores `value`, evicting the least recently used entry if the cache is full.
+#[frb(sync)]
+pub fn cache_put(key: String, value: Vec<u8>) {
+    with_cache(|cache| cache.put(key, value));
+}
+
+#[frb(sync)]
+pub fn cache_get(key: String) -> Option<Vec<u8>> {
+    with_cache(|cache| cache.get(&key))
+}
+
+#[frb(sync)]
+pub fn cache_stats() -> CacheStats {
+    with_cache(|cache| cache.stats())
+}
+
+fn non_zero(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).expect("non-zero")
+}
+
+/// Milliseconds since the first call; never jumps when the wall clock is changed.
+fn monotonic_ms() -> u64 {
+    static START: OnceLock<web_time::Instant> = OnceLock::new();
+    START
+        .get_or_init(web_time::Instant::now)
+        .elapsed()
+        .as_millis() as u64
+}
+
+type Clock = Box<dyn Fn() -> u64 + Send>;
+
+struct Entry {
+    value: Vec<u8>,
+    stored_at_ms: u64,
+}
+
+/// LRU map with optional per-entry expiry, reading time from an injectable clock.
+struct Cache {
+    entries: lru::LruCache<String, Entry>,
+    ttl_ms: u64,
+    clock: Clock,
+    hits: u64,
+    misses: u64,
+}
+
+impl Cache {
+    fn new(max_entries: NonZeroUsize, ttl_ms: u64, clock: Clock) -> Self {
+        Cache {
+            entries: lru::LruCache::new(max_entries),
+            ttl_ms,
+            clock,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn is_expired(&self, entry: &Entry, now_ms: u64) -> bool {
+        self.ttl_ms != 0 && now_ms.saturating_sub(entry.stored_at_ms) >= self.ttl_ms
+    }
+
+    fn put(&mut self, key: String, value: Vec<u8>) {
+        let stored_at_ms = (self.clock)();
+        self.entries.put(
+            key,
+            Entry {
+                value,
+                stored_at_ms,
+            },
+        );
+    }
+
+    fn get(&mut self, key: &str) -> Option<Vec<u8>> {
+        let now_ms = (self.clock)();
+        let expired = match self.entries.peek(key) {
+            Some(entry) => self.is_expired(entry, now_ms),
+            None => {
+                self.misses += 1;
+                return None;
+            }
+        };
+        if expired {
+            self.entries.pop(key);
+            self.misses += 1;
+            return None;
+        }
+        self.hits += 1;
+        self.entries.get(key).map(|entry| entry.value.clone())
+    }
+
+    fn stats(&mut self) -> CacheStats {
+        let now_ms = (self.clock)();
+        let expired: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| self.is_expired(entry, now_ms))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in expired {
+            self.entries.pop(&key);
+        }
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.entries.len() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+
+    /// A cache whose clock only moves when the test advances the returned counter.
+    fn manual_cache(max_entries: usize, ttl_ms: u64) -> (Cache, Arc<AtomicU64>) {
+        let now = Arc::new(AtomicU64::new(0));
+        let clock = now.clone();
+        let cache = Cache::new(
+            non_zero(max_entries),
+            ttl_ms,
+            Box::new(move || clock.load(Ordering::SeqCst)),
+        );
+        (cache, now)
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let (mut cache, _now) = manual_cache(2, 0);
+        cache.put("a".into(), vec![1]);
+        cache.put("b".into(), vec![2]);
+        // Reading `a` makes `b` the least recently used.
+        assert_eq!(cache.get("a"), Some(vec![1]));
+        cache.put("c".into(), vec![3]);
+
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a"), Some(vec![1]));
+        assert_eq!(cache.get("c"), Some(vec![3]));
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 3,
+                misses: 1,
+                size: 2
+            }
+        );
+    }
+
+    #[test]
+    fn entries_expire_after_ttl() {
+        let (mut cache, now) = manual_cache(8, 100);
+        cache.put("old".into(), vec![1]);
+        now.store(60, Ordering::SeqCst);
+        cache.put("new".into(), vec![2]);
+
+        now.store(99, Ordering::SeqCst);
+        assert_eq!(cache.get("old"), Some(vec![1]));
+        now.store(100, Ordering::SeqCst);
+        assert_eq!(cache.get("old"), None);
+        assert_eq!(cache.get("new"), Some(vec![2]));
+
+        now.store(160, Ordering::SeqCst);
+        assert_eq!(cache.stats().size, 0);
+    }
+
+    #[test]
+    fn zero_ttl_never_expires() {
+        let (mut cache, now) = manual_cache(8, 0);
+        cache.put("k".into(), vec![1]);
+        now.store(u64::MAX, Ordering::SeqCst);
+        assert_eq!(cache.get("k"), Some(vec![1]));
+    }
+
+    #[test]
+    fn global_cache_round_trips_and_rejects_zero_capacity() {
+        assert_eq!(cache_init(0, 0).unwrap_err().code(), "invalid_input");
+
+        cache_init(4, 0).unwrap();
+        cache_put("answer".into(), vec![42]);
+        assert_eq!(cache_get("answer".into()), Some(vec![42]));
+        assert_eq!(cache_get("question".into()), None);
+        assert_eq!(
+            cache_stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                size: 1
+            }
+        );
+    }
+}
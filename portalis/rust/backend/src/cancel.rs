@@ -0,0 +1,149 @@
+use std::future::Future;
+
+use flutter_rust_bridge::frb;
+
+use crate::error::PortalisError;
+
+/// Cancellation signal Dart creates and passes into long-running async calls.
+///
+/// Operations that observe a cancelled token stop early and return
+/// `PortalisError::Cancelled`. Cancelling is sticky: a token cannot be reset.
+#[frb(opaque)]
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    inner: sys::Token,
+}
+
+impl CancelToken {
+    #[frb(sync)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[frb(sync)]
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    #[frb(sync)]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+
+    /// Drives `fut` to completion unless the token is cancelled first, in which
+    /// case `fut` is dropped and `Cancelled` names what was interrupted.
+    ///
+    /// This is how async API functions taking a `&CancelToken` honour it; see
+    /// [`guarded`] for futures that must move onto the shared runtime.
+    #[frb(ignore)]
+    pub async fn guard<T>(
+        &self,
+        what: &str,
+        fut: impl Future<Output = Result<T, PortalisError>>,
+    ) -> Result<T, PortalisError> {
+        match self.inner.run_until_cancelled(fut).await {
+            Some(result) => result,
+            None => Err(PortalisError::Cancelled(what.to_string())),
+        }
+    }
+}
+
+/// `token.guard(what, fut)` with everything owned, so the whole call can move onto
+/// the shared runtime. API functions take `&CancelToken` and pass a clone.
+pub(crate) async fn guarded<T>(
+    token: CancelToken,
+    what: String,
+    fut: impl Future<Output = Result<T, PortalisError>>,
+) -> Result<T, PortalisError> {
+    token.guard(&what, fut).await
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    pub(super) type Token = tokio_util::sync::CancellationToken;
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use std::future::Future;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    /// Web has a single thread and no tokio reactor, so this only checks the flag
+    /// before starting; code that loops should poll `is_cancelled` between steps.
+    #[derive(Clone, Default)]
+    pub(super) struct Token {
+        cancelled: Arc<AtomicBool>,
+    }
+
+    impl Token {
+        pub(super) fn cancel(&self) {
+            self.cancelled.store(true, Ordering::SeqCst);
+        }
+
+        pub(super) fn is_cancelled(&self) -> bool {
+            self.cancelled.load(Ordering::SeqCst)
+        }
+
+        pub(super) async fn run_until_cancelled<F: Future>(&self, fut: F) -> Option<F::Output> {
+            if self.is_cancelled() {
+                return None;
+            }
+            Some(fut.await)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn clones_share_cancellation() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn cancel_interrupts_sleeping_task() {
+        let token = CancelToken::new();
+        let task = tokio::spawn({
+            let token = token.clone();
+            async move {
+                token
+                    .guard("sleep", async {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        Ok(())
+                    })
+                    .await
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        token.cancel();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), task)
+            .await
+            .expect("task should finish promptly once cancelled")
+            .unwrap();
+        assert_eq!(result.unwrap_err().code(), "cancelled");
+    }
+
+    #[tokio::test]
+    async fn uncancelled_work_completes() {
+        let token = CancelToken::new();
+        let value = token.guard("add", async { Ok(2 + 2) }).await.unwrap();
+        assert_eq!(value, 4);
+    }
+}
@@ -0,0 +1,787 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::events::{self, AppEvent};
+use crate::runtime;
+
+/// Schema version written by this build. Bump it and add a step to `migrate`
+/// whenever `AppConfig` changes shape.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppConfig {
+    pub schema_version: u32,
+    /// `light`, `dark` or `system`. Replaced the v1 `dark_mode` flag.
+    pub theme: String,
+    pub locale: String,
+    pub telemetry_enabled: bool,
+    #[serde(default)]
+    pub recent_files: Vec<String>,
+}
+
+// Sync: config blobs are small and parsing them must not need a web worker.
+#[frb(sync)]
+pub fn parse_config(json: String) -> Result<AppConfig, PortalisError> {
+    let value: Value = serde_json::from_str(&json)
+        .map_err(|e| PortalisError::InvalidInput(format!("malformed config JSON: {e}")))?;
+    config_from_value(value)
+}
+
+#[frb(sync)]
+pub fn serialize_config(cfg: AppConfig) -> String {
+    serde_json::to_string(&cfg).expect("AppConfig only contains JSON-safe types")
+}
+
+/// Loads a config file, picking the format from its extension: `.toml`, `.yaml`/`.yml`
+/// or `.json`. Older schema versions are migrated as in `parse_config`.
+///
+/// Not available on web; pass the JSON to `parse_config` instead.
+pub async fn load_config_file(
+    path: String,
+    timeout_ms: Option<u64>,
+) -> Result<AppConfig, PortalisError> {
+    runtime::with_timeout(timeout_ms, async move { sys::load(&path).await }).await
+}
+
+/// Emits the config at `path` now and again every time the file changes to a new
+/// valid config. Edits that fail to parse are logged and skipped, so the stream only
+/// ever carries usable configs.
+pub async fn watch_config(path: String, sink: StreamSink<AppConfig>) -> Result<(), PortalisError> {
+    runtime::run(async move {
+        sys::watch(&path, |cfg| {
+            events::publish(AppEvent::ConfigReloaded { path: path.clone() });
+            sink.add(cfg).is_ok()
+        })
+        .await
+    })
+    .await
+}
+
+/// One element of the top-level array read by `parse_json_array_stream`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonArrayElement {
+    /// 0-based position in the array, counting malformed elements too.
+    pub index: u64,
+    /// Byte offset in the file where the element starts.
+    pub offset: u64,
+    /// The element re-serialized as compact JSON; empty when `error` is set.
+    pub json: String,
+    pub error: Option<String>,
+}
+
+/// Streams each element of the JSON array in `path`, holding only one element in memory.
+///
+/// A malformed element is emitted with `error` set (including its byte offset) and
+/// parsing resumes at the next top-level comma. If a broken element swallowed later
+/// ones (it fails to parse, or the file ends inside it), parsing resumes at the first
+/// line within it that starts a `{`/`[` at the element's own column after a comma.
+/// A file that isn't an array at all, or a read failure, ends the stream with an error.
+/// Not available on web.
+pub async fn parse_json_array_stream(
+    path: String,
+    timeout_ms: Option<u64>,
+    sink: StreamSink<JsonArrayElement>,
+) -> Result<(), PortalisError> {
+    runtime::with_timeout(
+        timeout_ms,
+        sys::parse_json_array(path, move |element| sink.add(element).is_ok()),
+    )
+    .await
+}
+
+/// Shared tail of every loader: migrate, deserialize, then check field values.
+fn config_from_value(value: Value) -> Result<AppConfig, PortalisError> {
+    let value = migrate(value)?;
+    let cfg: AppConfig = serde_path_to_error::deserialize(value).map_err(|e| {
+        PortalisError::InvalidInput(format!("invalid config at `{}`: {}", e.path(), e.inner()))
+    })?;
+    validate(&cfg)?;
+    Ok(cfg)
+}
+
+fn validate(cfg: &AppConfig) -> Result<(), PortalisError> {
+    if !["light", "dark", "system"].contains(&cfg.theme.as_str()) {
+        return Err(PortalisError::InvalidInput(format!(
+            "invalid config at `theme`: expected light, dark or system, got {:?}",
+            cfg.theme
+        )));
+    }
+    Ok(())
+}
+
+/// Upgrades a raw config document step by step until it matches `CURRENT_SCHEMA_VERSION`.
+fn migrate(mut value: Value) -> Result<Value, PortalisError> {
+    loop {
+        let version = value
+            .get("schema_version")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| PortalisError::InvalidInput("missing schema_version".into()))?;
+        match version {
+            1 => value = migrate_v1_to_v2(value)?,
+            v if v == u64::from(CURRENT_SCHEMA_VERSION) => return Ok(value),
+            other => {
+                return Err(PortalisError::InvalidInput(format!(
+                    "unsupported schema_version {other}"
+                )))
+            }
+        }
+    }
+}
+
+/// v2 replaced the boolean `dark_mode` with a `theme` name.
+fn migrate_v1_to_v2(mut value: Value) -> Result<Value, PortalisError> {
+    let obj = value
+        .as_object_mut()
+        .ok_or_else(|| PortalisError::InvalidInput("config must be a JSON object".into()))?;
+    let dark = obj
+        .remove("dark_mode")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    obj.insert(
+        "theme".into(),
+        Value::from(if dark { "dark" } else { "light" }),
+    );
+    obj.insert("schema_version".into(), Value::from(2));
+    Ok(value)
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::path::Path;
+
+    use notify::{RecursiveMode, Watcher};
+    use serde_json::Value;
+
+    use super::{config_from_value, AppConfig, JsonArrayElement};
+    use crate::error::PortalisError;
+
+    pub(super) async fn parse_json_array(
+        path: String,
+        emit: impl FnMut(JsonArrayElement) -> bool + Send + 'static,
+    ) -> Result<(), PortalisError> {
+        tokio::task::spawn_blocking(move || scan_json_array(std::fs::File::open(&path)?, emit))
+            .await?
+    }
+
+    /// Splits a top-level JSON array into elements by tracking nesting and string state,
+    /// then parses each element on its own so one bad element doesn't sink the rest.
+    ///
+    /// Runs until the array ends or `emit` returns `false` (the Dart stream was closed).
+    pub(super) fn scan_json_array(
+        input: impl std::io::Read,
+        mut emit: impl FnMut(JsonArrayElement) -> bool,
+    ) -> Result<(), PortalisError> {
+        use std::io::BufRead;
+
+        let mut reader = std::io::BufReader::new(input);
+        let mut offset = 0u64;
+        let mut splitter = Splitter::new();
+        // Bytes handed back by a resync, and the offset of the first one.
+        let mut replay = (0u64, Vec::new());
+
+        loop {
+            if !replay.1.is_empty() {
+                let (from, bytes) = std::mem::take(&mut replay);
+                for (i, &byte) in bytes.iter().enumerate() {
+                    match splitter.feed(from + i as u64, byte, &mut emit)? {
+                        Flow::Next => {}
+                        Flow::Done => return Ok(()),
+                        Flow::Replay(at, mut again) => {
+                            again.extend_from_slice(&bytes[i + 1..]);
+                            replay = (at, again);
+                            break;
+                        }
+                    }
+                }
+                continue;
+            }
+            let buf = reader.fill_buf()?;
+            if buf.is_empty() {
+                match splitter.end(&mut emit) {
+                    Some(Flow::Replay(at, again)) => {
+                        replay = (at, again);
+                        continue;
+                    }
+                    Some(_) => return Ok(()),
+                    None => {
+                        let what = if splitter.opened {
+                            "unterminated array"
+                        } else {
+                            "empty input"
+                        };
+                        return Err(PortalisError::InvalidInput(format!(
+                            "{what} at byte {offset}"
+                        )));
+                    }
+                }
+            }
+            let mut used = buf.len();
+            for (i, &byte) in buf.iter().enumerate() {
+                match splitter.feed(offset + i as u64, byte, &mut emit)? {
+                    Flow::Next => {}
+                    Flow::Done => return Ok(()),
+                    Flow::Replay(at, again) => {
+                        replay = (at, again);
+                        used = i + 1;
+                        break;
+                    }
+                }
+            }
+            offset += used as u64;
+            reader.consume(used);
+        }
+    }
+
+    /// What `scan_json_array` does after handing a byte to the `Splitter`.
+    enum Flow {
+        Next,
+        /// The array ended, or `emit` asked to stop.
+        Done,
+        /// Scan these bytes, starting at this offset, before reading any further.
+        Replay(u64, Vec<u8>),
+    }
+
+    /// Byte-at-a-time state of `scan_json_array`.
+    struct Splitter {
+        opened: bool,
+        element: Vec<u8>,
+        start: u64,
+        index: u64,
+        in_string: bool,
+        escaped: bool,
+        /// Expected closing brackets of the current element, innermost last.
+        closers: Vec<u8>,
+        /// Where the current line starts, and the column the current element starts at.
+        line_start: u64,
+        column: u64,
+        /// The element's last non-whitespace byte, and whether a newline has come since.
+        last: u8,
+        fresh_line: bool,
+        /// Positions in `element` where the next element may begin if this one turns
+        /// out to be broken. Only used then, so they can't split a valid element.
+        resume: Vec<usize>,
+    }
+
+    impl Splitter {
+        fn new() -> Self {
+            Splitter {
+                opened: false,
+                element: Vec::new(),
+                start: 0,
+                index: 0,
+                in_string: false,
+                escaped: false,
+                closers: Vec::new(),
+                line_start: 0,
+                column: 0,
+                last: 0,
+                fresh_line: false,
+                resume: Vec::new(),
+            }
+        }
+
+        fn feed(
+            &mut self,
+            at: u64,
+            byte: u8,
+            emit: &mut impl FnMut(JsonArrayElement) -> bool,
+        ) -> Result<Flow, PortalisError> {
+            if !self.opened {
+                match byte {
+                    b'[' => self.opened = true,
+                    b if b.is_ascii_whitespace() => {}
+                    _ => {
+                        return Err(PortalisError::InvalidInput(format!(
+                            "expected a JSON array at byte {at}"
+                        )))
+                    }
+                }
+                return Ok(Flow::Next);
+            }
+            if byte == b'\n' {
+                self.line_start = at + 1;
+                self.fresh_line = true;
+            }
+            if self.in_string {
+                self.element.push(byte);
+                // JSON strings can't hold a raw newline, so one ends an unterminated string.
+                if byte == b'\n' {
+                    (self.in_string, self.escaped) = (false, false);
+                    return Ok(Flow::Next);
+                }
+                (self.last, self.fresh_line) = (byte, false);
+                match (self.escaped, byte) {
+                    (true, _) => self.escaped = false,
+                    (false, b'\\') => self.escaped = true,
+                    (false, b'"') => self.in_string = false,
+                    _ => {}
+                }
+                return Ok(Flow::Next);
+            }
+            match byte {
+                b',' | b']' if self.closers.is_empty() => {
+                    let closing = byte == b']';
+                    // `[]` is fine, but `[1,,2]` and `[1,]` have an element missing.
+                    if !self.element.is_empty() || !closing || self.index > 0 {
+                        let parsed = parse_element(self.index, self.start, &self.element, at);
+                        if parsed.error.is_some() && !self.resume.is_empty() {
+                            return Ok(self.resync(emit, Some(byte)));
+                        }
+                        if !emit(parsed) {
+                            return Ok(Flow::Done);
+                        }
+                        self.index += 1;
+                    }
+                    self.element.clear();
+                    self.resume.clear();
+                    if closing {
+                        return Ok(Flow::Done);
+                    }
+                }
+                b if b.is_ascii_whitespace() && self.element.is_empty() => {}
+                _ => {
+                    if self.element.is_empty() {
+                        self.start = at;
+                        self.column = at - self.line_start;
+                    } else if (byte == b'{' || byte == b'[')
+                        && self.fresh_line
+                        && self.last == b','
+                        && at - self.line_start == self.column
+                    {
+                        self.resume.push(self.element.len());
+                    }
+                    match byte {
+                        b'"' => self.in_string = true,
+                        b'{' => self.closers.push(b'}'),
+                        b'[' => self.closers.push(b']'),
+                        b'}' | b']' => pop_through(&mut self.closers, byte),
+                        _ => {}
+                    }
+                    if !byte.is_ascii_whitespace() {
+                        (self.last, self.fresh_line) = (byte, false);
+                    }
+                    self.element.push(byte);
+                }
+            }
+            Ok(Flow::Next)
+        }
+
+        /// At end of input: resyncs inside an unfinished element, if it has a place to.
+        fn end(&mut self, emit: &mut impl FnMut(JsonArrayElement) -> bool) -> Option<Flow> {
+            (!self.resume.is_empty()).then(|| self.resync(emit, None))
+        }
+
+        /// Emits the current element up to its first resume point as malformed, then hands
+        /// back the rest (and `tail`, the byte that ended it) to be scanned again.
+        fn resync(
+            &mut self,
+            emit: &mut impl FnMut(JsonArrayElement) -> bool,
+            tail: Option<u8>,
+        ) -> Flow {
+            let cut = self.resume[0];
+            let at = self.start + cut as u64;
+            // Leave out the comma in front of the resume point.
+            let head = &self.element[..cut];
+            let head = &head[..head.iter().rposition(|&b| b == b',').unwrap_or(0)];
+            if !emit(parse_element(self.index, self.start, head, at)) {
+                return Flow::Done;
+            }
+            self.index += 1;
+            let mut again = self.element.split_off(cut);
+            again.extend(tail);
+            self.element.clear();
+            self.resume.clear();
+            self.closers.clear();
+            (self.in_string, self.escaped) = (false, false);
+            self.line_start = at - self.column;
+            Flow::Replay(at, again)
+        }
+    }
+
+    /// Pops through the opener `closer` belongs to, so a mismatched bracket can't leave the
+    /// rest of the file looking nested. A closer with no opener at all is left to the parser.
+    fn pop_through(closers: &mut Vec<u8>, closer: u8) {
+        if let Some(at) = closers.iter().rposition(|&c| c == closer) {
+            closers.truncate(at);
+        }
+    }
+
+    /// `end` is where the element's terminating `,`/`]` sits, used when the element is empty.
+    fn parse_element(index: u64, start: u64, bytes: &[u8], end: u64) -> JsonArrayElement {
+        let (offset, result) = if bytes.is_empty() {
+            (end, Err("missing array element".to_string()))
+        } else {
+            let parsed = serde_json::from_slice::<Value>(bytes).map_err(|e| e.to_string());
+            (start, parsed)
+        };
+        match result {
+            Ok(value) => JsonArrayElement {
+                index,
+                offset,
+                json: value.to_string(),
+                error: None,
+            },
+            Err(err) => JsonArrayElement {
+                index,
+                offset,
+                json: String::new(),
+                error: Some(format!("malformed element at byte {offset}: {err}")),
+            },
+        }
+    }
+
+    pub(super) async fn load(path: &str) -> Result<AppConfig, PortalisError> {
+        let text = tokio::fs::read_to_string(path).await?;
+        parse_by_extension(path, &text)
+    }
+
+    pub(super) fn parse_by_extension(path: &str, text: &str) -> Result<AppConfig, PortalisError> {
+        let extension = Path::new(path)
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+        let value: Value = match extension.as_deref() {
+            Some("toml") => toml::from_str(text).map_err(|e| malformed("TOML", e))?,
+            Some("yaml" | "yml") => serde_yaml::from_str(text).map_err(|e| malformed("YAML", e))?,
+            Some("json") => serde_json::from_str(text).map_err(|e| malformed("JSON", e))?,
+            _ => {
+                return Err(PortalisError::InvalidInput(format!(
+                    "{path}: config files must end in .toml, .yaml, .yml or .json"
+                )))
+            }
+        };
+        config_from_value(value)
+    }
+
+    fn malformed(format: &str, err: impl std::fmt::Display) -> PortalisError {
+        PortalisError::InvalidInput(format!("malformed config {format}: {err}"))
+    }
+
+    /// Runs until `emit` returns `false` (the Dart stream was closed) or the watcher fails.
+    pub(super) async fn watch(
+        path: &str,
+        mut emit: impl FnMut(AppConfig) -> bool,
+    ) -> Result<(), PortalisError> {
+        let file = Path::new(path);
+        let name = file.file_name().map(|n| n.to_os_string());
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        })?;
+        // Watch the directory: editors often save by renaming a new file over the old
+        // one, which would silently end a watch on the file itself.
+        let dir = match file.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+
+        let mut last = load(path).await?;
+        if !emit(last.clone()) {
+            return Ok(());
+        }
+        while let Some(event) = rx.recv().await {
+            let event: notify::Event = event?;
+            if !event.paths.iter().any(|p| p.file_name() == name.as_deref()) {
+                continue;
+            }
+            match load(path).await {
+                // A save usually fires several events; only report actual changes.
+                Ok(cfg) if cfg == last => {}
+                Ok(cfg) => {
+                    last = cfg.clone();
+                    if !emit(cfg) {
+                        return Ok(());
+                    }
+                }
+                Err(err) => log::warn!("ignoring config change in {path}: {err}"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::{AppConfig, JsonArrayElement};
+    use crate::error::PortalisError;
+
+    fn unsupported() -> PortalisError {
+        PortalisError::unsupported_on_web("config files (use parse_config)")
+    }
+
+    pub(super) async fn load(_path: &str) -> Result<AppConfig, PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn parse_json_array(
+        _path: String,
+        _emit: impl FnMut(JsonArrayElement) -> bool,
+    ) -> Result<(), PortalisError> {
+        Err(PortalisError::unsupported_on_web("reading JSON files"))
+    }
+
+    pub(super) async fn watch(
+        _path: &str,
+        _emit: impl FnMut(AppConfig) -> bool,
+    ) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn sample() -> AppConfig {
+        AppConfig {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            theme: "system".into(),
+            locale: "en-US".into(),
+            telemetry_enabled: false,
+            recent_files: vec!["/tmp/a.txt".into()],
+        }
+    }
+
+    #[test]
+    fn round_trips_current_version() {
+        let cfg = sample();
+        assert_eq!(parse_config(serialize_config(cfg.clone())).unwrap(), cfg);
+    }
+
+    #[test]
+    fn migrates_v1_dark_mode_to_theme() {
+        let json =
+            r#"{"schema_version":1,"dark_mode":true,"locale":"it-IT","telemetry_enabled":true}"#;
+        let cfg = parse_config(json.into()).unwrap();
+        assert_eq!(cfg.schema_version, 2);
+        assert_eq!(cfg.theme, "dark");
+        assert_eq!(cfg.locale, "it-IT");
+        assert!(cfg.recent_files.is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_schema_version() {
+        let err = parse_config(r#"{"schema_version":99}"#.into()).unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+        assert!(err.to_string().contains("99"));
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        let err = parse_config("{not json".into()).unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+
+    #[test]
+    fn rejects_missing_version() {
+        let err = parse_config(r#"{"theme":"dark"}"#.into()).unwrap_err();
+        assert!(err.to_string().contains("schema_version"));
+    }
+
+    const TOML: &str = r#"
+schema_version = 2
+theme = "system"
+locale = "en-US"
+telemetry_enabled = false
+recent_files = ["/tmp/a.txt"]
+"#;
+
+    const YAML: &str = "
+schema_version: 2
+theme: system
+locale: en-US
+telemetry_enabled: false
+recent_files:
+  - /tmp/a.txt
+";
+
+    fn write_config(dir: &tempfile::TempDir, name: &str, text: &str) -> String {
+        let path = dir.path().join(name);
+        std::fs::write(&path, text).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[tokio::test]
+    async fn loads_every_supported_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let json = serialize_config(sample());
+        for (name, text) in [
+            ("app.toml", TOML),
+            ("app.yaml", YAML),
+            ("app.YML", YAML),
+            ("app.json", json.as_str()),
+        ] {
+            let path = write_config(&dir, name, text);
+            assert_eq!(
+                load_config_file(path, None).await.unwrap(),
+                sample(),
+                "{name}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn file_configs_are_migrated_too() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_config(
+            &dir,
+            "old.toml",
+            "schema_version = 1\ndark_mode = true\nlocale = \"en\"\ntelemetry_enabled = true\n",
+        );
+        assert_eq!(load_config_file(path, None).await.unwrap().theme, "dark");
+    }
+
+    #[test]
+    fn errors_name_the_offending_field() {
+        let err = io::parse_by_extension(
+            "app.yaml",
+            &YAML.replace("telemetry_enabled: false", "telemetry_enabled: maybe"),
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+        assert!(err.to_string().contains("`telemetry_enabled`"), "{err}");
+
+        let err = io::parse_by_extension(
+            "app.toml",
+            &TOML.replace(r#"["/tmp/a.txt"]"#, r#"["ok", 3]"#),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("`recent_files[1]`"), "{err}");
+
+        let err = parse_config(
+            r#"{"schema_version":2,"theme":"neon","locale":"en","telemetry_enabled":true}"#.into(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("`theme`"), "{err}");
+    }
+
+    #[test]
+    fn unknown_extension_is_rejected() {
+        let err = io::parse_by_extension("app.ini", "theme=dark").unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+
+    async fn next(rx: &mut tokio::sync::mpsc::UnboundedReceiver<AppConfig>) -> AppConfig {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("config event")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn watch_emits_initial_and_changed_configs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_config(&dir, "app.toml", TOML);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let task = tokio::spawn({
+            let path = path.clone();
+            async move { sys::watch(&path, |cfg| tx.send(cfg).is_ok()).await }
+        });
+        assert_eq!(next(&mut rx).await, sample());
+        // Give the watcher a moment, then save an invalid edit followed by a valid one.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        std::fs::write(&path, TOML.replace("system", "neon")).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        std::fs::write(&path, TOML.replace("system", "dark")).unwrap();
+
+        assert_eq!(next(&mut rx).await.theme, "dark");
+        task.abort();
+    }
+
+    fn scan(input: &str) -> Result<Vec<JsonArrayElement>, PortalisError> {
+        let mut elements = Vec::new();
+        sys::scan_json_array(input.as_bytes(), |e| {
+            elements.push(e);
+            true
+        })?;
+        Ok(elements)
+    }
+
+    #[tokio::test]
+    async fn json_array_streams_large_file_element_by_element() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.json");
+        let body: Vec<String> = (0..50_000)
+            .map(|i| format!(r#"{{ "id": {i}, "tags": ["a,b", "]"], "note": "q\"{i}" }}"#))
+            .collect();
+        std::fs::write(&path, format!("[\n{}\n]\n", body.join(",\n"))).unwrap();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        sys::parse_json_array(path.to_string_lossy().into_owned(), move |e| {
+            tx.send(e).is_ok()
+        })
+        .await
+        .unwrap();
+        let elements: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+
+        assert_eq!(elements.len(), 50_000);
+        assert!(elements.iter().all(|e| e.error.is_none()));
+        assert_eq!(elements[0].offset, 2);
+        assert_eq!(
+            elements[49_999].json,
+            r#"{"id":49999,"note":"q\"49999","tags":["a,b","]"]}"#
+        );
+    }
+
+    #[test]
+    fn json_array_reports_malformed_element_and_continues() {
+        let elements = scan(r#"[1, {"a": tru}, "x", , 4]"#).unwrap();
+
+        let json: Vec<_> = elements.iter().map(|e| e.json.as_str()).collect();
+        assert_eq!(json, ["1", "", r#""x""#, "", "4"]);
+        let error = elements[1].error.as_deref().unwrap();
+        assert!(error.starts_with("malformed element at byte 4:"), "{error}");
+        assert_eq!(elements[1].offset, 4);
+        assert_eq!(elements[3].offset, 21);
+        assert!(elements[3].error.is_some());
+        assert_eq!(elements[4].index, 4);
+    }
+
+    #[test]
+    fn json_array_resyncs_after_unbalanced_elements() {
+        let input = "[\n  {\"a\": [1, 2},\n  {\"b\": [3,\n  {\"c\": \"x,\n  [4],\n  5\n]";
+        let elements = scan(input).unwrap();
+
+        let json: Vec<_> = elements.iter().map(|e| e.json.as_str()).collect();
+        assert_eq!(json, ["", "", "", "[4]", "5"]);
+        let offsets: Vec<_> = elements.iter().map(|e| e.offset).collect();
+        let at = |needle: &str| input.find(needle).unwrap() as u64;
+        assert_eq!(
+            offsets,
+            [at("{\"a"), at("{\"b"), at("{\"c"), at("[4"), at("5")]
+        );
+        assert!(elements[..3].iter().all(|e| e.error.is_some()));
+        assert_eq!(elements[4].index, 4);
+    }
+
+    #[test]
+    fn json_array_keeps_valid_unindented_nesting_whole() {
+        let elements = scan("[\n{\"rows\": [\n{\"x\":1},\n{\"x\":2}\n]}\n]").unwrap();
+
+        assert_eq!(elements.len(), 1);
+        assert_eq!(elements[0].error, None);
+        assert_eq!(elements[0].json, r#"{"rows":[{"x":1},{"x":2}]}"#);
+    }
+
+    #[test]
+    fn json_array_rejects_non_arrays_and_truncation() {
+        assert!(scan("[]").unwrap().is_empty());
+        assert_eq!(scan(" [ ] ").unwrap().len(), 0);
+        assert_eq!(scan(r#"{"a": 1}"#).unwrap_err().code(), "invalid_input");
+        assert_eq!(scan("").unwrap_err().code(), "invalid_input");
+        let err = scan("[1, 2").unwrap_err();
+        assert!(err.to_string().contains("unterminated array"), "{err}");
+        // A trailing comma leaves an element missing before the `]`.
+        assert!(scan("[1,]").unwrap()[1].error.is_some());
+    }
+}
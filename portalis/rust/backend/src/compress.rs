@@ -0,0 +1,169 @@
+use std::io::{Read, Write};
+use std::ops::RangeInclusive;
+
+use crate::error::PortalisError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressAlgo {
+    /// Native only; web builds return `Unsupported` because zstd is a C library.
+    Zstd,
+    Gzip,
+}
+
+impl CompressAlgo {
+    fn level_range(self) -> RangeInclusive<i32> {
+        match self {
+            CompressAlgo::Zstd => sys::zstd_level_range(),
+            CompressAlgo::Gzip => 0..=9,
+        }
+    }
+}
+
+// Not `#[frb(sync)]`: buffers can be large, so these run on the FRB thread pool.
+
+pub fn compress(data: Vec<u8>, algo: CompressAlgo, level: i32) -> Result<Vec<u8>, PortalisError> {
+    check_level(algo, level)?;
+    match algo {
+        CompressAlgo::Zstd => sys::zstd_compress(&data, level),
+        CompressAlgo::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::new(level as u32));
+            encoder.write_all(&data)?;
+            Ok(encoder.finish()?)
+        }
+    }
+}
+
+pub fn decompress(data: Vec<u8>, algo: CompressAlgo) -> Result<Vec<u8>, PortalisError> {
+    match algo {
+        CompressAlgo::Zstd => sys::zstd_decompress(&data),
+        CompressAlgo::Gzip => {
+            let mut out = Vec::new();
+            flate2::read::GzDecoder::new(data.as_slice())
+                .read_to_end(&mut out)
+                .map_err(corrupted)?;
+            Ok(out)
+        }
+    }
+}
+
+pub(crate) fn check_level(algo: CompressAlgo, level: i32) -> Result<(), PortalisError> {
+    let range = algo.level_range();
+    if !range.contains(&level) {
+        return Err(PortalisError::InvalidInput(format!(
+            "{algo:?} level must be in {}..={}, got {level}",
+            range.start(),
+            range.end()
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn corrupted(err: std::io::Error) -> PortalisError {
+    PortalisError::InvalidInput(format!("corrupted compressed data: {err}"))
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::ops::RangeInclusive;
+
+    use super::corrupted;
+    use crate::error::PortalisError;
+
+    pub(super) fn zstd_level_range() -> RangeInclusive<i32> {
+        zstd::compression_level_range()
+    }
+
+    pub(super) fn zstd_compress(data: &[u8], level: i32) -> Result<Vec<u8>, PortalisError> {
+        Ok(zstd::encode_all(data, level)?)
+    }
+
+    pub(super) fn zstd_decompress(data: &[u8]) -> Result<Vec<u8>, PortalisError> {
+        zstd::decode_all(data).map_err(corrupted)
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use std::ops::RangeInclusive;
+
+    use crate::error::PortalisError;
+
+    pub(super) fn zstd_level_range() -> RangeInclusive<i32> {
+        1..=22
+    }
+
+    pub(super) fn zstd_compress(_data: &[u8], _level: i32) -> Result<Vec<u8>, PortalisError> {
+        Err(PortalisError::unsupported_on_web("zstd"))
+    }
+
+    pub(super) fn zstd_decompress(_data: &[u8]) -> Result<Vec<u8>, PortalisError> {
+        Err(PortalisError::unsupported_on_web("zstd"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic xorshift buffer; mixes runs with noise so compression has work to do.
+    fn random_buffer(len: usize, mut seed: u64) -> Vec<u8> {
+        (0..len)
+            .map(|i| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                if i % 3 == 0 {
+                    b'a'
+                } else {
+                    seed as u8
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn round_trips_random_buffers() {
+        for (algo, level) in [(CompressAlgo::Zstd, 3), (CompressAlgo::Gzip, 6)] {
+            for (len, seed) in [(0, 1), (1, 2), (4096, 3), (1 << 20, 4)] {
+                let data = random_buffer(len, seed);
+                let packed = compress(data.clone(), algo, level).unwrap();
+                assert_eq!(
+                    decompress(packed, algo).unwrap(),
+                    data,
+                    "{algo:?} len={len}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_levels() {
+        for (algo, level) in [
+            (CompressAlgo::Gzip, 10),
+            (CompressAlgo::Gzip, -1),
+            (CompressAlgo::Zstd, 23),
+        ] {
+            let err = compress(vec![1, 2, 3], algo, level).unwrap_err();
+            assert_eq!(err.code(), "invalid_input", "{algo:?} level={level}");
+        }
+    }
+
+    #[test]
+    fn corrupted_input_is_an_error() {
+        for algo in [CompressAlgo::Zstd, CompressAlgo::Gzip] {
+            let mut packed = compress(random_buffer(4096, 9), algo, 1).unwrap();
+            let mid = packed.len() / 2;
+            packed.truncate(mid);
+            packed.extend_from_slice(&[0xff; 16]);
+
+            let err = decompress(packed, algo).unwrap_err();
+            assert_eq!(err.code(), "invalid_input", "{algo:?}");
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::runtime;
+
+/// One record from `parse_csv_stream`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvRow {
+    /// 1-based line where the record starts; quoted fields may span several lines.
+    pub line_number: u64,
+    pub fields: Vec<String>,
+    /// Set for the header record when `has_header` is true.
+    pub is_header: bool,
+    /// Why the record couldn't be parsed (e.g. a different field count than the
+    /// first record); `fields` is empty when this is set.
+    pub error: Option<String>,
+}
+
+/// Streams the records of a CSV file one at a time, so the whole file is never in memory.
+///
+/// Malformed records are emitted with `error` set and parsing carries on with the next
+/// one. Only failing to read the file ends the stream early, with an error.
+pub async fn parse_csv_stream(
+    path: String,
+    has_header: bool,
+    timeout_ms: Option<u64>,
+    sink: StreamSink<CsvRow>,
+) -> Result<(), PortalisError> {
+    runtime::with_timeout(
+        timeout_ms,
+        sys::parse(path, has_header, move |row| sink.add(row).is_ok()),
+    )
+    .await
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use super::CsvRow;
+    use crate::error::PortalisError;
+
+    pub(super) async fn parse(
+        path: String,
+        has_header: bool,
+        emit: impl FnMut(CsvRow) -> bool + Send + 'static,
+    ) -> Result<(), PortalisError> {
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&path)?;
+            parse_reader(file, has_header, emit)
+        })
+        .await?
+    }
+
+    /// Runs until the input ends or `emit` returns `false` (the Dart stream was closed).
+    pub(super) fn parse_reader(
+        input: impl std::io::Read,
+        has_header: bool,
+        mut emit: impl FnMut(CsvRow) -> bool,
+    ) -> Result<(), PortalisError> {
+        // Headers are read as an ordinary record so they can be reported like one.
+        let mut reader = ::csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(input);
+        let mut record = ::csv::StringRecord::new();
+        let mut first = true;
+        loop {
+            let row = match reader.read_record(&mut record) {
+                Ok(false) => return Ok(()),
+                Ok(true) => CsvRow {
+                    line_number: record.position().map_or(0, |p| p.line()),
+                    fields: record.iter().map(str::to_string).collect(),
+                    is_header: has_header && first,
+                    error: None,
+                },
+                Err(err) => match err.kind() {
+                    ::csv::ErrorKind::Io(_) => {
+                        return Err(PortalisError::Io(err.to_string()));
+                    }
+                    _ => CsvRow {
+                        line_number: err.position().map_or(0, |p| p.line()),
+                        fields: Vec::new(),
+                        is_header: false,
+                        error: Some(err.to_string()),
+                    },
+                },
+            };
+            first = false;
+            if !emit(row) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::CsvRow;
+    use crate::error::PortalisError;
+
+    pub(super) async fn parse(
+        _path: String,
+        _has_header: bool,
+        _emit: impl FnMut(CsvRow) -> bool,
+    ) -> Result<(), PortalisError> {
+        Err(PortalisError::unsupported_on_web("filesystem access"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(input: &str, has_header: bool) -> Vec<CsvRow> {
+        let mut rows = Vec::new();
+        io::parse_reader(input.as_bytes(), has_header, |row| {
+            rows.push(row);
+            true
+        })
+        .unwrap();
+        rows
+    }
+
+    fn fields(row: &CsvRow) -> Vec<&str> {
+        row.fields.iter().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn quoted_fields_may_contain_commas_quotes_and_newlines() {
+        let rows = rows(
+            "name,note\n\"Doe, Jane\",\"said \"\"hi\"\"\"\n\"multi\nline\",x\nlast,y\n",
+            true,
+        );
+
+        assert!(rows[0].is_header);
+        assert_eq!(fields(&rows[0]), ["name", "note"]);
+        assert_eq!(fields(&rows[1]), ["Doe, Jane", "said \"hi\""]);
+        assert_eq!(fields(&rows[2]), ["multi\nline", "x"]);
+        let lines: Vec<_> = rows.iter().map(|r| r.line_number).collect();
+        assert_eq!(lines, [1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn ragged_rows_are_reported_without_stopping() {
+        let rows = rows("a,b,c\n1,2,3\n4,5\n6,7,8\n", false);
+
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|r| !r.is_header));
+        let bad = &rows[2];
+        assert_eq!(bad.line_number, 3);
+        assert!(bad.fields.is_empty());
+        assert!(bad.error.is_some());
+        assert_eq!(fields(&rows[3]), ["6", "7", "8"]);
+    }
+
+    #[test]
+    fn closing_the_stream_stops_parsing() {
+        let mut seen = 0;
+        io::parse_reader("1\n2\n3\n".as_bytes(), false, |_| {
+            seen += 1;
+            false
+        })
+        .unwrap();
+        assert_eq!(seen, 1);
+    }
+
+    #[tokio::test]
+    async fn missing_file_is_not_found() {
+        let err = sys::parse("/definitely/not/here.csv".into(), true, |_| true)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "not_found");
+    }
+}
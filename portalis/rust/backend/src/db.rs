@@ -0,0 +1,337 @@
+use std::sync::Mutex;
+
+use crate::error::PortalisError;
+
+/// A single SQLite value, used both for bound parameters and result cells.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SqlValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+// One connection shared by every call, like the kv store; SQLite serialises writers anyway.
+static CONNECTION: Mutex<Option<sys::Connection>> = Mutex::new(None);
+
+fn with_connection<T>(
+    f: impl FnOnce(&mut sys::Connection) -> Result<T, PortalisError>,
+) -> Result<T, PortalisError> {
+    let mut guard = CONNECTION
+        .lock()
+        .map_err(|_| PortalisError::Internal("db connection lock poisoned".into()))?;
+    match guard.as_mut() {
+        Some(conn) => f(conn),
+        None => Err(sys::not_open()),
+    }
+}
+
+// Not `#[frb(sync)]`: queries can take arbitrarily long, so they run on the FRB thread pool.
+
+/// Opens (or reopens) the database file at `path`, creating it if needed.
+pub fn db_open(path: String) -> Result<(), PortalisError> {
+    let mut guard = CONNECTION
+        .lock()
+        .map_err(|_| PortalisError::Internal("db connection lock poisoned".into()))?;
+    *guard = None;
+    *guard = Some(sys::Connection::open(&path)?);
+    Ok(())
+}
+
+/// Checkpoints and closes the open database; a no-op if none is open.
+pub(crate) fn close() -> Result<(), PortalisError> {
+    let mut guard = CONNECTION
+        .lock()
+        .map_err(|_| PortalisError::Internal("db connection lock poisoned".into()))?;
+    match guard.take() {
+        Some(conn) => conn.close(),
+        None => Ok(()),
+    }
+}
+
+/// Runs a statement that returns no rows and reports how many rows it changed.
+pub fn db_execute(sql: String, params: Vec<SqlValue>) -> Result<u64, PortalisError> {
+    with_connection(|conn| conn.execute(&sql, params))
+}
+
+/// Runs a query and returns every row, with columns in `SELECT` order.
+pub fn db_query(sql: String, params: Vec<SqlValue>) -> Result<Vec<Vec<SqlValue>>, PortalisError> {
+    with_connection(|conn| conn.query(&sql, params))
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use rusqlite::types::{ToSqlOutput, Value, ValueRef};
+    use rusqlite::ToSql;
+
+    use super::SqlValue;
+    use crate::error::PortalisError;
+
+    impl From<rusqlite::Error> for PortalisError {
+        fn from(err: rusqlite::Error) -> Self {
+            use rusqlite::ErrorCode;
+
+            let message = err.to_string();
+            match err {
+                rusqlite::Error::SqliteFailure(failure, _) => match failure.code {
+                    ErrorCode::CannotOpen
+                    | ErrorCode::DiskFull
+                    | ErrorCode::SystemIoFailure
+                    | ErrorCode::FileLockingProtocolFailed
+                    | ErrorCode::NoLargeFileSupport => PortalisError::Io(message),
+                    ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase => {
+                        PortalisError::Corrupted(message)
+                    }
+                    ErrorCode::PermissionDenied
+                    | ErrorCode::ReadOnly
+                    | ErrorCode::AuthorizationForStatementDenied => {
+                        PortalisError::PermissionDenied(message)
+                    }
+                    // Another connection held the lock for longer than the busy timeout.
+                    ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked => {
+                        PortalisError::Timeout(message)
+                    }
+                    // `Unknown` is plain `SQLITE_ERROR`, which is what bad SQL reports.
+                    ErrorCode::ConstraintViolation
+                    | ErrorCode::TypeMismatch
+                    | ErrorCode::TooBig
+                    | ErrorCode::ParameterOutOfRange
+                    | ErrorCode::Unknown => PortalisError::InvalidInput(message),
+                    _ => PortalisError::Internal(message),
+                },
+                rusqlite::Error::SqlInputError { .. }
+                | rusqlite::Error::InvalidParameterCount(..)
+                | rusqlite::Error::ExecuteReturnedResults
+                | rusqlite::Error::MultipleStatement => PortalisError::InvalidInput(message),
+                _ => PortalisError::Internal(message),
+            }
+        }
+    }
+
+    impl ToSql for SqlValue {
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            Ok(match self {
+                SqlValue::Null => ToSqlOutput::Owned(Value::Null),
+                SqlValue::Integer(v) => ToSqlOutput::Owned(Value::Integer(*v)),
+                SqlValue::Real(v) => ToSqlOutput::Owned(Value::Real(*v)),
+                SqlValue::Text(v) => ToSqlOutput::Borrowed(ValueRef::Text(v.as_bytes())),
+                SqlValue::Blob(v) => ToSqlOutput::Borrowed(ValueRef::Blob(v)),
+            })
+        }
+    }
+
+    impl From<ValueRef<'_>> for SqlValue {
+        fn from(value: ValueRef<'_>) -> Self {
+            match value {
+                ValueRef::Null => SqlValue::Null,
+                ValueRef::Integer(v) => SqlValue::Integer(v),
+                ValueRef::Real(v) => SqlValue::Real(v),
+                ValueRef::Text(v) => SqlValue::Text(String::from_utf8_lossy(v).into_owned()),
+                ValueRef::Blob(v) => SqlValue::Blob(v.to_vec()),
+            }
+        }
+    }
+
+    pub(super) fn not_open() -> PortalisError {
+        PortalisError::Internal("db is not open; call db_open first".into())
+    }
+
+    pub(super) struct Connection {
+        conn: rusqlite::Connection,
+    }
+
+    impl Connection {
+        pub(super) fn open(path: &str) -> Result<Self, PortalisError> {
+            Ok(Connection {
+                conn: rusqlite::Connection::open(path)?,
+            })
+        }
+
+        pub(super) fn execute(
+            &mut self,
+            sql: &str,
+            params: Vec<SqlValue>,
+        ) -> Result<u64, PortalisError> {
+            let changed = self.conn.execute(sql, rusqlite::params_from_iter(params))?;
+            Ok(changed as u64)
+        }
+
+        pub(super) fn query(
+            &mut self,
+            sql: &str,
+            params: Vec<SqlValue>,
+        ) -> Result<Vec<Vec<SqlValue>>, PortalisError> {
+            let mut stmt = self.conn.prepare(sql)?;
+            let columns = stmt.column_count();
+            let mut rows = stmt.query(rusqlite::params_from_iter(params))?;
+            let mut out = Vec::new();
+            while let Some(row) = rows.next()? {
+                let cells = (0..columns)
+                    .map(|i| row.get_ref(i).map(SqlValue::from))
+                    .collect::<Result<_, _>>()?;
+                out.push(cells);
+            }
+            Ok(out)
+        }
+
+        /// Moves everything in the write-ahead log (if any) into the main file, then closes.
+        pub(super) fn close(self) -> Result<(), PortalisError> {
+            // Returns a status row, so it can't go through `execute`. Without WAL this is a no-op.
+            self.conn
+                .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+            self.conn.close().map_err(|(_, err)| err.into())
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::SqlValue;
+    use crate::error::PortalisError;
+
+    fn unsupported() -> PortalisError {
+        PortalisError::unsupported_on_web("SQLite")
+    }
+
+    pub(super) fn not_open() -> PortalisError {
+        unsupported()
+    }
+
+    /// Never constructed: `open` always fails on web.
+    pub(super) enum Connection {}
+
+    impl Connection {
+        pub(super) fn open(_path: &str) -> Result<Self, PortalisError> {
+            Err(unsupported())
+        }
+
+        pub(super) fn execute(
+            &mut self,
+            _sql: &str,
+            _params: Vec<SqlValue>,
+        ) -> Result<u64, PortalisError> {
+            match *self {}
+        }
+
+        pub(super) fn query(
+            &mut self,
+            _sql: &str,
+            _params: Vec<SqlValue>,
+        ) -> Result<Vec<Vec<SqlValue>>, PortalisError> {
+            match *self {}
+        }
+
+        pub(super) fn close(self) -> Result<(), PortalisError> {
+            match self {}
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // The connection is a process-wide global, so tests touching it must not interleave.
+    pub(crate) static GLOBAL_DB_LOCK: Mutex<()> = Mutex::new(());
+
+    fn open_temp() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        db_open(dir.path().join("app.db").to_string_lossy().into_owned()).unwrap();
+        dir
+    }
+
+    #[test]
+    fn execute_and_query_round_trip_every_type() {
+        let _lock = GLOBAL_DB_LOCK.lock().unwrap();
+        let _dir = open_temp();
+
+        db_execute(
+            "CREATE TABLE item (id INTEGER, score REAL, name TEXT, data BLOB, note TEXT)".into(),
+            vec![],
+        )
+        .unwrap();
+        let row = vec![
+            SqlValue::Integer(7),
+            SqlValue::Real(1.5),
+            SqlValue::Text("héllo".into()),
+            SqlValue::Blob(vec![0, 255]),
+            SqlValue::Null,
+        ];
+        let changed = db_execute(
+            "INSERT INTO item VALUES (?1, ?2, ?3, ?4, ?5)".into(),
+            row.clone(),
+        )
+        .unwrap();
+        assert_eq!(changed, 1);
+
+        let rows = db_query(
+            "SELECT id, score, name, data, note FROM item WHERE id = ?1".into(),
+            vec![SqlValue::Integer(7)],
+        )
+        .unwrap();
+        assert_eq!(rows, vec![row]);
+    }
+
+    #[test]
+    fn execute_reports_rows_affected() {
+        let _lock = GLOBAL_DB_LOCK.lock().unwrap();
+        let _dir = open_temp();
+
+        db_execute("CREATE TABLE t (n INTEGER)".into(), vec![]).unwrap();
+        for n in 0..3 {
+            db_execute(
+                "INSERT INTO t VALUES (?1)".into(),
+                vec![SqlValue::Integer(n)],
+            )
+            .unwrap();
+        }
+        let changed = db_execute("UPDATE t SET n = n + 1 WHERE n > 0".into(), vec![]).unwrap();
+        assert_eq!(changed, 2);
+    }
+
+    #[test]
+    fn bad_sql_is_invalid_input() {
+        let _lock = GLOBAL_DB_LOCK.lock().unwrap();
+        let _dir = open_temp();
+
+        let err = db_query("SELEC nonsense".into(), vec![]).unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+
+    #[test]
+    fn constraint_violation_is_invalid_input() {
+        let _lock = GLOBAL_DB_LOCK.lock().unwrap();
+        let _dir = open_temp();
+
+        db_execute("CREATE TABLE u (name TEXT UNIQUE)".into(), vec![]).unwrap();
+        let insert = || {
+            db_execute(
+                "INSERT INTO u VALUES (?1)".into(),
+                vec![SqlValue::Text("ann".into())],
+            )
+        };
+        insert().unwrap();
+        assert_eq!(insert().unwrap_err().code(), "invalid_input");
+    }
+
+    #[test]
+    fn file_that_is_not_a_database_is_corrupted() {
+        let _lock = GLOBAL_DB_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("garbage.db");
+        std::fs::write(&path, vec![0x5a; 4096]).unwrap();
+
+        // SQLite only reads the header on first use, so opening still succeeds.
+        db_open(path.to_string_lossy().into_owned()).unwrap();
+        let err = db_query("SELECT * FROM sqlite_master".into(), vec![]).unwrap_err();
+        assert_eq!(err.code(), "corrupted");
+        // Closing takes the broken connection out even though its checkpoint fails.
+        assert!(close().is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use std::sync::OnceLock;
+
+use flutter_rust_bridge::frb;
+use serde_json::{json, Map, Value};
+
+use crate::logging;
+use crate::metrics;
+use crate::platform;
+
+/// How many of the most recent log records go into an export.
+pub const DIAG_LOG_LINES: usize = 200;
+
+/// Keys whose values never leave the device, matched case-insensitively. They are
+/// redacted both as JSON keys and in `key=value` / `key: value` text inside log messages.
+const SENSITIVE_KEYS: &[&str] = &[
+    "password",
+    "passphrase",
+    "secret",
+    "token",
+    "api_key",
+    "apikey",
+    "authorization",
+    "cookie",
+    "session",
+];
+
+const REDACTED: &str = "[redacted]";
+
+/// Pretty JSON for support tickets: build and platform info, the last `DIAG_LOG_LINES`
+/// log records and the timing metrics, with sensitive values redacted.
+///
+/// Logs are only collected after `init_logging` has run.
+// Sync: everything is already in memory, so this is quicker than a worker hop.
+#[frb(sync)]
+pub fn export_diagnostics() -> String {
+    let mut report = collect();
+    redact(&mut report);
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+fn collect() -> Value {
+    let build = crate::get_build_info();
+    let platform = platform::get_platform_info();
+    let logs: Vec<Value> = logging::recent_logs(DIAG_LOG_LINES)
+        .into_iter()
+        .map(|record| {
+            json!({
+                "timestamp_ms": record.timestamp_ms,
+                "level": record.level.as_str(),
+                "target": record.target,
+                "message": record.message,
+            })
+        })
+        .collect();
+    let metrics: Vec<Value> = metrics::export_metrics()
+        .into_iter()
+        .map(|m| {
+            json!({
+                "name": m.name,
+                "count": m.count,
+                "min_ms": m.min,
+                "max_ms": m.max,
+                "mean_ms": m.mean,
+                "p95_ms": m.p95,
+            })
+        })
+        .collect();
+    json!({
+        "build": {
+            "version": build.version,
+            "git_sha": build.git_sha,
+            "profile": build.profile,
+            "rustc_version": build.rustc_version,
+            "built_at_unix": build.built_at_unix,
+        },
+        "platform": {
+            "os": platform.os,
+            "arch": platform.arch,
+            "is_web": platform.is_web,
+            "has_filesystem": platform.has_filesystem,
+            "has_threads": platform.has_threads,
+            "num_cpus": platform.num_cpus,
+        },
+        "logs": logs,
+        "metrics": metrics,
+    })
+}
+
+fn is_sensitive(key: &str) -> bool {
+    let key = key.to_ascii_lowercase().replace('-', "_");
+    SENSITIVE_KEYS
+        .iter()
+        .any(|sensitive| key.contains(sensitive))
+}
+
+/// Blanks sensitive object values and scrubs every string in place.
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => redact_object(map),
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        Value::String(text) => *text = redact_text(text),
+        _ => {}
+    }
+}
+
+fn redact_object(map: &mut Map<String, Value>) {
+    for (key, value) in map.iter_mut() {
+        if is_sensitive(key) {
+            *value = Value::String(REDACTED.into());
+        } else {
+            redact(value);
+        }
+    }
+}
+
+/// Replaces the value in `token=abc`, `Authorization: Bearer abc`, `"password":"abc"`, etc.
+fn redact_text(text: &str) -> String {
+    static PATTERN: OnceLock<::regex::Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        let keys = SENSITIVE_KEYS.join("|").replace('_', "[_-]?");
+        ::regex::Regex::new(&format!(
+            r#"(?i)(\b\w*(?:{keys})\w*"?\s*[:=]\s*"?)(?:(?:bearer|basic)\s+)?[^\s"',;&]+"#
+        ))
+        .expect("valid redaction pattern")
+    });
+    pattern
+        .replace_all(text, format!("${{1}}{REDACTED}"))
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_has_every_section_and_redacts_secrets() {
+        logging::init_logging(logging::LogLevel::Info);
+        log::warn!(target: "backend::diag_test", "login failed for bob, token=abc123 retry");
+        metrics::record_timing("diag_test.op".into(), 12.5);
+
+        let report: Value = serde_json::from_str(&export_diagnostics()).unwrap();
+
+        assert_eq!(report["build"]["version"], crate::get_version());
+        assert!(report["platform"]["os"].is_string());
+        let metric = report["metrics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|m| m["name"] == "diag_test.op")
+            .expect("recorded metric");
+        assert!(metric["count"].as_u64().unwrap() >= 1);
+        let line = report["logs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|r| r["target"] == "backend::diag_test")
+            .expect("buffered log line");
+        assert_eq!(line["level"], "warn");
+        assert_eq!(
+            line["message"],
+            "login failed for bob, token=[redacted] retry"
+        );
+    }
+
+    #[test]
+    fn text_redaction_covers_common_shapes() {
+        assert_eq!(
+            redact_text("Authorization: Bearer eyJhbGc.def"),
+            "Authorization: [redacted]"
+        );
+        assert_eq!(
+            redact_text(r#"{"password":"hunter2","user":"bob"}"#),
+            r#"{"password":"[redacted]","user":"bob"}"#
+        );
+        assert_eq!(
+            redact_text("GET /v1?API-KEY=k1&page=2"),
+            "GET /v1?API-KEY=[redacted]&page=2"
+        );
+        assert_eq!(
+            redact_text("session_id = 42; theme=dark"),
+            "session_id = [redacted]; theme=dark"
+        );
+        assert_eq!(redact_text("nothing to hide"), "nothing to hide");
+    }
+
+    #[test]
+    fn sensitive_json_keys_are_blanked() {
+        let mut value = json!({"user": "bob", "Auth_Token": "t", "nested": [{"passphrase": 1}]});
+        redact(&mut value);
+        assert_eq!(
+            value,
+            json!({"user": "bob", "Auth_Token": REDACTED, "nested": [{"passphrase": REDACTED}]})
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use base64::Engine as _;
+use flutter_rust_bridge::frb;
+
+use crate::error::PortalisError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Variant {
+    /// RFC 4648 alphabet (`+`, `/`) with `=` padding.
+    Standard,
+    /// URL- and filename-safe alphabet (`-`, `_`) without padding, as used in JWTs.
+    UrlSafe,
+}
+
+impl Base64Variant {
+    fn engine(self) -> &'static base64::engine::GeneralPurpose {
+        match self {
+            Base64Variant::Standard => &base64::engine::general_purpose::STANDARD,
+            Base64Variant::UrlSafe => &URL_SAFE_LENIENT,
+        }
+    }
+}
+
+// Encodes without padding but accepts it when decoding, since URL-safe producers disagree.
+const URL_SAFE_LENIENT: base64::engine::GeneralPurpose = base64::engine::GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    base64::engine::GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(base64::engine::DecodePaddingMode::Indifferent),
+);
+
+// All sync: these are cheap enough that a worker round-trip would cost more than the work.
+
+#[frb(sync)]
+pub fn base64_encode(data: Vec<u8>, variant: Base64Variant) -> String {
+    variant.engine().encode(data)
+}
+
+#[frb(sync)]
+pub fn base64_decode(s: String, variant: Base64Variant) -> Result<Vec<u8>, PortalisError> {
+    variant
+        .engine()
+        .decode(s)
+        .map_err(|err| PortalisError::InvalidInput(format!("invalid base64: {err}")))
+}
+
+/// Lowercase hex, two characters per byte.
+#[frb(sync)]
+pub fn hex_encode(data: Vec<u8>) -> String {
+    hex::encode(data)
+}
+
+/// Accepts upper- or lowercase digits; the length must be even.
+#[frb(sync)]
+pub fn hex_decode(s: String) -> Result<Vec<u8>, PortalisError> {
+    hex::decode(s).map_err(|err| PortalisError::InvalidInput(format!("invalid hex: {err}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_base64_pads_to_multiple_of_four() {
+        let cases: [(&[u8], &str); 4] =
+            [(b"", ""), (b"f", "Zg=="), (b"fo", "Zm8="), (b"foo", "Zm9v")];
+        for (raw, encoded) in cases {
+            assert_eq!(
+                base64_encode(raw.to_vec(), Base64Variant::Standard),
+                encoded
+            );
+            assert_eq!(
+                base64_decode(encoded.into(), Base64Variant::Standard).unwrap(),
+                raw
+            );
+        }
+    }
+
+    #[test]
+    fn standard_base64_rejects_missing_or_extra_padding() {
+        for bad in ["Zg", "Zg=", "Zm9v=", "Zg===", "Z"] {
+            let err = base64_decode(bad.into(), Base64Variant::Standard).unwrap_err();
+            assert_eq!(err.code(), "invalid_input", "{bad}");
+        }
+    }
+
+    #[test]
+    fn url_safe_base64_uses_its_own_alphabet_and_optional_padding() {
+        let data = vec![0xfb, 0xff, 0xbf];
+        assert_eq!(base64_encode(data.clone(), Base64Variant::Standard), "+/+/");
+        assert_eq!(base64_encode(data.clone(), Base64Variant::UrlSafe), "-_-_");
+        assert_eq!(base64_encode(b"f".to_vec(), Base64Variant::UrlSafe), "Zg");
+
+        assert_eq!(
+            base64_decode("Zg".into(), Base64Variant::UrlSafe).unwrap(),
+            b"f"
+        );
+        assert_eq!(
+            base64_decode("Zg==".into(), Base64Variant::UrlSafe).unwrap(),
+            b"f"
+        );
+        let err = base64_decode("+/+/".into(), Base64Variant::UrlSafe).unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+
+    #[test]
+    fn hex_round_trips_and_rejects_bad_input() {
+        assert_eq!(hex_encode(vec![0x00, 0xab, 0xff]), "00abff");
+        assert_eq!(hex_decode("00ABff".into()).unwrap(), vec![0x00, 0xab, 0xff]);
+        for bad in ["abc", "zz"] {
+            assert_eq!(hex_decode(bad.into()).unwrap_err().code(), "invalid_input");
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use flutter_rust_bridge::frb;
+
+/// Environment variables Dart may read through `get_env`; everything else stays hidden.
+pub const ALLOWED_ENV_VARS: &[&str] = &[
+    "HTTP_PROXY",
+    "HTTPS_PROXY",
+    "ALL_PROXY",
+    "NO_PROXY",
+    "http_proxy",
+    "https_proxy",
+    "all_proxy",
+    "no_proxy",
+    "XDG_DATA_HOME",
+];
+
+/// Returns the variable's value, or `None` if it is unset, not valid UTF-8, or not
+/// on [`ALLOWED_ENV_VARS`]. Always `None` on web.
+#[frb(sync)]
+pub fn get_env(name: String) -> Option<String> {
+    if !ALLOWED_ENV_VARS.contains(&name.as_str()) {
+        return None;
+    }
+    sys::get_env(&name)
+}
+
+/// Per-user directory for app data (e.g. `~/.local/share/portalis` on Linux).
+///
+/// Empty on web or when the platform has no home directory.
+#[frb(sync)]
+pub fn get_data_dir() -> String {
+    sys::data_dir().unwrap_or_default()
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    pub(super) fn get_env(name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+
+    pub(super) fn data_dir() -> Option<String> {
+        // Matches the application id used by the Flutter runners.
+        let dirs = directories::ProjectDirs::from("com", "example", "portalis")?;
+        Some(dirs.data_dir().to_string_lossy().into_owned())
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    pub(super) fn get_env(_name: &str) -> Option<String> {
+        None
+    }
+
+    pub(super) fn data_dir() -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_allowlisted_variables_are_visible() {
+        // PATH is set in every test environment but must never cross the bridge.
+        assert!(std::env::var("PATH").is_ok());
+        assert_eq!(get_env("PATH".into()), None);
+        assert_eq!(get_env("NO_PROXY".into()), std::env::var("NO_PROXY").ok());
+    }
+
+    #[test]
+    fn data_dir_is_absolute() {
+        let dir = get_data_dir();
+        assert!(std::path::Path::new(&dir).is_absolute(), "{dir:?}");
+    }
+}
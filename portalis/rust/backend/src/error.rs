@@ -0,0 +1,167 @@
+use flutter_rust_bridge::frb;
+
+/// Error returned by every fallible API function.
+///
+/// Crosses the bridge as a Dart exception; `code()` is a stable identifier the
+/// UI can branch on, the message is for humans and may change between versions.
+#[derive(Debug, thiserror::Error)]
+pub enum PortalisError {
+    #[error("I/O error: {0}")]
+    Io(String),
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+    /// The operation is not available on this platform (e.g. filesystem on web).
+    #[error("unsupported: {0}")]
+    Unsupported(String),
+    /// Connecting, sending or receiving over the network failed.
+    #[error("network error: {0}")]
+    Network(String),
+    #[error("cancelled: {0}")]
+    Cancelled(String),
+    /// The OS refused access to a file or directory.
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
+    /// Stored data failed an integrity check (e.g. checksum mismatch).
+    #[error("corrupted: {0}")]
+    Corrupted(String),
+    /// The operation took longer than the `timeout_ms` its caller allowed.
+    #[error("timed out: {0}")]
+    Timeout(String),
+}
+
+/// Flat `{code, message}` view of a [`PortalisError`] for logging or display.
+pub struct ErrorInfo {
+    pub code: String,
+    pub message: String,
+}
+
+impl PortalisError {
+    #[frb(sync)]
+    pub fn code(&self) -> String {
+        match self {
+            PortalisError::Io(_) => "io",
+            PortalisError::InvalidInput(_) => "invalid_input",
+            PortalisError::NotFound(_) => "not_found",
+            PortalisError::Internal(_) => "internal",
+            PortalisError::Unsupported(_) => "unsupported",
+            PortalisError::Network(_) => "network",
+            PortalisError::Cancelled(_) => "cancelled",
+            PortalisError::PermissionDenied(_) => "permission_denied",
+            PortalisError::Corrupted(_) => "corrupted",
+            PortalisError::Timeout(_) => "timeout",
+        }
+        .to_string()
+    }
+
+    #[frb(sync)]
+    pub fn info(&self) -> ErrorInfo {
+        ErrorInfo {
+            code: self.code(),
+            message: self.to_string(),
+        }
+    }
+
+    /// Prefixes the message with `context` (e.g. the path involved), keeping the variant.
+    pub(crate) fn context(self, context: impl std::fmt::Display) -> Self {
+        let wrap = |message: String| format!("{context}: {message}");
+        match self {
+            PortalisError::Io(m) => PortalisError::Io(wrap(m)),
+            PortalisError::InvalidInput(m) => PortalisError::InvalidInput(wrap(m)),
+            PortalisError::NotFound(m) => PortalisError::NotFound(wrap(m)),
+            PortalisError::Internal(m) => PortalisError::Internal(wrap(m)),
+            PortalisError::Unsupported(m) => PortalisError::Unsupported(wrap(m)),
+            PortalisError::Network(m) => PortalisError::Network(wrap(m)),
+            PortalisError::Cancelled(m) => PortalisError::Cancelled(wrap(m)),
+            PortalisError::PermissionDenied(m) => PortalisError::PermissionDenied(wrap(m)),
+            PortalisError::Corrupted(m) => PortalisError::Corrupted(wrap(m)),
+            PortalisError::Timeout(m) => PortalisError::Timeout(wrap(m)),
+        }
+    }
+
+    #[cfg(target_family = "wasm")]
+    pub(crate) fn unsupported_on_web(what: &str) -> Self {
+        PortalisError::Unsupported(format!("{what} is not available on web"))
+    }
+}
+
+impl From<PortalisError> for ErrorInfo {
+    fn from(err: PortalisError) -> Self {
+        err.info()
+    }
+}
+
+impl From<std::io::Error> for PortalisError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::NotFound => PortalisError::NotFound(err.to_string()),
+            std::io::ErrorKind::Unsupported => PortalisError::Unsupported(err.to_string()),
+            std::io::ErrorKind::PermissionDenied => {
+                PortalisError::PermissionDenied(err.to_string())
+            }
+            _ => PortalisError::Io(err.to_string()),
+        }
+    }
+}
+
+impl From<tokio::task::JoinError> for PortalisError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        PortalisError::Internal(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_stable() {
+        let cases = [
+            (PortalisError::Io(String::new()), "io"),
+            (PortalisError::InvalidInput(String::new()), "invalid_input"),
+            (PortalisError::NotFound(String::new()), "not_found"),
+            (PortalisError::Internal(String::new()), "internal"),
+            (PortalisError::Unsupported(String::new()), "unsupported"),
+            (PortalisError::Network(String::new()), "network"),
+            (PortalisError::Cancelled(String::new()), "cancelled"),
+            (
+                PortalisError::PermissionDenied(String::new()),
+                "permission_denied",
+            ),
+            (PortalisError::Corrupted(String::new()), "corrupted"),
+            (PortalisError::Timeout(String::new()), "timeout"),
+        ];
+        for (err, code) in cases {
+            assert_eq!(err.code(), code);
+        }
+    }
+
+    #[test]
+    fn io_errors_map_by_kind() {
+        let missing = std::io::Error::new(std::io::ErrorKind::NotFound, "gone");
+        assert_eq!(PortalisError::from(missing).code(), "not_found");
+
+        let denied = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "no");
+        assert_eq!(PortalisError::from(denied).code(), "permission_denied");
+
+        let broken = std::io::Error::new(std::io::ErrorKind::BrokenPipe, "pipe");
+        assert_eq!(PortalisError::from(broken).code(), "io");
+    }
+
+    #[test]
+    fn context_keeps_the_variant() {
+        let err = PortalisError::NotFound("no such file".into()).context("/tmp/x");
+        assert_eq!(err.code(), "not_found");
+        assert_eq!(err.to_string(), "not found: /tmp/x: no such file");
+    }
+
+    #[test]
+    fn info_carries_code_and_message() {
+        let info = ErrorInfo::from(PortalisError::InvalidInput("empty path".into()));
+        assert_eq!(info.code, "invalid_input");
+        assert_eq!(info.message, "invalid input: empty path");
+    }
+}
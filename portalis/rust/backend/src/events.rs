@@ -0,0 +1,164 @@
+use std::sync::OnceLock;
+
+use tokio::sync::broadcast;
+
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::runtime;
+
+/// Notification from some part of the backend, tagged by where it came from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AppEvent {
+    /// A `download_file` call ended, successfully or not.
+    DownloadFinished {
+        url: String,
+        dest: String,
+        succeeded: bool,
+    },
+    /// `watch_config` delivered a new config read from `path`.
+    ConfigReloaded { path: String },
+    /// A background job from `submit_job` completed or failed.
+    JobFinished { id: u64, succeeded: bool },
+    /// The subscriber fell behind and `skipped` older events were dropped.
+    Lagged { skipped: u64 },
+}
+
+/// Events buffered per subscriber before the oldest are dropped as `Lagged`.
+const CAPACITY: usize = 256;
+
+fn bus() -> &'static broadcast::Sender<AppEvent> {
+    static BUS: OnceLock<broadcast::Sender<AppEvent>> = OnceLock::new();
+    BUS.get_or_init(|| broadcast::channel(CAPACITY).0)
+}
+
+/// Sends `event` to every current subscriber; a no-op when nobody is listening.
+pub(crate) fn publish(event: AppEvent) {
+    let _ = bus().send(event);
+}
+
+/// Streams every event published from now on until Dart cancels the subscription.
+pub async fn subscribe_events(sink: StreamSink<AppEvent>) -> Result<(), PortalisError> {
+    let rx = bus().subscribe();
+    runtime::run(async move {
+        forward(rx, |event| sink.add(event).is_ok()).await;
+        Ok(())
+    })
+    .await
+}
+
+/// Hands events from `rx` to `emit` until it returns `false` or the channel closes.
+async fn forward(mut rx: broadcast::Receiver<AppEvent>, mut emit: impl FnMut(AppEvent) -> bool) {
+    loop {
+        let event = match rx.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => AppEvent::Lagged { skipped },
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+        if !emit(event) {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn two_emitters_interleave_in_order() {
+        let (tx, rx) = broadcast::channel(CAPACITY);
+        let emitters: Vec<_> = [1u64, 2]
+            .into_iter()
+            .map(|source| {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    for n in 0..50 {
+                        let _ = tx.send(AppEvent::JobFinished {
+                            id: source * 1_000 + n,
+                            succeeded: true,
+                        });
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+        for emitter in emitters {
+            emitter.await.unwrap();
+        }
+        drop(tx);
+
+        let mut seen = Vec::new();
+        forward(rx, |event| {
+            seen.push(event);
+            true
+        })
+        .await;
+
+        assert_eq!(seen.len(), 100);
+        for source in [1u64, 2] {
+            let ids: Vec<u64> = seen
+                .iter()
+                .filter_map(|event| match event {
+                    AppEvent::JobFinished { id, .. } if id / 1_000 == source => Some(id % 1_000),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(ids, (0..50).collect::<Vec<_>>(), "source {source}");
+        }
+    }
+
+    #[tokio::test]
+    async fn slow_subscriber_gets_lagged_event() {
+        let (tx, rx) = broadcast::channel(2);
+        for id in 0..5 {
+            tx.send(AppEvent::JobFinished {
+                id,
+                succeeded: true,
+            })
+            .unwrap();
+        }
+        drop(tx);
+
+        let mut seen = Vec::new();
+        forward(rx, |event| {
+            seen.push(event);
+            true
+        })
+        .await;
+
+        assert_eq!(
+            seen,
+            [
+                AppEvent::Lagged { skipped: 3 },
+                AppEvent::JobFinished {
+                    id: 3,
+                    succeeded: true
+                },
+                AppEvent::JobFinished {
+                    id: 4,
+                    succeeded: true
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn published_events_reach_subscribers() {
+        let mut rx = bus().subscribe();
+        publish(AppEvent::ConfigReloaded {
+            path: "published.toml".into(),
+        });
+
+        // Other tests may publish on the shared bus too, so look for ours.
+        loop {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+                .await
+                .unwrap();
+            if let Ok(AppEvent::ConfigReloaded { path }) = event {
+                if path == "published.toml" {
+                    break;
+                }
+            }
+        }
+    }
+}
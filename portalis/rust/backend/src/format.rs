@@ -0,0 +1,150 @@
+use flutter_rust_bridge::frb;
+
+// All sync: formatting a single number is far cheaper than a worker hop.
+
+/// Human-readable size: `1.5 MiB` with `binary` (powers of 1024), else `1.5 MB` (powers of 1000).
+///
+/// Sizes below one kilobyte are exact (`512 B`); larger ones show at most one decimal.
+#[frb(sync)]
+pub fn format_bytes(n: u64, binary: bool) -> String {
+    const BINARY: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    const DECIMAL: [&str; 6] = ["kB", "MB", "GB", "TB", "PB", "EB"];
+    let (base, units) = if binary {
+        (1024.0, BINARY)
+    } else {
+        (1000.0, DECIMAL)
+    };
+    if (n as f64) < base {
+        return format!("{n} B");
+    }
+
+    let mut value = n as f64 / base;
+    let mut unit = 0;
+    // Compare the rounded value so e.g. 1023.96 KiB shows as "1 MiB", not "1024 KiB".
+    while round_tenth(value) >= base && unit < units.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+    let value = round_tenth(value);
+    if value.fract() == 0.0 {
+        format!("{value:.0} {}", units[unit])
+    } else {
+        format!("{value:.1} {}", units[unit])
+    }
+}
+
+fn round_tenth(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+/// Formats `n` with the digit grouping of `locale` (e.g. `en-US`, `de_DE`, `fr`).
+///
+/// Only the language and region matter; unknown locales fall back to `en` (`1,234,567`).
+#[frb(sync)]
+pub fn format_number(n: i64, locale: String) -> String {
+    let grouping = Grouping::for_locale(&locale);
+    let digits = n.unsigned_abs().to_string();
+    let sign = if n < 0 { "-" } else { "" };
+    format!("{sign}{}", grouping.apply(&digits))
+}
+
+/// How a locale separates groups of digits in whole numbers.
+struct Grouping {
+    separator: &'static str,
+    /// Indian-style grouping: the first group has three digits, the rest two.
+    lakh: bool,
+}
+
+impl Grouping {
+    fn for_locale(locale: &str) -> Self {
+        let locale = locale.replace('_', "-").to_ascii_lowercase();
+        let (language, region) = match locale.split_once('-') {
+            Some((language, rest)) => (language, rest.split('-').next().unwrap_or("")),
+            None => (locale.as_str(), ""),
+        };
+        let (separator, lakh) = match (language, region) {
+            ("de" | "it", "ch") => ("\u{2019}", false),
+            ("en" | "hi", "in") | ("hi", _) => (",", true),
+            ("de" | "es" | "it" | "nl" | "pt" | "id" | "da" | "tr" | "el", _) => (".", false),
+            // French uses a narrow no-break space; the others a plain no-break space.
+            ("fr", _) => ("\u{202f}", false),
+            ("ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi", _) => ("\u{a0}", false),
+            _ => (",", false),
+        };
+        Grouping { separator, lakh }
+    }
+
+    fn apply(&self, digits: &str) -> String {
+        let mut groups = Vec::new();
+        let mut rest = digits;
+        let mut size = 3;
+        while rest.len() > size {
+            let (head, tail) = rest.split_at(rest.len() - size);
+            groups.push(tail);
+            rest = head;
+            if self.lakh {
+                size = 2;
+            }
+        }
+        groups.push(rest);
+        groups.reverse();
+        groups.join(self.separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_below_a_kilobyte_are_exact() {
+        assert_eq!(format_bytes(0, true), "0 B");
+        assert_eq!(format_bytes(1023, true), "1023 B");
+        assert_eq!(format_bytes(999, false), "999 B");
+    }
+
+    #[test]
+    fn exact_powers_have_no_decimals() {
+        assert_eq!(format_bytes(1024, true), "1 KiB");
+        assert_eq!(format_bytes(1 << 20, true), "1 MiB");
+        assert_eq!(format_bytes(1 << 30, true), "1 GiB");
+        assert_eq!(format_bytes(1000, false), "1 kB");
+        assert_eq!(format_bytes(1_000_000_000_000, false), "1 TB");
+    }
+
+    #[test]
+    fn fractions_round_to_one_decimal() {
+        assert_eq!(format_bytes(1536 * 1024, true), "1.5 MiB");
+        assert_eq!(format_bytes(1_500_000, false), "1.5 MB");
+        assert_eq!(format_bytes(1_048_575, true), "1 MiB");
+        assert_eq!(format_bytes(u64::MAX, true), "16 EiB");
+    }
+
+    #[test]
+    fn numbers_group_by_locale() {
+        assert_eq!(format_number(0, "en-US".into()), "0");
+        assert_eq!(format_number(999, "de-DE".into()), "999");
+        assert_eq!(format_number(1_234_567, "en-US".into()), "1,234,567");
+        assert_eq!(format_number(1_234_567, "de_DE".into()), "1.234.567");
+        assert_eq!(
+            format_number(1_234_567, "fr-FR".into()),
+            "1\u{202f}234\u{202f}567"
+        );
+        assert_eq!(
+            format_number(1_234_567, "de-CH".into()),
+            "1\u{2019}234\u{2019}567"
+        );
+        assert_eq!(format_number(1_234_567, "en-IN".into()), "12,34,567");
+        assert_eq!(format_number(-1_000, "sv".into()), "-1\u{a0}000");
+    }
+
+    #[test]
+    fn unknown_locales_fall_back_to_english() {
+        assert_eq!(format_number(1_000_000, "xx-YY".into()), "1,000,000");
+        assert_eq!(format_number(1_000_000, String::new()), "1,000,000");
+        assert_eq!(
+            format_number(i64::MIN, "nonsense".into()),
+            "-9,223,372,036,854,775,808"
+        );
+    }
+}
@@ -0,0 +1,1071 @@
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::hash::{self, HashAlgo};
+use crate::runtime;
+
+pub struct CopyProgress {
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+}
+
+/// A file or directory as shown in a file browser.
+#[derive(Debug, PartialEq)]
+pub struct DirEntry {
+    pub name: String,
+    pub is_dir: bool,
+    /// Zero for directories.
+    pub size_bytes: u64,
+    /// Seconds since the Unix epoch; 0 when the platform doesn't record it.
+    pub modified_unix: i64,
+}
+
+// These are async (not `#[frb(sync)]`) so large files never block the UI isolate.
+
+pub async fn read_file(path: String, timeout_ms: Option<u64>) -> Result<Vec<u8>, PortalisError> {
+    runtime::with_timeout(timeout_ms, async move { sys::read_file(&path).await }).await
+}
+
+pub async fn write_file(
+    path: String,
+    bytes: Vec<u8>,
+    timeout_ms: Option<u64>,
+) -> Result<(), PortalisError> {
+    runtime::with_timeout(
+        timeout_ms,
+        async move { sys::write_file(&path, &bytes).await },
+    )
+    .await
+}
+
+/// Chunk size used by `read_file_chunked` when the caller passes 0.
+pub const DEFAULT_READ_CHUNK_SIZE: usize = 64 * 1024;
+/// Largest chunk `read_file_chunked` accepts, so one event can't balloon memory.
+pub const MAX_READ_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Streams the contents of `path` in order, `chunk_size` bytes at a time.
+///
+/// Every chunk is full except possibly the last; an empty chunk marks the end of the
+/// file. Pass 0 for [`DEFAULT_READ_CHUNK_SIZE`]. A read error part-way through ends
+/// the stream with that error, after the chunks already read.
+pub async fn read_file_chunked(
+    path: String,
+    chunk_size: usize,
+    timeout_ms: Option<u64>,
+    sink: StreamSink<Vec<u8>>,
+) -> Result<(), PortalisError> {
+    let chunk_size = match chunk_size {
+        0 => DEFAULT_READ_CHUNK_SIZE,
+        n if n > MAX_READ_CHUNK_SIZE => {
+            return Err(PortalisError::InvalidInput(format!(
+                "chunk_size must be at most {MAX_READ_CHUNK_SIZE} bytes, got {n}"
+            )))
+        }
+        n => n,
+    };
+    runtime::with_timeout(timeout_ms, async move {
+        sys::read_chunked(&path, chunk_size, |chunk| sink.add(chunk).is_ok()).await
+    })
+    .await
+}
+
+/// Replaces `path` with `bytes` so readers see the old or the new contents, never a mix.
+///
+/// The data goes to a temporary file in the same directory, is fsynced, then renamed
+/// over `path`. With `verify`, a `<path>.sha256` sidecar is written for
+/// `read_file_verified`; without it any existing sidecar is removed so it can't go stale.
+pub async fn write_file_atomic(
+    path: String,
+    bytes: Vec<u8>,
+    verify: bool,
+    timeout_ms: Option<u64>,
+) -> Result<(), PortalisError> {
+    runtime::with_timeout(timeout_ms, async move {
+        let sidecar = sidecar_path(&path);
+        let digest = verify.then(|| hash::hash_slice(HashAlgo::Sha256, &bytes));
+        sys::write_atomic(path, bytes).await?;
+        match digest {
+            Some(digest) => sys::write_atomic(sidecar, digest.into_bytes()).await,
+            None => sys::remove_if_exists(&sidecar).await,
+        }
+    })
+    .await
+}
+
+/// Reads a file saved with `write_file_atomic(.., verify: true)`.
+///
+/// Fails with `Corrupted` if the contents no longer match the sidecar checksum, and
+/// with `NotFound` if the sidecar is missing.
+pub async fn read_file_verified(
+    path: String,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<u8>, PortalisError> {
+    runtime::with_timeout(timeout_ms, async move {
+        let bytes = sys::read_file(&path).await?;
+        let expected = sys::read_file(&sidecar_path(&path)).await?;
+        let expected = String::from_utf8_lossy(&expected)
+            .trim()
+            .to_ascii_lowercase();
+        let actual = hash::hash_slice(HashAlgo::Sha256, &bytes);
+        if actual != expected {
+            return Err(PortalisError::Corrupted(format!(
+                "{path}: sha256 is {actual}, sidecar says {expected}"
+            )));
+        }
+        Ok(bytes)
+    })
+    .await
+}
+
+fn sidecar_path(path: &str) -> String {
+    format!("{path}.sha256")
+}
+
+/// Copies `src` to `dst`, reporting progress after every chunk.
+pub async fn copy_file_with_progress(
+    src: String,
+    dst: String,
+    timeout_ms: Option<u64>,
+    sink: StreamSink<CopyProgress>,
+) -> Result<(), PortalisError> {
+    runtime::with_timeout(timeout_ms, async move {
+        sys::copy_file(&src, &dst, |progress| {
+            let _ = sink.add(progress);
+        })
+        .await
+    })
+    .await
+}
+
+/// Lists the direct children of `path`, directories first, then by name.
+pub async fn list_dir(
+    path: String,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<DirEntry>, PortalisError> {
+    let mut entries =
+        runtime::with_timeout(timeout_ms, async move { sys::list_dir(&path).await }).await?;
+    sort_entries(&mut entries);
+    Ok(entries)
+}
+
+pub async fn file_metadata(
+    path: String,
+    timeout_ms: Option<u64>,
+) -> Result<DirEntry, PortalisError> {
+    runtime::with_timeout(timeout_ms, async move { sys::file_metadata(&path).await }).await
+}
+
+/// Creates `path` and any missing parents; succeeds if it already exists.
+pub async fn make_dir_all(path: String, timeout_ms: Option<u64>) -> Result<(), PortalisError> {
+    runtime::with_timeout(timeout_ms, async move { sys::make_dir_all(&path).await }).await
+}
+
+fn sort_entries(entries: &mut [DirEntry]) {
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+}
+
+/// Returns up to `max_lines` lines starting at zero-based `start_line`.
+///
+/// Only the requested page is kept in memory. The byte offset of every 1024th line is
+/// remembered per file, so paging deep into a big file reads at most 1024 extra lines;
+/// the first call, or any call after the file changed, still scans from the top.
+pub async fn read_lines(
+    path: String,
+    start_line: u64,
+    max_lines: u64,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<String>, PortalisError> {
+    runtime::with_timeout(timeout_ms, sys::read_lines(path, start_line, max_lines)).await
+}
+
+/// Emits every complete line appended to `path` after the call, like `tail -f`.
+///
+/// A final line without a trailing newline is held back until it is completed. If the
+/// file is truncated, rewritten or replaced (e.g. by log rotation), following restarts
+/// from the top of whatever is there now.
+pub async fn tail_file(path: String, sink: StreamSink<String>) -> Result<(), PortalisError> {
+    runtime::run(async move { sys::tail_file(&path, |line| sink.add(line).is_ok()).await }).await
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+    use std::path::Path;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
+    use std::time::SystemTime;
+
+    use notify::{RecursiveMode, Watcher};
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+
+    use super::{CopyProgress, DirEntry};
+    use crate::error::PortalisError;
+
+    impl From<notify::Error> for PortalisError {
+        fn from(err: notify::Error) -> Self {
+            match err.kind {
+                notify::ErrorKind::Io(err) => err.into(),
+                notify::ErrorKind::PathNotFound => PortalisError::NotFound(err.to_string()),
+                _ => PortalisError::Internal(err.to_string()),
+            }
+        }
+    }
+
+    /// Chunk size used when streaming file contents.
+    pub(super) const CHUNK_SIZE: usize = 64 * 1024;
+
+    pub(super) async fn read_file(path: &str) -> Result<Vec<u8>, PortalisError> {
+        Ok(tokio::fs::read(path).await?)
+    }
+
+    pub(super) async fn write_file(path: &str, bytes: &[u8]) -> Result<(), PortalisError> {
+        Ok(tokio::fs::write(path, bytes).await?)
+    }
+
+    pub(super) async fn read_chunked(
+        path: &str,
+        chunk_size: usize,
+        emit: impl FnMut(Vec<u8>) -> bool,
+    ) -> Result<(), PortalisError> {
+        let file = tokio::fs::File::open(path).await?;
+        read_chunks(file, chunk_size, emit).await
+    }
+
+    /// Emits `reader` in `chunk_size` pieces followed by an empty end marker.
+    ///
+    /// Stops early, without the marker, once `emit` returns `false`.
+    pub(super) async fn read_chunks(
+        mut reader: impl AsyncRead + Unpin,
+        chunk_size: usize,
+        mut emit: impl FnMut(Vec<u8>) -> bool,
+    ) -> Result<(), PortalisError> {
+        loop {
+            let mut chunk = vec![0u8; chunk_size];
+            let mut filled = 0;
+            // A single read may return less than asked for; keep going so chunks stay full.
+            while filled < chunk_size {
+                let n = reader.read(&mut chunk[filled..]).await?;
+                if n == 0 {
+                    break;
+                }
+                filled += n;
+            }
+            chunk.truncate(filled);
+            let at_end = filled < chunk_size;
+            if filled > 0 && !emit(chunk) {
+                return Ok(());
+            }
+            if at_end {
+                emit(Vec::new());
+                return Ok(());
+            }
+        }
+    }
+
+    pub(super) async fn write_atomic(path: String, bytes: Vec<u8>) -> Result<(), PortalisError> {
+        tokio::task::spawn_blocking(move || write_atomic_blocking(Path::new(&path), &bytes)).await?
+    }
+
+    fn write_atomic_blocking(path: &Path, bytes: &[u8]) -> Result<(), PortalisError> {
+        // Unique per call so concurrent writers to the same path never share a temp file.
+        static NEXT_TMP: AtomicU64 = AtomicU64::new(0);
+
+        let name = path.file_name().ok_or_else(|| {
+            PortalisError::InvalidInput(format!("{} is not a file path", path.display()))
+        })?;
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let tmp = dir.join(format!(
+            ".{}.{}-{}.tmp",
+            name.to_string_lossy(),
+            std::process::id(),
+            NEXT_TMP.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let written = (|| {
+            let mut file = std::fs::File::create(&tmp)?;
+            file.write_all(bytes)?;
+            file.sync_all()?;
+            std::fs::rename(&tmp, path)
+        })();
+        if written.is_err() {
+            let _ = std::fs::remove_file(&tmp);
+        }
+        written?;
+        // Make the rename itself durable; Windows can't open directories for this.
+        #[cfg(unix)]
+        std::fs::File::open(dir)?.sync_all()?;
+        Ok(())
+    }
+
+    pub(super) async fn remove_if_exists(path: &str) -> Result<(), PortalisError> {
+        match tokio::fs::remove_file(path).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) async fn copy_file(
+        src: &str,
+        dst: &str,
+        mut on_progress: impl FnMut(CopyProgress),
+    ) -> Result<(), PortalisError> {
+        let mut reader = tokio::fs::File::open(src).await?;
+        let bytes_total = reader.metadata().await?.len();
+        let mut writer = tokio::fs::File::create(dst).await?;
+
+        let mut buf = vec![0u8; CHUNK_SIZE];
+        let mut bytes_done = 0u64;
+        on_progress(CopyProgress {
+            bytes_done,
+            bytes_total,
+        });
+        loop {
+            let n = reader.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            writer.write_all(&buf[..n]).await?;
+            bytes_done += n as u64;
+            on_progress(CopyProgress {
+                bytes_done,
+                bytes_total,
+            });
+        }
+        writer.flush().await?;
+        Ok(())
+    }
+
+    fn to_entry(name: String, meta: &std::fs::Metadata) -> DirEntry {
+        let modified_unix = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs() as i64);
+        DirEntry {
+            name,
+            is_dir: meta.is_dir(),
+            size_bytes: if meta.is_dir() { 0 } else { meta.len() },
+            modified_unix,
+        }
+    }
+
+    pub(super) async fn list_dir(path: &str) -> Result<Vec<DirEntry>, PortalisError> {
+        let mut dir = tokio::fs::read_dir(path).await?;
+        let mut entries = Vec::new();
+        while let Some(entry) = dir.next_entry().await? {
+            // Follows symlinks so a link to a folder is browsable like the folder itself.
+            let meta = tokio::fs::metadata(entry.path()).await?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            entries.push(to_entry(name, &meta));
+        }
+        Ok(entries)
+    }
+
+    pub(super) async fn file_metadata(path: &str) -> Result<DirEntry, PortalisError> {
+        let meta = tokio::fs::metadata(path).await?;
+        let name = Path::new(path)
+            .file_name()
+            .map_or_else(|| path.to_string(), |n| n.to_string_lossy().into_owned());
+        Ok(to_entry(name, &meta))
+    }
+
+    pub(super) async fn make_dir_all(path: &str) -> Result<(), PortalisError> {
+        Ok(tokio::fs::create_dir_all(path).await?)
+    }
+
+    pub(super) async fn read_lines(
+        path: String,
+        start_line: u64,
+        max_lines: u64,
+    ) -> Result<Vec<String>, PortalisError> {
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&path)?;
+            let mut index = LineIndex::take(&path, stamp(&file.metadata()?));
+            let (mut line, mut offset) = index.nearest(start_line);
+            let mut reader = std::io::BufReader::new(file);
+            reader.seek(SeekFrom::Start(offset))?;
+            let mut buf = Vec::new();
+            let mut lines = Vec::new();
+            while (lines.len() as u64) < max_lines {
+                buf.clear();
+                let read = reader.read_until(b'\n', &mut buf)?;
+                if read == 0 {
+                    break;
+                }
+                if line >= start_line {
+                    let text = buf.strip_suffix(b"\n").unwrap_or(&buf);
+                    lines.push(decode_line(text));
+                }
+                line += 1;
+                offset += read as u64;
+                index.record(line, offset);
+            }
+            index.put();
+            Ok(lines)
+        })
+        .await?
+    }
+
+    /// Lines between two remembered offsets in a `LineIndex`.
+    const LINE_INDEX_STRIDE: u64 = 1024;
+    /// How many files keep a `LineIndex` at once; the least recently read is dropped.
+    const LINE_INDEX_FILES: usize = 8;
+
+    static LINE_INDEXES: Mutex<Vec<LineIndex>> = Mutex::new(Vec::new());
+
+    /// Identity, length and mtime: replacing, rewriting or appending to a file changes
+    /// at least one of them.
+    type FileStamp = (Option<(u64, u64)>, u64, Option<SystemTime>);
+
+    fn stamp(meta: &std::fs::Metadata) -> FileStamp {
+        (file_id(meta), meta.len(), meta.modified().ok())
+    }
+
+    /// Device and inode, so a file renamed away and recreated under the same path is
+    /// seen as a different file. Unavailable elsewhere; callers fall back to contents.
+    #[cfg(unix)]
+    fn file_id(meta: &std::fs::Metadata) -> Option<(u64, u64)> {
+        use std::os::unix::fs::MetadataExt;
+        Some((meta.dev(), meta.ino()))
+    }
+
+    #[cfg(not(unix))]
+    fn file_id(_meta: &std::fs::Metadata) -> Option<(u64, u64)> {
+        None
+    }
+
+    /// Byte offsets of every `LINE_INDEX_STRIDE`th line of one version of a file.
+    struct LineIndex {
+        path: String,
+        stamp: FileStamp,
+        /// `offsets[i]` is where line `i * LINE_INDEX_STRIDE` starts.
+        offsets: Vec<u64>,
+    }
+
+    impl LineIndex {
+        /// Removes and returns the cached index for `path`, or a fresh one if the file
+        /// changed since it was built. Concurrent readers of one file each build their own.
+        fn take(path: &str, stamp: FileStamp) -> Self {
+            let mut cache = LINE_INDEXES.lock().unwrap_or_else(|p| p.into_inner());
+            let cached = cache
+                .iter()
+                .position(|index| index.path == path)
+                .map(|pos| cache.remove(pos));
+            match cached {
+                Some(index) if index.stamp == stamp => index,
+                _ => LineIndex {
+                    path: path.to_string(),
+                    stamp,
+                    offsets: vec![0],
+                },
+            }
+        }
+
+        fn put(self) {
+            let mut cache = LINE_INDEXES.lock().unwrap_or_else(|p| p.into_inner());
+            if cache.len() == LINE_INDEX_FILES {
+                cache.remove(0);
+            }
+            cache.push(self);
+        }
+
+        /// The closest known `(line, offset)` at or before `line`.
+        fn nearest(&self, line: u64) -> (u64, u64) {
+            let slot = ((line / LINE_INDEX_STRIDE) as usize).min(self.offsets.len() - 1);
+            (slot as u64 * LINE_INDEX_STRIDE, self.offsets[slot])
+        }
+
+        /// Notes that `line` starts at `offset`, if that extends the index.
+        fn record(&mut self, line: u64, offset: u64) {
+            if line == self.offsets.len() as u64 * LINE_INDEX_STRIDE {
+                self.offsets.push(offset);
+            }
+        }
+    }
+
+    /// Splits raw bytes into lines, keeping any incomplete trailing line for later.
+    ///
+    /// Splitting on `\n` bytes never cuts a multi-byte UTF-8 sequence in half.
+    pub(super) struct LineSplitter {
+        pending: Vec<u8>,
+    }
+
+    impl LineSplitter {
+        pub(super) fn new() -> Self {
+            LineSplitter {
+                pending: Vec::new(),
+            }
+        }
+
+        pub(super) fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+            self.pending.extend_from_slice(bytes);
+            let Some(last_newline) = self.pending.iter().rposition(|&b| b == b'\n') else {
+                return Vec::new();
+            };
+            let rest = self.pending.split_off(last_newline + 1);
+            let complete = std::mem::replace(&mut self.pending, rest);
+            complete[..last_newline]
+                .split(|&b| b == b'\n')
+                .map(decode_line)
+                .collect()
+        }
+
+        fn clear(&mut self) {
+            self.pending.clear();
+        }
+    }
+
+    fn decode_line(line: &[u8]) -> String {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        String::from_utf8_lossy(line).into_owned()
+    }
+
+    /// Bytes just before the read position that must still be there for new data to
+    /// count as appended rather than part of a rewritten file.
+    const TAIL_FINGERPRINT: usize = 256;
+
+    /// Follows a file from its current end, re-reading whatever was appended.
+    pub(super) struct Tail {
+        path: String,
+        id: Option<(u64, u64)>,
+        offset: u64,
+        /// The last bytes read, up to `TAIL_FINGERPRINT` of them.
+        seen: Vec<u8>,
+        lines: LineSplitter,
+    }
+
+    impl Tail {
+        pub(super) fn at_end(path: &str) -> Result<Self, PortalisError> {
+            let mut file = std::fs::File::open(path)?;
+            let meta = file.metadata()?;
+            let offset = meta.len();
+            let start = offset.saturating_sub(TAIL_FINGERPRINT as u64);
+            file.seek(SeekFrom::Start(start))?;
+            let mut seen = Vec::new();
+            file.read_to_end(&mut seen)?;
+            Ok(Tail {
+                path: path.to_string(),
+                id: file_id(&meta),
+                offset: start + seen.len() as u64,
+                seen,
+                lines: LineSplitter::new(),
+            })
+        }
+
+        pub(super) fn read_new_lines(&mut self) -> Result<Vec<String>, PortalisError> {
+            let mut file = match std::fs::File::open(&self.path) {
+                Ok(file) => file,
+                // Mid-rotation: the old file was moved away and the new one isn't there yet.
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+                Err(err) => return Err(err.into()),
+            };
+            let meta = file.metadata()?;
+            if file_id(&meta) != self.id || !self.still_prefix(&mut file, meta.len())? {
+                // Rotated, truncated or rewritten: whatever is there now is all new.
+                self.id = file_id(&meta);
+                self.offset = 0;
+                self.seen.clear();
+                self.lines.clear();
+            }
+            file.seek(SeekFrom::Start(self.offset))?;
+            let mut appended = Vec::new();
+            self.offset += file.read_to_end(&mut appended)? as u64;
+            self.seen.extend_from_slice(&appended);
+            let excess = self.seen.len().saturating_sub(TAIL_FINGERPRINT);
+            self.seen.drain(..excess);
+            Ok(self.lines.push(&appended))
+        }
+
+        /// Whether the file still ends its first `offset` bytes with what was last read.
+        fn still_prefix(&self, file: &mut std::fs::File, len: u64) -> Result<bool, PortalisError> {
+            if len < self.offset {
+                return Ok(false);
+            }
+            let mut current = vec![0; self.seen.len()];
+            file.seek(SeekFrom::Start(self.offset - self.seen.len() as u64))?;
+            file.read_exact(&mut current)?;
+            Ok(current == self.seen)
+        }
+    }
+
+    /// Runs until `emit` returns `false` (the Dart stream was closed) or the watcher fails.
+    pub(super) async fn tail_file(
+        path: &str,
+        mut emit: impl FnMut(String) -> bool,
+    ) -> Result<(), PortalisError> {
+        let mut tail = Tail::at_end(path)?;
+        let file = Path::new(path);
+        let name = file.file_name().map(|n| n.to_os_string());
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        })?;
+        // Watch the directory, like `watch_config`: a watch on the file itself follows the
+        // renamed inode and goes quiet once a rotation puts a new file at `path`.
+        let dir = match file.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+
+        while let Some(event) = rx.recv().await {
+            let event: notify::Event = event?;
+            if !event.paths.iter().any(|p| p.file_name() == name.as_deref()) {
+                continue;
+            }
+            for line in tail.read_new_lines()? {
+                if !emit(line) {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::{CopyProgress, DirEntry};
+    use crate::error::PortalisError;
+
+    fn unsupported() -> PortalisError {
+        PortalisError::unsupported_on_web("filesystem access")
+    }
+
+    pub(super) async fn read_file(_path: &str) -> Result<Vec<u8>, PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn write_file(_path: &str, _bytes: &[u8]) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn read_chunked(
+        _path: &str,
+        _chunk_size: usize,
+        _emit: impl FnMut(Vec<u8>) -> bool,
+    ) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn write_atomic(_path: String, _bytes: Vec<u8>) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn remove_if_exists(_path: &str) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn copy_file(
+        _src: &str,
+        _dst: &str,
+        _on_progress: impl FnMut(CopyProgress),
+    ) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn list_dir(_path: &str) -> Result<Vec<DirEntry>, PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn file_metadata(_path: &str) -> Result<DirEntry, PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn make_dir_all(_path: &str) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn read_lines(
+        _path: String,
+        _start_line: u64,
+        _max_lines: u64,
+    ) -> Result<Vec<String>, PortalisError> {
+        Err(unsupported())
+    }
+
+    pub(super) async fn tail_file(
+        _path: &str,
+        _emit: impl FnMut(String) -> bool,
+    ) -> Result<(), PortalisError> {
+        Err(unsupported())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn write_then_read_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.bin").to_string_lossy().into_owned();
+
+        write_file(path.clone(), vec![1, 2, 3], None).await.unwrap();
+        assert_eq!(read_file(path, None).await.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn read_missing_file_is_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").to_string_lossy().into_owned();
+
+        let err = read_file(path, None).await.unwrap_err();
+        assert_eq!(err.code(), "not_found");
+    }
+
+    #[tokio::test]
+    async fn chunked_read_reassembles_to_the_original() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.bin");
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let mut chunks = Vec::new();
+        sys::read_chunked(path.to_str().unwrap(), 4096, |chunk| {
+            chunks.push(chunk);
+            true
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(chunks.last(), Some(&Vec::new()));
+        let body = &chunks[..chunks.len() - 1];
+        assert!(body[..body.len() - 1].iter().all(|c| c.len() == 4096));
+        assert_eq!(body.concat(), data);
+    }
+
+    /// Yields `data`, then fails every read after it.
+    struct FailAfter {
+        data: std::io::Cursor<Vec<u8>>,
+    }
+
+    impl tokio::io::AsyncRead for FailAfter {
+        fn poll_read(
+            mut self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            use std::io::Read;
+
+            let mut scratch = vec![0u8; buf.remaining()];
+            let result = match self.data.read(&mut scratch) {
+                Ok(0) => Err(std::io::Error::other("disk went away")),
+                Ok(n) => {
+                    buf.put_slice(&scratch[..n]);
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            };
+            std::task::Poll::Ready(result)
+        }
+    }
+
+    #[tokio::test]
+    async fn chunked_read_error_ends_the_stream() {
+        let reader = FailAfter {
+            data: std::io::Cursor::new(vec![7u8; 10]),
+        };
+
+        let mut chunks = Vec::new();
+        let err = io::read_chunks(reader, 4, |chunk| {
+            chunks.push(chunk);
+            true
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code(), "io");
+        assert_eq!(chunks, [vec![7u8; 4], vec![7u8; 4]]);
+    }
+
+    #[tokio::test]
+    async fn atomic_write_replaces_file_without_leaving_temp_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json").to_string_lossy().into_owned();
+
+        write_file_atomic(path.clone(), b"v1".to_vec(), false, None)
+            .await
+            .unwrap();
+        write_file_atomic(path.clone(), b"v2".to_vec(), false, None)
+            .await
+            .unwrap();
+
+        assert_eq!(read_file(path, None).await.unwrap(), b"v2");
+        let names: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(names, ["state.json"]);
+    }
+
+    #[tokio::test]
+    async fn verified_read_detects_tampering() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("save.bin").to_string_lossy().into_owned();
+
+        write_file_atomic(path.clone(), b"important".to_vec(), true, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            read_file_verified(path.clone(), None).await.unwrap(),
+            b"important"
+        );
+
+        std::fs::write(&path, b"imp0rtant").unwrap();
+        let err = read_file_verified(path, None).await.unwrap_err();
+        assert_eq!(err.code(), "corrupted");
+    }
+
+    #[tokio::test]
+    async fn unverified_write_drops_stale_sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("save.bin").to_string_lossy().into_owned();
+
+        write_file_atomic(path.clone(), b"old".to_vec(), true, None)
+            .await
+            .unwrap();
+        write_file_atomic(path.clone(), b"new".to_vec(), false, None)
+            .await
+            .unwrap();
+
+        let err = read_file_verified(path, None).await.unwrap_err();
+        assert_eq!(err.code(), "not_found");
+    }
+
+    #[tokio::test]
+    async fn copy_reports_progress_until_done() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src.bin");
+        let dst = dir.path().join("dst.bin");
+        let data: Vec<u8> = (0..io::CHUNK_SIZE * 2 + 10).map(|i| i as u8).collect();
+        std::fs::write(&src, &data).unwrap();
+
+        let mut events = Vec::new();
+        sys::copy_file(src.to_str().unwrap(), dst.to_str().unwrap(), |p| {
+            events.push((p.bytes_done, p.bytes_total))
+        })
+        .await
+        .unwrap();
+
+        let total = data.len() as u64;
+        assert_eq!(events.first(), Some(&(0, total)));
+        assert_eq!(events.last(), Some(&(total, total)));
+        assert_eq!(events.len(), 4);
+        assert_eq!(std::fs::read(&dst).unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn list_dir_puts_dirs_first_then_sorts_by_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_string_lossy().into_owned();
+        std::fs::write(dir.path().join("b.txt"), b"12345").unwrap();
+        std::fs::write(dir.path().join("a.txt"), b"").unwrap();
+        make_dir_all(format!("{root}/zeta/nested"), None)
+            .await
+            .unwrap();
+        make_dir_all(format!("{root}/alpha"), None).await.unwrap();
+
+        let entries = list_dir(root, None).await.unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["alpha", "zeta", "a.txt", "b.txt"]);
+        assert!(entries[0].is_dir && entries[0].size_bytes == 0);
+        assert_eq!(entries[3].size_bytes, 5);
+        assert!(entries[3].modified_unix > 0);
+    }
+
+    #[tokio::test]
+    async fn file_metadata_describes_single_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.md");
+        std::fs::write(&path, b"# hi").unwrap();
+
+        let entry = file_metadata(path.to_string_lossy().into_owned(), None)
+            .await
+            .unwrap();
+        assert_eq!(entry.name, "notes.md");
+        assert!(!entry.is_dir);
+        assert_eq!(entry.size_bytes, 4);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unreadable_dir_is_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let locked = dir.path().join("locked");
+        std::fs::create_dir(&locked).unwrap();
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000)).unwrap();
+        // Root ignores permission bits, so there is nothing to assert there.
+        let readable = std::fs::read_dir(&locked).is_ok();
+
+        let result = list_dir(locked.to_string_lossy().into_owned(), None).await;
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755)).unwrap();
+        if !readable {
+            assert_eq!(result.unwrap_err().code(), "permission_denied");
+        }
+    }
+
+    fn write_lines(dir: &tempfile::TempDir, name: &str, content: &str) -> String {
+        let path = dir.path().join(name);
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[tokio::test]
+    async fn read_lines_pages_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_lines(&dir, "log.txt", "zero\none\r\ntwo\nthree");
+
+        assert_eq!(
+            read_lines(path.clone(), 1, 2, None).await.unwrap(),
+            ["one", "two"]
+        );
+        assert_eq!(
+            read_lines(path.clone(), 3, 10, None).await.unwrap(),
+            ["three"]
+        );
+        assert!(read_lines(path, 10, 10, None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn read_lines_keeps_multibyte_characters() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_lines(&dir, "utf8.txt", "héllo\n日本語\n🦀\n");
+
+        assert_eq!(
+            read_lines(path, 0, 10, None).await.unwrap(),
+            ["héllo", "日本語", "🦀"]
+        );
+    }
+
+    #[test]
+    fn splitter_holds_back_incomplete_lines() {
+        let mut lines = io::LineSplitter::new();
+        let crab = "🦀".as_bytes();
+
+        assert!(lines.push(b"first").is_empty());
+        assert_eq!(lines.push(b" line\nsec"), ["first line"]);
+        // Split a four-byte character across two pushes.
+        assert!(lines.push(&crab[..2]).is_empty());
+        let mut rest = crab[2..].to_vec();
+        rest.extend_from_slice(b"ond\n");
+        assert_eq!(lines.push(&rest), ["sec🦀ond"]);
+    }
+
+    #[tokio::test]
+    async fn read_lines_reuses_and_invalidates_its_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let numbered = |n: u32| (0..n).map(|i| format!("{i}\n")).collect::<String>();
+        let path = write_lines(&dir, "big.log", &numbered(3000));
+
+        // The second read starts from the remembered offset of line 2048.
+        for _ in 0..2 {
+            assert_eq!(
+                read_lines(path.clone(), 2500, 2, None).await.unwrap(),
+                ["2500", "2501"]
+            );
+        }
+        std::fs::write(&path, format!("first\n{}", numbered(3000))).unwrap();
+        assert_eq!(
+            read_lines(path.clone(), 2500, 1, None).await.unwrap(),
+            ["2499"]
+        );
+    }
+
+    #[test]
+    fn tail_resets_after_truncation_or_rewrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_lines(&dir, "app.log", "old\n");
+        let mut tail = io::Tail::at_end(&path).unwrap();
+
+        // Same length, so only the contents show the file was replaced.
+        std::fs::write(&path, "new\n").unwrap();
+        assert_eq!(tail.read_new_lines().unwrap(), ["new"]);
+        std::fs::write(&path, "x\n").unwrap();
+        assert_eq!(tail.read_new_lines().unwrap(), ["x"]);
+        std::fs::write(&path, "x\ny\n").unwrap();
+        assert_eq!(tail.read_new_lines().unwrap(), ["y"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn tail_follows_rotation_to_the_new_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_lines(&dir, "app.log", "before rotation\n");
+        let mut tail = io::Tail::at_end(&path).unwrap();
+
+        std::fs::rename(&path, dir.path().join("app.log.1")).unwrap();
+        write_lines(&dir, "app.log", "before rotation\nfresh\n");
+        assert_eq!(tail.read_new_lines().unwrap(), ["before rotation", "fresh"]);
+    }
+
+    #[tokio::test]
+    async fn tail_file_emits_appended_lines() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_lines(&dir, "follow.log", "already here\n");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let task = tokio::spawn({
+            let path = path.clone();
+            async move { sys::tail_file(&path, |line| tx.send(line).is_ok()).await }
+        });
+        // Give the watcher a moment to register before appending.
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        file.write_all(b"appended\npart").unwrap();
+        file.flush().unwrap();
+
+        let line = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap();
+        assert_eq!(line.as_deref(), Some("appended"));
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn tail_file_keeps_following_across_a_rename_rotation() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_lines(&dir, "app.log", "");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let task = tokio::spawn({
+            let path = path.clone();
+            async move { sys::tail_file(&path, |line| tx.send(line).is_ok()).await }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        let patience = std::time::Duration::from_secs(5);
+
+        let append = |line: &str| {
+            let mut file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(&path)
+                .unwrap();
+            file.write_all(line.as_bytes()).unwrap();
+        };
+        append("old file\n");
+        let line = tokio::time::timeout(patience, rx.recv()).await.unwrap();
+        assert_eq!(line.as_deref(), Some("old file"));
+
+        std::fs::rename(&path, dir.path().join("app.log.1")).unwrap();
+        write_lines(&dir, "app.log", "");
+        append("new file\n");
+        let line = tokio::time::timeout(patience, rx.recv()).await.unwrap();
+        assert_eq!(line.as_deref(), Some("new file"));
+        task.abort();
+    }
+}
@@ -0,0 +1,302 @@
+use std::sync::Mutex;
+
+use flutter_rust_bridge::frb;
+use sha2::Digest as _;
+
+use crate::error::PortalisError;
+use crate::{metrics, runtime};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgo {
+    Sha256,
+    Blake3,
+}
+
+/// Incremental hashing state shared by the one-shot and file helpers.
+#[derive(Clone)]
+enum Digest {
+    Sha256(sha2::Sha256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Digest {
+    fn new(algo: HashAlgo) -> Self {
+        match algo {
+            HashAlgo::Sha256 => Digest::Sha256(sha2::Sha256::new()),
+            HashAlgo::Blake3 => Digest::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Digest::Sha256(h) => h.update(data),
+            Digest::Blake3(h) => {
+                h.update(data);
+            }
+        }
+    }
+
+    /// Lowercase hex digest.
+    fn finalize(self) -> String {
+        match self {
+            Digest::Sha256(h) => hex::encode(h.finalize()),
+            Digest::Blake3(h) => h.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+/// Running hash Dart feeds chunk by chunk, e.g. while an upload arrives.
+///
+/// The state sits behind a lock, so concurrent async calls on one handle are safe;
+/// chunks are hashed in the order their `update` calls take the lock.
+#[frb(opaque)]
+pub struct Hasher {
+    state: Mutex<Digest>,
+}
+
+impl Hasher {
+    #[frb(sync)]
+    pub fn new(algo: HashAlgo) -> Self {
+        Hasher {
+            state: Mutex::new(Digest::new(algo)),
+        }
+    }
+
+    // Sync for the same reason as `hash_bytes`: chunks are expected to be small.
+    #[frb(sync)]
+    pub fn update(&self, data: Vec<u8>) {
+        self.lock().update(&data);
+    }
+
+    /// Lowercase hex digest of everything fed so far.
+    ///
+    /// The handle keeps its state, so more data can still be added afterwards.
+    #[frb(sync)]
+    pub fn finalize(&self) -> String {
+        self.lock().clone().finalize()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Digest> {
+        // A panic mid-update can't leave a digest half-written, so a poisoned lock is fine.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Hashes an in-memory buffer and returns the lowercase hex digest.
+// Sync so small payloads on web don't need a worker; use `hash_file` for large data.
+#[frb(sync)]
+pub fn hash_bytes(algo: HashAlgo, data: Vec<u8>) -> String {
+    hash_slice(algo, &data)
+}
+
+/// Borrowing form of [`hash_bytes`] for Rust callers.
+pub(crate) fn hash_slice(algo: HashAlgo, data: &[u8]) -> String {
+    let mut digest = Digest::new(algo);
+    digest.update(data);
+    digest.finalize()
+}
+
+/// Hashes a file in 64KB chunks so it is never fully loaded into memory.
+pub async fn hash_file(
+    path: String,
+    algo: HashAlgo,
+    timeout_ms: Option<u64>,
+) -> Result<String, PortalisError> {
+    let _timing = metrics::time_scope("hash_file");
+    runtime::with_timeout(timeout_ms, sys::hash_file(path, algo)).await
+}
+
+/// Hashes many files at once, spread across all CPU cores on native.
+///
+/// Results are `(path, hex digest)` in the same order as `paths`. The first file that
+/// can't be read aborts the batch with an error naming that path.
+// Not `#[frb(sync)]` and not async: this is CPU-bound, so it runs on the FRB thread pool.
+pub fn parallel_hash_files(
+    paths: Vec<String>,
+    algo: HashAlgo,
+) -> Result<Vec<(String, String)>, PortalisError> {
+    let _timing = metrics::time_scope("parallel_hash_files");
+    runtime::par_map(paths, |path| {
+        let digest = sys::hash_file_blocking(&path, algo).map_err(|e| e.context(&path))?;
+        Ok((path, digest))
+    })
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::io::Read;
+
+    use super::{Digest, HashAlgo};
+    use crate::error::PortalisError;
+
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    pub(super) async fn hash_file(path: String, algo: HashAlgo) -> Result<String, PortalisError> {
+        tokio::task::spawn_blocking(move || hash_file_blocking(&path, algo)).await?
+    }
+
+    pub(super) fn hash_file_blocking(path: &str, algo: HashAlgo) -> Result<String, PortalisError> {
+        let mut file = std::fs::File::open(path)?;
+        let mut digest = Digest::new(algo);
+        let mut buf = vec![0u8; CHUNK_SIZE];
+        loop {
+            let n = file.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            digest.update(&buf[..n]);
+        }
+        Ok(digest.finalize())
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::HashAlgo;
+    use crate::error::PortalisError;
+
+    pub(super) async fn hash_file(_path: String, _algo: HashAlgo) -> Result<String, PortalisError> {
+        Err(PortalisError::unsupported_on_web("filesystem access"))
+    }
+
+    pub(super) fn hash_file_blocking(
+        _path: &str,
+        _algo: HashAlgo,
+    ) -> Result<String, PortalisError> {
+        Err(PortalisError::unsupported_on_web("filesystem access"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA256_EMPTY: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+    const SHA256_ABC: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    const BLAKE3_EMPTY: &str = "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262";
+    const BLAKE3_ABC: &str = "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85";
+
+    #[test]
+    fn hash_bytes_matches_known_vectors() {
+        assert_eq!(hash_bytes(HashAlgo::Sha256, vec![]), SHA256_EMPTY);
+        assert_eq!(hash_bytes(HashAlgo::Sha256, b"abc".to_vec()), SHA256_ABC);
+        assert_eq!(hash_bytes(HashAlgo::Blake3, vec![]), BLAKE3_EMPTY);
+        assert_eq!(hash_bytes(HashAlgo::Blake3, b"abc".to_vec()), BLAKE3_ABC);
+    }
+
+    #[test]
+    fn incremental_updates_match_one_shot() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+
+        for algo in [HashAlgo::Sha256, HashAlgo::Blake3] {
+            let hasher = Hasher::new(algo);
+            hasher.update(data[..10].to_vec());
+            hasher.update(data[10..7_000].to_vec());
+            hasher.update(data[7_000..].to_vec());
+            assert_eq!(
+                hasher.finalize(),
+                hash_bytes(algo, data.clone()),
+                "{algo:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn finalize_leaves_the_hasher_usable() {
+        let hasher = Hasher::new(HashAlgo::Sha256);
+        assert_eq!(hasher.finalize(), SHA256_EMPTY);
+        hasher.update(b"abc".to_vec());
+        assert_eq!(hasher.finalize(), SHA256_ABC);
+        assert_eq!(hasher.finalize(), SHA256_ABC);
+    }
+
+    #[tokio::test]
+    async fn hasher_can_be_shared_across_tasks() {
+        let hasher = std::sync::Arc::new(Hasher::new(HashAlgo::Blake3));
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let hasher = hasher.clone();
+                tokio::spawn(async move { hasher.update(vec![7u8; 1024]) })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(
+            hasher.finalize(),
+            hash_bytes(HashAlgo::Blake3, vec![7u8; 8 * 1024])
+        );
+    }
+
+    #[tokio::test]
+    async fn hash_file_matches_hash_bytes_across_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blob.bin");
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+        let path = path.to_string_lossy().into_owned();
+
+        for algo in [HashAlgo::Sha256, HashAlgo::Blake3] {
+            let from_file = hash_file(path.clone(), algo, None).await.unwrap();
+            assert_eq!(from_file, hash_bytes(algo, data.clone()));
+        }
+    }
+
+    fn fixture_files(dir: &tempfile::TempDir, count: usize) -> Vec<(String, Vec<u8>)> {
+        (0..count)
+            .map(|i| {
+                // Varying sizes so workers finish out of order.
+                let data: Vec<u8> = (0..(count - i) * 10_000).map(|b| (b * i) as u8).collect();
+                let path = dir.path().join(format!("file-{i}.bin"));
+                std::fs::write(&path, &data).unwrap();
+                (path.to_string_lossy().into_owned(), data)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parallel_hashes_keep_input_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = fixture_files(&dir, 24);
+        let paths: Vec<String> = files.iter().map(|(path, _)| path.clone()).collect();
+
+        let results = parallel_hash_files(paths, HashAlgo::Blake3).unwrap();
+
+        let expected: Vec<(String, String)> = files
+            .into_iter()
+            .map(|(path, data)| (path, hash_bytes(HashAlgo::Blake3, data)))
+            .collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn parallel_hash_reports_the_failing_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut paths: Vec<String> = fixture_files(&dir, 4).into_iter().map(|(p, _)| p).collect();
+        let missing = dir
+            .path()
+            .join("missing.bin")
+            .to_string_lossy()
+            .into_owned();
+        paths.insert(2, missing.clone());
+
+        let err = parallel_hash_files(paths, HashAlgo::Sha256).unwrap_err();
+        assert_eq!(err.code(), "not_found");
+        assert!(err.to_string().contains(&missing), "{err}");
+    }
+
+    #[tokio::test]
+    async fn hash_file_missing_is_not_found() {
+        let err = hash_file("/definitely/not/here".into(), HashAlgo::Sha256, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "not_found");
+    }
+}
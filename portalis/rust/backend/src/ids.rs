@@ -0,0 +1,70 @@
+use flutter_rust_bridge::frb;
+use uuid::Uuid;
+
+// All sync: generating or parsing a UUID is far cheaper than a worker round-trip.
+
+/// Random UUID in lowercase hyphenated form.
+#[frb(sync)]
+pub fn new_uuid_v4() -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// Time-ordered UUID, so ids sort by creation time when used as database keys.
+///
+/// Values from one process are strictly increasing, even within the same millisecond.
+#[frb(sync)]
+pub fn new_uuid_v7() -> String {
+    // `now_v7` draws from uuid's process-wide context, which carries a counter for
+    // ids generated within the same millisecond.
+    Uuid::now_v7().to_string()
+}
+
+/// Accepts hyphenated, simple, braced and `urn:uuid:` forms in either case.
+#[frb(sync)]
+pub fn is_valid_uuid(s: String) -> bool {
+    Uuid::parse_str(&s).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v4_and_v7_report_their_version() {
+        assert_eq!(
+            Uuid::parse_str(&new_uuid_v4()).unwrap().get_version_num(),
+            4
+        );
+        assert_eq!(
+            Uuid::parse_str(&new_uuid_v7()).unwrap().get_version_num(),
+            7
+        );
+        assert_ne!(new_uuid_v4(), new_uuid_v4());
+    }
+
+    #[test]
+    fn v7_sorts_in_generation_order() {
+        let ids: Vec<String> = (0..10_000).map(|_| new_uuid_v7()).collect();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn validation_accepts_common_forms_only() {
+        for good in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert!(is_valid_uuid(good.into()), "{good}");
+        }
+        for bad in [
+            "",
+            "67e55044-10b1-426f-9247",
+            "67e55044-10b1-426f-9247-bb680e5fe0cz",
+        ] {
+            assert!(!is_valid_uuid(bad.into()), "{bad}");
+        }
+    }
+}
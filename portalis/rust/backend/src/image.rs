@@ -0,0 +1,156 @@
+use std::io::Cursor;
+
+use ::image::codecs::jpeg::JpegEncoder;
+use ::image::{DynamicImage, GenericImageView, ImageReader};
+
+use crate::error::PortalisError;
+use crate::runtime;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    /// Quality 85; any transparency is flattened to black.
+    Jpeg,
+}
+
+const JPEG_QUALITY: u8 = 85;
+
+/// Scales `input` (PNG or JPEG) to fit within `max_dim`×`max_dim`, keeping the
+/// aspect ratio, and encodes it as `format`. Images already small enough are
+/// re-encoded at their original size, never upscaled.
+pub async fn make_thumbnail(
+    input: Vec<u8>,
+    max_dim: u32,
+    format: ImageFormat,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<u8>, PortalisError> {
+    if max_dim == 0 {
+        return Err(PortalisError::InvalidInput(
+            "max_dim must be at least 1".into(),
+        ));
+    }
+    runtime::with_timeout(
+        timeout_ms,
+        sys::off_thread(move || thumbnail(&input, max_dim, format)),
+    )
+    .await
+}
+
+fn thumbnail(input: &[u8], max_dim: u32, format: ImageFormat) -> Result<Vec<u8>, PortalisError> {
+    let img = ImageReader::new(Cursor::new(input))
+        .with_guessed_format()?
+        .decode()
+        .map_err(|e| PortalisError::InvalidInput(format!("cannot decode image: {e}")))?;
+    let (width, height) = img.dimensions();
+    let img = if width > max_dim || height > max_dim {
+        img.thumbnail(max_dim, max_dim)
+    } else {
+        img
+    };
+
+    let mut out = Vec::new();
+    match format {
+        ImageFormat::Png => img.write_to(&mut Cursor::new(&mut out), ::image::ImageFormat::Png),
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(img.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut out, JPEG_QUALITY)),
+    }
+    .map_err(|e| PortalisError::Internal(format!("cannot encode thumbnail: {e}")))?;
+    Ok(out)
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use crate::error::PortalisError;
+
+    /// Decoding is CPU-bound, so keep it off the async worker threads.
+    pub(super) async fn off_thread<T: Send + 'static>(
+        f: impl FnOnce() -> Result<T, PortalisError> + Send + 'static,
+    ) -> Result<T, PortalisError> {
+        tokio::task::spawn_blocking(f).await?
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use crate::error::PortalisError;
+
+    pub(super) async fn off_thread<T>(
+        f: impl FnOnce() -> Result<T, PortalisError>,
+    ) -> Result<T, PortalisError> {
+        f()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::image::{Rgba, RgbaImage};
+
+    use super::*;
+
+    /// A 64×32 gradient PNG, generated so the test needs no binary fixture file.
+    fn fixture_png() -> Vec<u8> {
+        let img = RgbaImage::from_fn(64, 32, |x, y| {
+            Rgba([(x * 4) as u8, (y * 8) as u8, 128, 255])
+        });
+        let mut out = Vec::new();
+        DynamicImage::ImageRgba8(img)
+            .write_to(&mut Cursor::new(&mut out), ::image::ImageFormat::Png)
+            .unwrap();
+        out
+    }
+
+    fn dimensions(encoded: &[u8]) -> (u32, u32) {
+        ::image::load_from_memory(encoded).unwrap().dimensions()
+    }
+
+    #[tokio::test]
+    async fn shrinks_to_fit_and_keeps_aspect_ratio() {
+        for format in [ImageFormat::Png, ImageFormat::Jpeg] {
+            let thumb = make_thumbnail(fixture_png(), 16, format, None)
+                .await
+                .unwrap();
+            assert_eq!(dimensions(&thumb), (16, 8), "{format:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn encodes_requested_format() {
+        let png = make_thumbnail(fixture_png(), 16, ImageFormat::Png, None)
+            .await
+            .unwrap();
+        let jpeg = make_thumbnail(fixture_png(), 16, ImageFormat::Jpeg, None)
+            .await
+            .unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        assert!(jpeg.starts_with(&[0xff, 0xd8]));
+    }
+
+    #[tokio::test]
+    async fn small_images_are_not_upscaled() {
+        let thumb = make_thumbnail(fixture_png(), 500, ImageFormat::Png, None)
+            .await
+            .unwrap();
+        assert_eq!(dimensions(&thumb), (64, 32));
+    }
+
+    #[tokio::test]
+    async fn corrupt_or_unknown_input_is_invalid() {
+        let mut truncated = fixture_png();
+        truncated.truncate(40);
+        for input in [b"definitely not an image".to_vec(), truncated] {
+            let err = make_thumbnail(input, 16, ImageFormat::Png, None)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), "invalid_input");
+        }
+        let err = make_thumbnail(fixture_png(), 0, ImageFormat::Png, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+}
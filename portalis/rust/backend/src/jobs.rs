@@ -0,0 +1,269 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use flutter_rust_bridge::frb;
+
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::events::{self, AppEvent};
+use crate::hash::{self, HashAlgo};
+
+/// CPU-heavy work that can be handed to the background pool.
+pub enum JobSpec {
+    Hash { algo: HashAlgo, data: Vec<u8> },
+}
+
+pub enum JobEvent {
+    Started { id: u64 },
+    Completed { id: u64, result: String },
+    Failed { id: u64, error: String },
+}
+
+// Ids start at 1 and are never handed out twice, even after a job finishes.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+static SINK: Mutex<Option<StreamSink<JobEvent>>> = Mutex::new(None);
+
+fn emit(event: JobEvent) {
+    match event {
+        JobEvent::Completed { id, .. } => events::publish(AppEvent::JobFinished {
+            id,
+            succeeded: true,
+        }),
+        JobEvent::Failed { id, .. } => events::publish(AppEvent::JobFinished {
+            id,
+            succeeded: false,
+        }),
+        JobEvent::Started { .. } => {}
+    }
+    if let Ok(sink) = SINK.lock() {
+        if let Some(sink) = sink.as_ref() {
+            let _ = sink.add(event);
+        }
+    }
+}
+
+fn run(spec: JobSpec) -> Result<String, PortalisError> {
+    match spec {
+        JobSpec::Hash { algo, data } => Ok(hash::hash_bytes(algo, data)),
+    }
+}
+
+/// Queues `job` and returns its id; progress is reported on `job_events`.
+///
+/// On web there are no threads, so the job runs to completion before this returns.
+#[frb(sync)]
+pub fn submit_job(job: JobSpec) -> u64 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    sys::submit(id, job);
+    id
+}
+
+/// Registers the Dart stream receiving events for every job, replacing any previous one.
+#[frb(sync)]
+pub fn job_events(sink: StreamSink<JobEvent>) {
+    if let Ok(mut current) = SINK.lock() {
+        *current = Some(sink);
+    }
+}
+
+/// Cancels a job that has not started yet. Running or finished jobs return `false`.
+#[frb(sync)]
+pub fn cancel_job(id: u64) -> bool {
+    sys::cancel(id)
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Condvar, Mutex, OnceLock};
+
+    use super::{run, JobEvent, JobSpec};
+    use crate::error::PortalisError;
+
+    type Emitter = Arc<dyn Fn(JobEvent) + Send + Sync>;
+
+    /// Fixed-size worker pool draining a shared FIFO queue.
+    pub(super) struct Pool {
+        queue: Mutex<VecDeque<(u64, JobSpec)>>,
+        ready: Condvar,
+        emit: Emitter,
+    }
+
+    impl Pool {
+        pub(super) fn new(workers: usize, emit: Emitter) -> Arc<Self> {
+            let pool = Arc::new(Pool {
+                queue: Mutex::new(VecDeque::new()),
+                ready: Condvar::new(),
+                emit,
+            });
+            for _ in 0..workers.max(1) {
+                let pool = pool.clone();
+                std::thread::spawn(move || pool.work());
+            }
+            pool
+        }
+
+        pub(super) fn submit(&self, id: u64, spec: JobSpec) {
+            self.queue.lock().unwrap().push_back((id, spec));
+            self.ready.notify_one();
+        }
+
+        pub(super) fn cancel(&self, id: u64) -> bool {
+            let mut queue = self.queue.lock().unwrap();
+            let Some(pos) = queue.iter().position(|(queued, _)| *queued == id) else {
+                return false;
+            };
+            queue.remove(pos);
+            drop(queue);
+            (self.emit)(JobEvent::Failed {
+                id,
+                error: PortalisError::Cancelled(format!("job {id}")).to_string(),
+            });
+            true
+        }
+
+        fn work(&self) {
+            loop {
+                let (id, spec) = {
+                    let mut queue = self.queue.lock().unwrap();
+                    loop {
+                        if let Some(job) = queue.pop_front() {
+                            break job;
+                        }
+                        queue = self.ready.wait(queue).unwrap();
+                    }
+                };
+                (self.emit)(JobEvent::Started { id });
+                (self.emit)(match run(spec) {
+                    Ok(result) => JobEvent::Completed { id, result },
+                    Err(err) => JobEvent::Failed {
+                        id,
+                        error: err.to_string(),
+                    },
+                });
+            }
+        }
+    }
+
+    fn pool() -> &'static Pool {
+        static POOL: OnceLock<Arc<Pool>> = OnceLock::new();
+        POOL.get_or_init(|| {
+            let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+            Pool::new(workers, Arc::new(super::emit))
+        })
+    }
+
+    pub(super) fn submit(id: u64, spec: JobSpec) {
+        pool().submit(id, spec);
+    }
+
+    pub(super) fn cancel(id: u64) -> bool {
+        pool().cancel(id)
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::{emit, run, JobEvent, JobSpec};
+
+    pub(super) fn submit(id: u64, spec: JobSpec) {
+        emit(JobEvent::Started { id });
+        emit(match run(spec) {
+            Ok(result) => JobEvent::Completed { id, result },
+            Err(err) => JobEvent::Failed {
+                id,
+                error: err.to_string(),
+            },
+        });
+    }
+
+    pub(super) fn cancel(_id: u64) -> bool {
+        // Jobs finish inside `submit_job`, so there is never anything pending.
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::io::Pool;
+    use super::*;
+
+    fn hash_job(data: &[u8]) -> JobSpec {
+        JobSpec::Hash {
+            algo: HashAlgo::Sha256,
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn job_ids_are_monotonic() {
+        let first = submit_job(hash_job(b"a"));
+        let second = submit_job(hash_job(b"b"));
+        assert!(second > first);
+    }
+
+    #[test]
+    fn pool_reports_started_then_completed() {
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(tx);
+        let pool = Pool::new(
+            1,
+            Arc::new(move |event| tx.lock().unwrap().send(event).unwrap()),
+        );
+
+        pool.submit(7, hash_job(b"abc"));
+
+        let timeout = Duration::from_secs(5);
+        assert!(matches!(
+            rx.recv_timeout(timeout).unwrap(),
+            JobEvent::Started { id: 7 }
+        ));
+        match rx.recv_timeout(timeout).unwrap() {
+            JobEvent::Completed { id, result } => {
+                assert_eq!(id, 7);
+                assert_eq!(result, hash::hash_bytes(HashAlgo::Sha256, b"abc".to_vec()));
+            }
+            _ => panic!("expected Completed"),
+        }
+    }
+
+    #[test]
+    fn cancel_removes_queued_job() {
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(tx);
+        // Keep the single worker busy with a large job so the second one stays queued.
+        let pool = Pool::new(
+            1,
+            Arc::new(move |event| tx.lock().unwrap().send(event).unwrap()),
+        );
+        pool.submit(1, hash_job(&vec![0u8; 16 * 1024 * 1024]));
+        pool.submit(2, hash_job(b"never runs"));
+
+        assert!(pool.cancel(2));
+        assert!(!pool.cancel(2));
+
+        let mut seen = Vec::new();
+        while let Ok(event) = rx.recv_timeout(Duration::from_secs(10)) {
+            let done = matches!(event, JobEvent::Completed { id: 1, .. });
+            seen.push(event);
+            if done {
+                break;
+            }
+        }
+        assert!(seen.iter().any(
+            |e| matches!(e, JobEvent::Failed { id: 2, error } if error.starts_with("cancelled"))
+        ));
+        assert!(!seen
+            .iter()
+            .any(|e| matches!(e, JobEvent::Started { id: 2 })));
+    }
+}
@@ -0,0 +1,793 @@
+use std::sync::{Mutex, MutexGuard};
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use flutter_rust_bridge::frb;
+
+use crate::crypto::random_bytes;
+use crate::error::PortalisError;
+
+// Native builds persist to sled; web keeps an in-memory map behind the same API.
+// The open store is a process-wide global so Dart never handles a pointer.
+static STORE: Mutex<Option<Open>> = Mutex::new(None);
+
+struct Open {
+    store: sys::Store,
+    /// Set when opened with `kv_open_encrypted`; every value then goes through it.
+    cipher: Option<Cipher>,
+}
+
+fn lock_store() -> Result<MutexGuard<'static, Option<Open>>, PortalisError> {
+    STORE
+        .lock()
+        .map_err(|_| PortalisError::Internal("kv store lock poisoned".into()))
+}
+
+fn with_store<T>(
+    f: impl FnOnce(&mut sys::Store, Option<&Cipher>) -> Result<T, PortalisError>,
+) -> Result<T, PortalisError> {
+    match lock_store()?.as_mut() {
+        Some(open) => f(&mut open.store, open.cipher.as_ref()),
+        None => Err(PortalisError::Internal(
+            "kv store is not open; call kv_open first".into(),
+        )),
+    }
+}
+
+/// Opens (or reopens) the store at `path`. On web it lives in memory until the page
+/// reloads, but reopening the same `path` still finds its values.
+///
+/// Fails if the store was created by `kv_open_encrypted`.
+// Not `#[frb(sync)]`: closing the previous store waits for sled to release its file,
+// which must not stall the Dart UI isolate.
+pub fn kv_open(path: String) -> Result<(), PortalisError> {
+    close()?;
+    let mut store = sys::Store::open(&path)?;
+    if store.header()?.is_some() {
+        return Err(PortalisError::InvalidInput(format!(
+            "the kv store at {path} is encrypted; open it with kv_open_encrypted"
+        )));
+    }
+    *lock_store()? = Some(Open {
+        store,
+        cipher: None,
+    });
+    Ok(())
+}
+
+/// Like `kv_open`, but values are encrypted at rest with a key derived from `passphrase`.
+///
+/// The key comes from Argon2id and values are sealed with ChaCha20-Poly1305, bound to
+/// their key so they can't be swapped around. Keys themselves stay plaintext, so
+/// prefix scans still work. A new store saves its random salt and KDF parameters in a
+/// header; reopening with another passphrase fails with `PermissionDenied`, and a value
+/// altered on disk makes `kv_get` fail with `Corrupted`. An existing unencrypted store
+/// with values in it is refused rather than half-encrypted.
+// Not `#[frb(sync)]`: Argon2 is deliberately slow, so it runs on the FRB thread pool.
+pub fn kv_open_encrypted(path: String, passphrase: String) -> Result<(), PortalisError> {
+    open_encrypted(&path, &passphrase, DEFAULT_KDF)
+}
+
+/// Flushes the open store to disk and closes it; a no-op if none is open.
+///
+/// Returns once the same path can be opened again, so a reopen never trips over the
+/// file lock of the handle it replaces.
+pub(crate) fn close() -> Result<(), PortalisError> {
+    // Taken out first, so other callers aren't held up while sled lets go of the file.
+    let open = lock_store()?.take();
+    match open {
+        Some(open) => open.store.close(),
+        None => Ok(()),
+    }
+}
+
+#[frb(sync)]
+pub fn kv_set(key: String, value: Vec<u8>) -> Result<(), PortalisError> {
+    with_store(|store, cipher| store.set(&key, seal(cipher, &key, value)?))
+}
+
+#[frb(sync)]
+pub fn kv_get(key: String) -> Result<Option<Vec<u8>>, PortalisError> {
+    with_store(|store, cipher| unseal(cipher, &key, store.get(&key)?))
+}
+
+#[frb(sync)]
+pub fn kv_delete(key: String) -> Result<(), PortalisError> {
+    with_store(|store, _| store.delete(&key))
+}
+
+/// Writes every entry in one transaction: either all of them land or none do.
+#[frb(sync)]
+pub fn kv_set_batch(entries: Vec<(String, Vec<u8>)>) -> Result<(), PortalisError> {
+    with_store(|store, cipher| {
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| Ok((key.clone(), seal(cipher, &key, value)?)))
+            .collect::<Result<Vec<_>, PortalisError>>()?;
+        store.set_batch(&entries)
+    })
+}
+
+/// Looks up several keys at once; the result lines up with `keys`.
+#[frb(sync)]
+pub fn kv_get_batch(keys: Vec<String>) -> Result<Vec<Option<Vec<u8>>>, PortalisError> {
+    with_store(|store, cipher| {
+        keys.iter()
+            .map(|key| unseal(cipher, key, store.get(key)?))
+            .collect()
+    })
+}
+
+/// Returns every entry whose key starts with `prefix`, sorted by key.
+#[frb(sync)]
+pub fn kv_prefix_scan(prefix: String) -> Result<Vec<(String, Vec<u8>)>, PortalisError> {
+    with_store(|store, cipher| {
+        store
+            .prefix_scan(&prefix)?
+            .into_iter()
+            .map(|(key, value)| match cipher {
+                Some(cipher) => Ok((key.clone(), cipher.open(&key, &value)?)),
+                None => Ok((key, value)),
+            })
+            .collect()
+    })
+}
+
+/// Argon2id cost settings, saved in the header so they can be raised for new stores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct KdfParams {
+    /// Memory in KiB.
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+/// OWASP's minimum recommendation for Argon2id.
+const DEFAULT_KDF: KdfParams = KdfParams {
+    m_cost: 19 * 1024,
+    t_cost: 2,
+    p_cost: 1,
+};
+
+/// Upper bounds for costs read back from a header: 1 GiB of memory, 16 passes and 16
+/// lanes. Anything above is a damaged or hostile file, not a setting worth honouring.
+const MAX_KDF: KdfParams = KdfParams {
+    m_cost: 1024 * 1024,
+    t_cost: 16,
+    p_cost: 16,
+};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const HEADER_MAGIC: &[u8; 4] = b"PKV1";
+/// Sealed into the header so a wrong passphrase is caught at open, not on the first read.
+const HEADER_CHECK: &[u8] = b"portalis kv passphrase check";
+
+fn open_encrypted(path: &str, passphrase: &str, kdf: KdfParams) -> Result<(), PortalisError> {
+    close()?;
+    // The lock is only taken again for the swap: Argon2 takes a while, and the sync calls
+    // from the UI isolate must not queue up behind it.
+    let mut store = sys::Store::open(path)?;
+    let cipher = match store.header()? {
+        Some(bytes) => {
+            let header = Header::decode(&bytes)?;
+            let cipher = Cipher::derive(passphrase, header.kdf, &header.salt)?;
+            cipher.open("", &header.check).map_err(|_| {
+                PortalisError::PermissionDenied("wrong passphrase for the kv store".into())
+            })?;
+            cipher
+        }
+        None => {
+            if !store.is_empty()? {
+                return Err(PortalisError::InvalidInput(format!(
+                    "the kv store at {path} already holds unencrypted values"
+                )));
+            }
+            let salt = random_bytes(SALT_LEN)?;
+            let cipher = Cipher::derive(passphrase, kdf, &salt)?;
+            let check = cipher.seal("", HEADER_CHECK)?;
+            store.set_header(&Header { kdf, salt, check }.encode())?;
+            cipher
+        }
+    };
+    *lock_store()? = Some(Open {
+        store,
+        cipher: Some(cipher),
+    });
+    Ok(())
+}
+
+fn seal(cipher: Option<&Cipher>, key: &str, value: Vec<u8>) -> Result<Vec<u8>, PortalisError> {
+    match cipher {
+        Some(cipher) => cipher.seal(key, &value),
+        None => Ok(value),
+    }
+}
+
+fn unseal(
+    cipher: Option<&Cipher>,
+    key: &str,
+    value: Option<Vec<u8>>,
+) -> Result<Option<Vec<u8>>, PortalisError> {
+    match (cipher, value) {
+        (Some(cipher), Some(value)) => cipher.open(key, &value).map(Some),
+        (_, value) => Ok(value),
+    }
+}
+
+struct Cipher {
+    aead: ChaCha20Poly1305,
+}
+
+impl Cipher {
+    fn derive(passphrase: &str, kdf: KdfParams, salt: &[u8]) -> Result<Self, PortalisError> {
+        let params = argon2::Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(32))
+            .map_err(|e| PortalisError::Corrupted(format!("bad kv KDF parameters: {e}")))?;
+        let argon2 =
+            argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+        let mut key = [0u8; 32];
+        argon2
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+            .map_err(|e| PortalisError::Internal(format!("key derivation failed: {e}")))?;
+        Ok(Cipher {
+            aead: ChaCha20Poly1305::new(Key::from_slice(&key)),
+        })
+    }
+
+    /// A fresh random nonce followed by the ciphertext and tag, with `key` as associated data.
+    fn seal(&self, key: &str, value: &[u8]) -> Result<Vec<u8>, PortalisError> {
+        let mut sealed = random_bytes(NONCE_LEN)?;
+        let payload = Payload {
+            msg: value,
+            aad: key.as_bytes(),
+        };
+        let ciphertext = self
+            .aead
+            .encrypt(Nonce::from_slice(&sealed), payload)
+            .map_err(|_| PortalisError::Internal("encryption failed".into()))?;
+        sealed.extend_from_slice(&ciphertext);
+        Ok(sealed)
+    }
+
+    fn open(&self, key: &str, sealed: &[u8]) -> Result<Vec<u8>, PortalisError> {
+        let tampered =
+            || PortalisError::Corrupted(format!("the value for {key:?} failed authentication"));
+        if sealed.len() < NONCE_LEN {
+            return Err(tampered());
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: key.as_bytes(),
+        };
+        self.aead
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_| tampered())
+    }
+}
+
+/// Magic, the three KDF costs (u32 BE), the salt, then the sealed `HEADER_CHECK`.
+struct Header {
+    kdf: KdfParams,
+    salt: Vec<u8>,
+    check: Vec<u8>,
+}
+
+impl Header {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = HEADER_MAGIC.to_vec();
+        for cost in [self.kdf.m_cost, self.kdf.t_cost, self.kdf.p_cost] {
+            out.extend_from_slice(&cost.to_be_bytes());
+        }
+        out.extend_from_slice(&self.salt);
+        out.extend_from_slice(&self.check);
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, PortalisError> {
+        let corrupted = || PortalisError::Corrupted("malformed kv store header".into());
+        let rest = bytes.strip_prefix(HEADER_MAGIC).ok_or_else(corrupted)?;
+        if rest.len() < 12 + SALT_LEN {
+            return Err(corrupted());
+        }
+        let (costs, rest) = rest.split_at(12);
+        let cost = |i: usize| u32::from_be_bytes(costs[i * 4..i * 4 + 4].try_into().unwrap());
+        let (salt, check) = rest.split_at(SALT_LEN);
+        let kdf = KdfParams {
+            m_cost: cost(0),
+            t_cost: cost(1),
+            p_cost: cost(2),
+        };
+        // Argon2 itself needs at least one pass and lane, and 8 KiB per lane.
+        let sane = (1..=MAX_KDF.t_cost).contains(&kdf.t_cost)
+            && (1..=MAX_KDF.p_cost).contains(&kdf.p_cost)
+            && (8 * kdf.p_cost..=MAX_KDF.m_cost).contains(&kdf.m_cost);
+        if !sane {
+            return Err(corrupted());
+        }
+        Ok(Header {
+            kdf,
+            salt: salt.to_vec(),
+            check: check.to_vec(),
+        })
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+pub(crate) use io::{close_sled, open_sled};
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::path::Path;
+    use std::time::{Duration, Instant};
+
+    use sled::transaction::{ConflictableTransactionError, TransactionError};
+
+    use crate::error::PortalisError;
+
+    impl From<sled::Error> for PortalisError {
+        fn from(err: sled::Error) -> Self {
+            match err {
+                sled::Error::Io(err) => err.into(),
+                sled::Error::Corruption { .. } => PortalisError::Corrupted(err.to_string()),
+                other => PortalisError::Internal(other.to_string()),
+            }
+        }
+    }
+
+    /// Opens a sled database; close it with `close_sled` so it can be reopened at once.
+    pub(crate) fn open_sled(path: &str) -> Result<sled::Db, PortalisError> {
+        Ok(sled::open(path)?)
+    }
+
+    /// How long `close_sled` waits for sled to release a database's lock file.
+    const RELEASE_TIMEOUT: Duration = Duration::from_secs(2);
+
+    /// Flushes and drops `db`, then waits until nothing holds the lock sled keeps on
+    /// `<path>/db`.
+    ///
+    /// sled lets go of that lock from background threads some time after the last
+    /// handle drops, so opening the path again straight away could otherwise fail.
+    pub(crate) fn close_sled(db: sled::Db, path: &str) -> Result<(), PortalisError> {
+        db.flush()?;
+        drop(db);
+        let lock_file = match std::fs::File::open(Path::new(path).join("db")) {
+            Ok(file) => file,
+            // Deleted along with its directory: nobody can be holding it.
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+        let deadline = Instant::now() + RELEASE_TIMEOUT;
+        loop {
+            // Dropping `lock_file` (or unlocking) releases this probe lock again.
+            match lock_file.try_lock() {
+                Ok(()) => return Ok(lock_file.unlock()?),
+                Err(std::fs::TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                Err(std::fs::TryLockError::WouldBlock) => {
+                    return Err(PortalisError::Timeout(format!(
+                        "sled did not release {path} within {RELEASE_TIMEOUT:?}"
+                    )))
+                }
+                Err(std::fs::TryLockError::Error(err)) => return Err(err.into()),
+            }
+        }
+    }
+
+    pub(super) struct Store {
+        path: String,
+        db: sled::Db,
+        /// Holds the encryption header, out of the way of user keys and prefix scans.
+        meta: sled::Tree,
+    }
+
+    const HEADER_KEY: &str = "header";
+
+    impl Store {
+        pub(super) fn open(path: &str) -> Result<Self, PortalisError> {
+            let db = open_sled(path)?;
+            let meta = db.open_tree("portalis.kv.meta")?;
+            Ok(Store {
+                path: path.to_string(),
+                db,
+                meta,
+            })
+        }
+
+        pub(super) fn header(&mut self) -> Result<Option<Vec<u8>>, PortalisError> {
+            Ok(self.meta.get(HEADER_KEY)?.map(|v| v.to_vec()))
+        }
+
+        pub(super) fn set_header(&mut self, header: &[u8]) -> Result<(), PortalisError> {
+            self.meta.insert(HEADER_KEY, header)?;
+            // Losing the salt would make the whole store unreadable, so don't leave it cached.
+            self.meta.flush()?;
+            Ok(())
+        }
+
+        pub(super) fn is_empty(&mut self) -> Result<bool, PortalisError> {
+            Ok(self.db.is_empty())
+        }
+
+        /// Writes everything sled still holds in memory, the header tree included, then
+        /// closes the database.
+        pub(super) fn close(self) -> Result<(), PortalisError> {
+            drop(self.meta);
+            close_sled(self.db, &self.path)
+        }
+
+        pub(super) fn set(&mut self, key: &str, value: Vec<u8>) -> Result<(), PortalisError> {
+            self.db.insert(key, value)?;
+            Ok(())
+        }
+
+        pub(super) fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>, PortalisError> {
+            Ok(self.db.get(key)?.map(|v| v.to_vec()))
+        }
+
+        pub(super) fn delete(&mut self, key: &str) -> Result<(), PortalisError> {
+            self.db.remove(key)?;
+            Ok(())
+        }
+
+        pub(super) fn set_batch(
+            &mut self,
+            entries: &[(String, Vec<u8>)],
+        ) -> Result<(), PortalisError> {
+            self.set_batch_checked(entries, |_| Ok(()))
+        }
+
+        /// Like `set_batch`, but `check(i)` runs before the `i`th write and an error from
+        /// it rolls back the whole batch. Lets tests fail a batch part-way through.
+        pub(super) fn set_batch_checked(
+            &mut self,
+            entries: &[(String, Vec<u8>)],
+            check: impl Fn(usize) -> Result<(), PortalisError>,
+        ) -> Result<(), PortalisError> {
+            let result = self.db.transaction(|tx| {
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    check(i).map_err(ConflictableTransactionError::Abort)?;
+                    tx.insert(key.as_bytes(), value.as_slice())?;
+                }
+                Ok(())
+            });
+            match result {
+                Ok(()) => Ok(()),
+                Err(TransactionError::Abort(err)) => Err(err),
+                Err(TransactionError::Storage(err)) => Err(err.into()),
+            }
+        }
+
+        pub(super) fn prefix_scan(
+            &mut self,
+            prefix: &str,
+        ) -> Result<Vec<(String, Vec<u8>)>, PortalisError> {
+            self.db
+                .scan_prefix(prefix)
+                .map(|entry| {
+                    let (key, value) = entry?;
+                    Ok((String::from_utf8_lossy(&key).into_owned(), value.to_vec()))
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    use crate::error::PortalisError;
+
+    // Ordered so prefix scans come back sorted, matching sled.
+    struct Data {
+        map: BTreeMap<String, Vec<u8>>,
+        header: Option<Vec<u8>>,
+    }
+
+    /// Every store opened since the page loaded, by path, so a reopen (e.g. after
+    /// `shutdown_all`) keeps its values the way sled does on native.
+    static STORES: Mutex<BTreeMap<String, Data>> = Mutex::new(BTreeMap::new());
+
+    pub(super) struct Store {
+        path: String,
+    }
+
+    impl Store {
+        pub(super) fn open(path: &str) -> Result<Self, PortalisError> {
+            let store = Store {
+                path: path.to_string(),
+            };
+            store.with(|_| ())?;
+            Ok(store)
+        }
+
+        fn with<T>(&self, f: impl FnOnce(&mut Data) -> T) -> Result<T, PortalisError> {
+            let mut stores = STORES
+                .lock()
+                .map_err(|_| PortalisError::Internal("kv store lock poisoned".into()))?;
+            let data = stores.entry(self.path.clone()).or_insert_with(|| Data {
+                map: BTreeMap::new(),
+                header: None,
+            });
+            Ok(f(data))
+        }
+
+        pub(super) fn header(&mut self) -> Result<Option<Vec<u8>>, PortalisError> {
+            self.with(|data| data.header.clone())
+        }
+
+        pub(super) fn set_header(&mut self, header: &[u8]) -> Result<(), PortalisError> {
+            self.with(|data| data.header = Some(header.to_vec()))
+        }
+
+        pub(super) fn is_empty(&mut self) -> Result<bool, PortalisError> {
+            self.with(|data| data.map.is_empty())
+        }
+
+        pub(super) fn close(self) -> Result<(), PortalisError> {
+            Ok(())
+        }
+
+        pub(super) fn set(&mut self, key: &str, value: Vec<u8>) -> Result<(), PortalisError> {
+            self.with(|data| {
+                data.map.insert(key.to_string(), value);
+            })
+        }
+
+        pub(super) fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>, PortalisError> {
+            self.with(|data| data.map.get(key).cloned())
+        }
+
+        pub(super) fn delete(&mut self, key: &str) -> Result<(), PortalisError> {
+            self.with(|data| {
+                data.map.remove(key);
+            })
+        }
+
+        pub(super) fn set_batch(
+            &mut self,
+            entries: &[(String, Vec<u8>)],
+        ) -> Result<(), PortalisError> {
+            // Inserting into a map can't fail part-way, so this is already all-or-nothing.
+            self.with(|data| data.map.extend(entries.iter().cloned()))
+        }
+
+        pub(super) fn prefix_scan(
+            &mut self,
+            prefix: &str,
+        ) -> Result<Vec<(String, Vec<u8>)>, PortalisError> {
+            self.with(|data| {
+                data.map
+                    .range(prefix.to_string()..)
+                    .take_while(|(key, _)| key.starts_with(prefix))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect()
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // The store is a process-wide global, so tests touching it must not interleave.
+    pub(crate) static GLOBAL_STORE_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn set_get_delete_round_trip() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        kv_open(dir.path().to_string_lossy().into_owned()).unwrap();
+
+        assert_eq!(kv_get("theme".into()).unwrap(), None);
+        kv_set("theme".into(), b"dark".to_vec()).unwrap();
+        assert_eq!(kv_get("theme".into()).unwrap(), Some(b"dark".to_vec()));
+        kv_delete("theme".into()).unwrap();
+        assert_eq!(kv_get("theme".into()).unwrap(), None);
+    }
+
+    #[test]
+    fn values_survive_reopen() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().into_owned();
+
+        kv_open(path.clone()).unwrap();
+        kv_set("count".into(), vec![42]).unwrap();
+        kv_open(path).unwrap();
+        assert_eq!(kv_get("count".into()).unwrap(), Some(vec![42]));
+    }
+
+    fn numbered(prefix: &str, n: usize) -> Vec<(String, Vec<u8>)> {
+        (0..n)
+            .map(|i| (format!("{prefix}{i:05}"), i.to_le_bytes().to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn large_batch_commits_and_reads_back() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        kv_open(dir.path().to_string_lossy().into_owned()).unwrap();
+
+        let entries = numbered("item/", 10_000);
+        kv_set_batch(entries.clone()).unwrap();
+
+        let values = kv_get_batch(vec![
+            "item/00000".into(),
+            "nope".into(),
+            "item/09999".into(),
+        ])
+        .unwrap();
+        assert_eq!(
+            values,
+            [
+                Some(entries[0].1.clone()),
+                None,
+                Some(entries[9_999].1.clone())
+            ]
+        );
+        assert_eq!(kv_prefix_scan("item/".into()).unwrap(), entries);
+    }
+
+    #[test]
+    fn failed_batch_writes_nothing() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        kv_open(dir.path().to_string_lossy().into_owned()).unwrap();
+
+        let entries = numbered("doomed/", 10_000);
+        let err = with_store(|store, _| {
+            store.set_batch_checked(&entries, |i| match i {
+                5_000 => Err(PortalisError::Internal("simulated crash".into())),
+                _ => Ok(()),
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(err.code(), "internal");
+        assert!(kv_prefix_scan("doomed/".into()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn prefix_scan_only_returns_matching_keys() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        kv_open(dir.path().to_string_lossy().into_owned()).unwrap();
+
+        for key in ["user/b", "user/a", "users", "admin/a"] {
+            kv_set(key.into(), key.as_bytes().to_vec()).unwrap();
+        }
+        let keys: Vec<_> = kv_prefix_scan("user/".into())
+            .unwrap()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert_eq!(keys, ["user/a", "user/b"]);
+    }
+
+    /// Cheap enough that debug-build tests don't spend seconds in Argon2.
+    const TEST_KDF: KdfParams = KdfParams {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    };
+
+    #[test]
+    fn encrypted_values_round_trip_and_are_not_stored_in_plaintext() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().into_owned();
+
+        open_encrypted(&path, "hunter2", TEST_KDF).unwrap();
+        kv_set("token".into(), b"s3cret-value".to_vec()).unwrap();
+        kv_set_batch(vec![("user/a".into(), b"a".to_vec())]).unwrap();
+        let raw = with_store(|store, _| store.get("token")).unwrap().unwrap();
+        assert!(!raw.windows(6).any(|w| w == b"s3cret"));
+
+        open_encrypted(&path, "hunter2", TEST_KDF).unwrap();
+        assert_eq!(
+            kv_get("token".into()).unwrap(),
+            Some(b"s3cret-value".to_vec())
+        );
+        assert_eq!(
+            kv_prefix_scan("user/".into()).unwrap(),
+            [("user/a".to_string(), b"a".to_vec())]
+        );
+        // The plain opener must not hand out ciphertext as if it were the value.
+        assert_eq!(kv_open(path).unwrap_err().code(), "invalid_input");
+    }
+
+    #[test]
+    fn wrong_passphrase_is_rejected() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().into_owned();
+
+        open_encrypted(&path, "correct horse", TEST_KDF).unwrap();
+        kv_set("k".into(), b"v".to_vec()).unwrap();
+        // The costs saved in the header win over the ones passed in.
+        let err = open_encrypted(&path, "battery staple", DEFAULT_KDF).unwrap_err();
+
+        assert_eq!(err.code(), "permission_denied");
+        assert!(kv_get("k".into()).is_err(), "store must stay closed");
+    }
+
+    #[test]
+    fn tampered_or_swapped_values_are_corrupted() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        open_encrypted(&dir.path().to_string_lossy(), "pw", TEST_KDF).unwrap();
+        kv_set("a".into(), b"alpha".to_vec()).unwrap();
+        kv_set("b".into(), b"bravo".to_vec()).unwrap();
+
+        with_store(|store, _| {
+            let mut raw = store.get("a")?.unwrap();
+            *raw.last_mut().unwrap() ^= 1;
+            store.set("a", raw)?;
+            let b = store.get("b")?.unwrap();
+            store.set("c", b)
+        })
+        .unwrap();
+
+        assert_eq!(kv_get("a".into()).unwrap_err().code(), "corrupted");
+        assert_eq!(kv_get("c".into()).unwrap_err().code(), "corrupted");
+        assert_eq!(kv_get("b".into()).unwrap(), Some(b"bravo".to_vec()));
+    }
+
+    #[test]
+    fn header_with_out_of_range_costs_is_corrupted() {
+        let header = |kdf| {
+            Header {
+                kdf,
+                salt: vec![0; SALT_LEN],
+                check: vec![1; 8],
+            }
+            .encode()
+        };
+        assert!(Header::decode(&header(MAX_KDF)).is_ok());
+        for kdf in [
+            KdfParams {
+                m_cost: u32::MAX,
+                ..TEST_KDF
+            },
+            KdfParams {
+                t_cost: MAX_KDF.t_cost + 1,
+                ..TEST_KDF
+            },
+            KdfParams {
+                p_cost: 1000,
+                ..TEST_KDF
+            },
+            KdfParams {
+                t_cost: 0,
+                ..TEST_KDF
+            },
+        ] {
+            let err = Header::decode(&header(kdf)).err().unwrap();
+            assert_eq!(err.code(), "corrupted", "{kdf:?}");
+        }
+    }
+
+    #[test]
+    fn plaintext_store_with_values_is_not_encrypted_in_place() {
+        let _lock = GLOBAL_STORE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().into_owned();
+
+        kv_open(path.clone()).unwrap();
+        kv_set("old".into(), b"plain".to_vec()).unwrap();
+        let err = open_encrypted(&path, "pw", TEST_KDF).unwrap_err();
+
+        assert_eq!(err.code(), "invalid_input");
+    }
+}
@@ -1,5 +1,41 @@
 #![cfg_attr(not(frb_expand), allow(unexpected_cfgs))]
 mod api; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
+pub mod archive;
+pub mod cache;
+pub mod cancel;
+pub mod codec;
+pub mod compress;
+pub mod crypto;
+pub mod csv;
+pub mod db;
+pub mod diag;
+pub mod diff;
+pub mod encoding;
+pub mod env;
+pub mod error;
+pub mod events;
+pub mod format;
+pub mod fs;
+pub mod hash;
+pub mod ids;
+pub mod image;
+pub mod jobs;
+pub mod kv;
+pub mod lifecycle;
+pub mod logging;
+pub mod metrics;
+pub mod net;
+pub mod pipeline;
+pub mod platform;
+pub mod progress;
+pub mod queue;
+pub mod regex;
+pub mod resources;
+pub mod runtime;
+pub mod search;
+pub mod state;
+pub mod time;
+pub mod watch;
 use flutter_rust_bridge::frb;
 
 // Keep web simple by making this a synchronous, non-threaded function.
@@ -9,6 +45,28 @@ pub fn get_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// Build metadata for the "About" screen. Values are baked in by `build.rs`.
+pub struct BuildInfo {
+    pub version: String,
+    pub git_sha: String,
+    /// Cargo profile the backend was compiled with (`debug` or `release`).
+    pub profile: String,
+    pub rustc_version: String,
+    pub built_at_unix: u64,
+}
+
+// Sync for the same reason as `get_version`: everything is a compile-time constant.
+#[frb(sync)]
+pub fn get_build_info() -> BuildInfo {
+    BuildInfo {
+        version: get_version(),
+        git_sha: env!("PORTALIS_GIT_SHA").to_string(),
+        profile: env!("PORTALIS_PROFILE").to_string(),
+        rustc_version: env!("PORTALIS_RUSTC_VERSION").to_string(),
+        built_at_unix: env!("PORTALIS_BUILT_AT_UNIX").parse().unwrap_or_default(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,4 +75,14 @@ mod tests {
     fn get_version_matches_crate_metadata() {
         assert_eq!(get_version(), env!("CARGO_PKG_VERSION"));
     }
+
+    #[test]
+    fn get_build_info_is_populated() {
+        let info = get_build_info();
+        assert_eq!(info.version, get_version());
+        assert!(!info.git_sha.is_empty());
+        assert!(info.profile == "debug" || info.profile == "release");
+        assert!(info.rustc_version.starts_with("rustc"));
+        assert!(info.built_at_unix > 0);
+    }
 }
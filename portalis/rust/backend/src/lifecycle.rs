@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use crate::error::PortalisError;
+use crate::{db, kv, queue, runtime};
+
+/// How long `shutdown_all` lets running async calls finish before cancelling them.
+pub const SHUTDOWN_DRAIN_MS: u64 = 2_000;
+
+/// Call on app exit: lets in-flight async calls finish (up to `SHUTDOWN_DRAIN_MS`),
+/// then flushes and closes the kv store and the queue and checkpoints and closes the
+/// SQLite database.
+///
+/// Subsystems that were never opened are skipped, so this is safe to call at any
+/// time and more than once. Every subsystem is attempted even if an earlier one
+/// fails; the error then names each one that couldn't be flushed. Each can be
+/// reopened afterwards with its `_open` function.
+// Not `#[frb(sync)]`: draining and flushing block, so it runs on the FRB thread pool.
+pub fn shutdown_all() -> Result<(), PortalisError> {
+    shutdown(runtime::drain)
+}
+
+/// `shutdown_all` with the drain step passed in, so tests can stop a private runtime.
+fn shutdown(drain: impl FnOnce(Duration) -> usize) -> Result<(), PortalisError> {
+    let cancelled = drain(Duration::from_millis(SHUTDOWN_DRAIN_MS));
+    if cancelled > 0 {
+        log::warn!("shutdown cancelled {cancelled} async calls that were still running");
+    }
+    close_stores()
+}
+
+type Close = fn() -> Result<(), PortalisError>;
+
+/// The flushing half of `shutdown_all`, separate so tests can skip stopping the runtime.
+fn close_stores() -> Result<(), PortalisError> {
+    let steps: [(&str, Close); 3] = [
+        ("kv", kv::close),
+        ("queue", queue::close),
+        ("db", db::close),
+    ];
+    let failures: Vec<(&str, PortalisError)> = steps
+        .into_iter()
+        .filter_map(|(name, close)| close().err().map(|err| (name, err)))
+        .collect();
+    match failures.len() {
+        0 => Ok(()),
+        // Keep the variant, so e.g. a full disk still reads as `io`.
+        1 => {
+            let (name, err) = failures.into_iter().next().unwrap();
+            Err(err.context(format!("shutdown failed for {name}")))
+        }
+        _ => {
+            let details: Vec<String> = failures
+                .iter()
+                .map(|(name, err)| format!("{name}: {err}"))
+                .collect();
+            Err(PortalisError::Internal(format!(
+                "shutdown failed for {}",
+                details.join("; ")
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::SqlValue;
+    use crate::queue::QueuedOp;
+
+    #[test]
+    fn writes_made_right_before_shutdown_survive_reopen() {
+        let _kv = kv::tests::GLOBAL_STORE_LOCK.lock().unwrap();
+        let _queue = queue::tests::GLOBAL_QUEUE_LOCK.lock().unwrap();
+        let _db = db::tests::GLOBAL_DB_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| dir.path().join(name).to_string_lossy().into_owned();
+
+        kv::kv_open(path("kv")).unwrap();
+        queue::queue_open(path("queue")).unwrap();
+        db::db_open(path("app.db")).unwrap();
+        // WAL mode, so the test also shows the checkpoint folding the log back in.
+        db::db_query("PRAGMA journal_mode = WAL".into(), vec![]).unwrap();
+        db::db_execute("CREATE TABLE note (body TEXT)".into(), vec![]).unwrap();
+        kv::kv_set("last".into(), b"written at exit".to_vec()).unwrap();
+        let op = QueuedOp {
+            id: 0,
+            kind: "sync".into(),
+            payload: vec![1, 2, 3],
+        };
+        queue::queue_enqueue(op).unwrap();
+        db::db_execute(
+            "INSERT INTO note VALUES (?1)".into(),
+            vec![SqlValue::Text("bye".into())],
+        )
+        .unwrap();
+
+        close_stores().unwrap();
+        // Everything is closed now, and a second shutdown has nothing left to do.
+        assert_eq!(kv::kv_get("last".into()).unwrap_err().code(), "internal");
+        close_stores().unwrap();
+        assert!(!dir.path().join("app.db-wal").exists());
+
+        kv::kv_open(path("kv")).unwrap();
+        queue::queue_open(path("queue")).unwrap();
+        db::db_open(path("app.db")).unwrap();
+        assert_eq!(
+            kv::kv_get("last".into()).unwrap(),
+            Some(b"written at exit".to_vec())
+        );
+        assert_eq!(queue::queue_peek().unwrap().unwrap().payload, [1, 2, 3]);
+        assert_eq!(
+            db::db_query("SELECT body FROM note".into(), vec![]).unwrap(),
+            [[SqlValue::Text("bye".into())]]
+        );
+        close_stores().unwrap();
+    }
+
+    #[test]
+    fn async_calls_and_reopen_work_after_shutting_down_twice() {
+        let _kv = kv::tests::GLOBAL_STORE_LOCK.lock().unwrap();
+        let _queue = queue::tests::GLOBAL_QUEUE_LOCK.lock().unwrap();
+        let _db = db::tests::GLOBAL_DB_LOCK.lock().unwrap();
+        // A private runtime, so draining it doesn't cancel other tests' calls.
+        static SLOT: runtime::Slot = runtime::Slot::new();
+        let drain = |timeout| SLOT.drain(timeout);
+        let aux = tokio::runtime::Runtime::new().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kv").to_string_lossy().into_owned();
+
+        kv::kv_open(path.clone()).unwrap();
+        kv::kv_set("k".into(), b"v".to_vec()).unwrap();
+        aux.block_on(runtime::run_on(&SLOT, async { Ok(()) }))
+            .unwrap();
+
+        shutdown(drain).unwrap();
+        shutdown(drain).unwrap();
+
+        // The runtime restarts lazily, and sled's lock is released by the time it's needed.
+        let value = aux
+            .block_on(runtime::run_on(&SLOT, async move {
+                kv::kv_open(path)?;
+                kv::kv_get("k".into())
+            }))
+            .unwrap();
+        assert_eq!(value, Some(b"v".to_vec()));
+        close_stores().unwrap();
+    }
+}
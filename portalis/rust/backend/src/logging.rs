@@ -0,0 +1,197 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, Once};
+
+use flutter_rust_bridge::frb;
+use web_time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::StreamSink;
+
+#[derive(Clone, Copy, Debug)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+#[derive(Clone, Debug)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+    pub timestamp_ms: i64,
+}
+
+impl From<LogLevel> for log::LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+impl LogLevel {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+impl LogRecord {
+    fn from_record(record: &log::Record) -> Self {
+        LogRecord {
+            level: record.level().into(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            timestamp_ms: now_unix_ms(),
+        }
+    }
+}
+
+static INSTALL: Once = Once::new();
+static SINK: Mutex<Option<StreamSink<LogRecord>>> = Mutex::new(None);
+
+/// Records kept for `recent_logs`, so a diagnostics export has context even when no
+/// Dart stream was listening.
+const RECENT_CAPACITY: usize = 500;
+
+static RECENT: Mutex<VecDeque<LogRecord>> = Mutex::new(VecDeque::new());
+
+/// The last `limit` records the bridge logger saw (at most `RECENT_CAPACITY`), oldest first.
+pub(crate) fn recent_logs(limit: usize) -> Vec<LogRecord> {
+    let Ok(recent) = RECENT.lock() else {
+        return Vec::new();
+    };
+    let skip = recent.len().saturating_sub(limit);
+    recent.iter().skip(skip).cloned().collect()
+}
+
+fn remember(record: &LogRecord) {
+    let Ok(mut recent) = RECENT.lock() else {
+        return;
+    };
+    if recent.len() == RECENT_CAPACITY {
+        recent.pop_front();
+    }
+    recent.push_back(record.clone());
+}
+
+/// Forwards every `log::Record` to the Dart stream registered via `create_log_stream`,
+/// keeping the most recent ones in memory as well.
+struct BridgeLogger;
+
+impl log::Log for BridgeLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let record = LogRecord::from_record(record);
+        remember(&record);
+        let Ok(sink) = SINK.lock() else {
+            return;
+        };
+        if let Some(sink) = sink.as_ref() {
+            // Nothing sensible to do if Dart has gone away; never log from the logger.
+            let _ = sink.add(record);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the bridge logger on first call; later calls only adjust the level.
+// Sync so the web binding never needs a worker.
+#[frb(sync)]
+pub fn init_logging(level: LogLevel) {
+    INSTALL.call_once(|| {
+        // Another logger may already be set by the host process; keep it in that case.
+        let _ = log::set_boxed_logger(Box::new(BridgeLogger));
+    });
+    log::set_max_level(level.into());
+}
+
+/// Registers the Dart stream that receives log records, replacing any previous one.
+#[frb(sync)]
+pub fn create_log_stream(sink: StreamSink<LogRecord>) {
+    if let Ok(mut current) = SINK.lock() {
+        *current = Some(sink);
+    }
+}
+
+fn now_unix_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init_logging_is_idempotent() {
+        init_logging(LogLevel::Info);
+        init_logging(LogLevel::Debug);
+        assert_eq!(log::max_level(), log::LevelFilter::Debug);
+        log::debug!("logged without a Dart stream attached");
+    }
+
+    #[test]
+    fn record_carries_level_target_and_message() {
+        let record = LogRecord::from_record(
+            &log::Record::builder()
+                .level(log::Level::Warn)
+                .target("backend::tests")
+                .args(format_args!("disk {} full", "almost"))
+                .build(),
+        );
+        assert!(matches!(record.level, LogLevel::Warn));
+        assert_eq!(record.target, "backend::tests");
+        assert_eq!(record.message, "disk almost full");
+        assert!(record.timestamp_ms > 0);
+    }
+
+    #[test]
+    fn recent_logs_keep_the_newest_records() {
+        for i in 0..RECENT_CAPACITY + 10 {
+            remember(&LogRecord {
+                level: LogLevel::Info,
+                target: "backend::tests".into(),
+                message: format!("ring {i}"),
+                timestamp_ms: 0,
+            });
+        }
+        let all = recent_logs(usize::MAX);
+        assert_eq!(all.len(), RECENT_CAPACITY);
+        // Other tests may log concurrently, so don't assume which records are newest.
+        assert!(all.iter().all(|r| r.message != "ring 0"));
+        assert_eq!(recent_logs(3).len(), 3);
+    }
+}
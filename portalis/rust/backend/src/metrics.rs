@@ -0,0 +1,203 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+
+use flutter_rust_bridge::frb;
+
+/// Aggregates for one metric name, in milliseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricSummary {
+    pub name: String,
+    pub count: u64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// Taken over the most recent `MAX_SAMPLES` values, so it follows current behaviour.
+    pub p95: f64,
+}
+
+/// Samples kept per metric for the percentile; older ones still count towards the rest.
+const MAX_SAMPLES: usize = 1024;
+
+// One global registry, so timings recorded from any module or isolate land together.
+static METRICS: Mutex<BTreeMap<String, Histogram>> = Mutex::new(BTreeMap::new());
+
+fn with_metrics<T>(f: impl FnOnce(&mut BTreeMap<String, Histogram>) -> T) -> T {
+    // Losing a sample to a panic is harmless, so a poisoned lock is fine.
+    let mut guard = METRICS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut guard)
+}
+
+// Both sync: recording is a map update under a lock, cheaper than a worker hop.
+
+/// Adds one sample to metric `name`. Negative and non-finite values are ignored.
+#[frb(sync)]
+pub fn record_timing(name: String, millis: f64) {
+    if !millis.is_finite() || millis < 0.0 {
+        return;
+    }
+    with_metrics(|metrics| {
+        metrics
+            .entry(name)
+            .or_insert_with(Histogram::new)
+            .record(millis)
+    });
+}
+
+/// One summary per metric recorded so far, sorted by name.
+#[frb(sync)]
+pub fn export_metrics() -> Vec<MetricSummary> {
+    with_metrics(|metrics| {
+        metrics
+            .iter()
+            .map(|(name, histogram)| histogram.summary(name))
+            .collect()
+    })
+}
+
+/// Records how long it lives under `name`; see [`time_scope`].
+pub(crate) struct TimeScope {
+    name: &'static str,
+    start: web_time::Instant,
+}
+
+impl Drop for TimeScope {
+    fn drop(&mut self) {
+        let millis = self.start.elapsed().as_secs_f64() * 1000.0;
+        record_timing(self.name.to_string(), millis);
+    }
+}
+
+/// Times the rest of the enclosing block: `let _timing = metrics::time_scope("hash_file");`.
+///
+/// The sample is recorded when the guard drops, so early returns, errors and futures
+/// dropped on timeout are all counted.
+pub(crate) fn time_scope(name: &'static str) -> TimeScope {
+    TimeScope {
+        name,
+        start: web_time::Instant::now(),
+    }
+}
+
+struct Histogram {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+    recent: VecDeque<f64>,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Histogram {
+            count: 0,
+            sum: 0.0,
+            min: 0.0,
+            max: 0.0,
+            recent: VecDeque::new(),
+        }
+    }
+
+    fn record(&mut self, value: f64) {
+        if self.count == 0 {
+            (self.min, self.max) = (value, value);
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        self.sum += value;
+        if self.recent.len() == MAX_SAMPLES {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(value);
+    }
+
+    fn summary(&self, name: &str) -> MetricSummary {
+        MetricSummary {
+            name: name.to_string(),
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            mean: self.sum / self.count as f64,
+            p95: self.percentile(95.0),
+        }
+    }
+
+    /// Nearest-rank percentile: the smallest sample with at least `p`% of samples at or below it.
+    fn percentile(&self, p: f64) -> f64 {
+        let mut sorted: Vec<f64> = self.recent.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(name: &str) -> Option<MetricSummary> {
+        export_metrics().into_iter().find(|m| m.name == name)
+    }
+
+    #[test]
+    fn summary_reports_count_extremes_mean_and_p95() {
+        // Shuffled 1..=100, so the percentile can't rely on insertion order.
+        for i in 0..100u32 {
+            record_timing("test.summary".into(), f64::from((i * 37) % 100 + 1));
+        }
+        record_timing("test.summary".into(), f64::NAN);
+        record_timing("test.summary".into(), -1.0);
+
+        let m = summary("test.summary").unwrap();
+        assert_eq!(m.count, 100);
+        assert_eq!((m.min, m.max), (1.0, 100.0));
+        assert_eq!(m.mean, 50.5);
+        assert_eq!(m.p95, 95.0);
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let mut histogram = Histogram::new();
+        histogram.record(7.5);
+        assert_eq!(histogram.percentile(95.0), 7.5);
+
+        for value in [1.0, 2.0, 3.0] {
+            histogram.record(value);
+        }
+        // ceil(0.95 * 4) = 4th smallest of [1, 2, 3, 7.5].
+        assert_eq!(histogram.percentile(95.0), 7.5);
+        assert_eq!(histogram.percentile(50.0), 2.0);
+    }
+
+    #[test]
+    fn p95_follows_recent_samples_but_totals_cover_everything() {
+        let mut histogram = Histogram::new();
+        for _ in 0..MAX_SAMPLES {
+            histogram.record(1000.0);
+        }
+        for _ in 0..MAX_SAMPLES {
+            histogram.record(1.0);
+        }
+        let m = histogram.summary("x");
+        assert_eq!(m.count, 2 * MAX_SAMPLES as u64);
+        assert_eq!(m.max, 1000.0);
+        assert_eq!(m.p95, 1.0);
+    }
+
+    #[test]
+    fn time_scope_records_on_drop_from_many_threads() {
+        std::thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    let _timing = time_scope("test.scope");
+                });
+            }
+        });
+        let m = summary("test.scope").unwrap();
+        assert_eq!(m.count, 8);
+        assert!(m.min >= 0.0);
+    }
+}
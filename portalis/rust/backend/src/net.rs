@@ -0,0 +1,1081 @@
+use std::sync::{Arc, OnceLock};
+
+use crate::api::StreamSink;
+use crate::cancel::{self, CancelToken};
+use crate::error::PortalisError;
+use crate::events::{self, AppEvent};
+use crate::hash::HashAlgo;
+use crate::{metrics, runtime};
+
+/// Response of an HTTP call. Non-2xx statuses are returned here, not as errors.
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl From<reqwest::Error> for PortalisError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_builder() {
+            PortalisError::InvalidInput(err.to_string())
+        } else {
+            PortalisError::Network(err.to_string())
+        }
+    }
+}
+
+/// Shared client so every call reuses the same connection pool and TLS config.
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new)
+}
+
+/// Cancelling `token` drops the request and fails the call with `Cancelled`.
+pub async fn http_get(
+    url: String,
+    headers: Vec<(String, String)>,
+    timeout_ms: Option<u64>,
+    token: &CancelToken,
+) -> Result<HttpResponse, PortalisError> {
+    let _timing = metrics::time_scope("http_get");
+    let what = format!("GET {url}");
+    let request = send(client().get(url), headers);
+    runtime::with_timeout(timeout_ms, cancel::guarded(token.clone(), what, request)).await
+}
+
+/// Cancelling `token` drops the request and fails the call with `Cancelled`.
+pub async fn http_post(
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    timeout_ms: Option<u64>,
+    token: &CancelToken,
+) -> Result<HttpResponse, PortalisError> {
+    let _timing = metrics::time_scope("http_post");
+    let what = format!("POST {url}");
+    let request = send(client().post(url).body(body), headers);
+    runtime::with_timeout(timeout_ms, cancel::guarded(token.clone(), what, request)).await
+}
+
+async fn send(
+    request: reqwest::RequestBuilder,
+    headers: Vec<(String, String)>,
+) -> Result<HttpResponse, PortalisError> {
+    Ok(fetch(request, headers).await?)
+}
+
+/// Like `send`, but keeps the raw error so the retry loop can tell what failed.
+async fn fetch(
+    mut request: reqwest::RequestBuilder,
+    headers: Vec<(String, String)>,
+) -> Result<HttpResponse, reqwest::Error> {
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    let response = request.send().await?;
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            )
+        })
+        .collect();
+    let body = response.bytes().await?.to_vec();
+    Ok(HttpResponse {
+        status,
+        headers,
+        body,
+    })
+}
+
+/// Looks up the IP addresses of `host`, in the order the resolver returned them.
+///
+/// Not available on web, where the browser never exposes DNS.
+pub async fn resolve_host(
+    host: String,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<String>, PortalisError> {
+    if host.is_empty() {
+        return Err(PortalisError::InvalidInput("host must not be empty".into()));
+    }
+    runtime::with_timeout(timeout_ms, async move { sys::resolve_host(&host).await }).await
+}
+
+/// Returns `true` if a `HEAD` request to `url` gets any HTTP response within `timeout_ms`.
+///
+/// Error statuses still count: the server answered, so it is reachable.
+pub async fn check_reachable(url: String, timeout_ms: u32) -> bool {
+    let request = client()
+        .head(url)
+        .timeout(std::time::Duration::from_millis(timeout_ms.into()));
+    runtime::run(async move { Ok(request.send().await.is_ok()) })
+        .await
+        .unwrap_or(false)
+}
+
+/// How `http_get_retry` spaces out repeated attempts.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Total tries including the first; 0 is treated as 1.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each one after.
+    pub base_delay_ms: u64,
+    /// Randomise each delay between half and all of its value so clients don't retry in sync.
+    pub jitter: bool,
+}
+
+/// No single wait exceeds this, whether from backoff or a server's `Retry-After`.
+const MAX_RETRY_DELAY_MS: u64 = 30_000;
+
+impl RetryPolicy {
+    /// Delay after the `attempt`th (1-based) failure when the server gave no `Retry-After`.
+    fn backoff_ms(&self, attempt: u32) -> u64 {
+        let factor = 1u64 << (attempt - 1).min(32);
+        let delay = self
+            .base_delay_ms
+            .saturating_mul(factor)
+            .min(MAX_RETRY_DELAY_MS);
+        if self.jitter {
+            fastrand::u64(delay / 2..=delay)
+        } else {
+            delay
+        }
+    }
+}
+
+/// GETs `url`, retrying connection failures, timeouts, 429 and 5xx responses.
+///
+/// A `Retry-After` header (in seconds) overrides the backoff delay. Once attempts run
+/// out, the last failure is returned with the attempt count in its message: `Network`
+/// for a retryable status (`giving up after 3 attempts: HTTP 503`) or a connection
+/// failure, `Timeout` if the last request timed out. Cancelling `token` also stops a
+/// pending backoff wait.
+pub async fn http_get_retry(
+    url: String,
+    headers: Vec<(String, String)>,
+    policy: RetryPolicy,
+    timeout_ms: Option<u64>,
+    token: &CancelToken,
+) -> Result<HttpResponse, PortalisError> {
+    let what = format!("GET {url}");
+    let retries = async move {
+        let attempts = policy.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            let outcome = fetch(client().get(&url), headers.clone()).await;
+            let delay_ms = match &outcome {
+                Ok(response) if is_retryable_status(response.status) => {
+                    retry_after_ms(response).unwrap_or_else(|| policy.backoff_ms(attempt))
+                }
+                Err(err) if sys::is_transient(err) => policy.backoff_ms(attempt),
+                _ => return Ok(outcome?),
+            };
+            if attempt >= attempts {
+                let giving_up = format!("giving up after {attempt} attempts");
+                return Err(match outcome {
+                    Ok(response) => {
+                        PortalisError::Network(format!("{giving_up}: HTTP {}", response.status))
+                    }
+                    Err(err) if err.is_timeout() => {
+                        PortalisError::Timeout(format!("{giving_up}: {err}"))
+                    }
+                    Err(err) => PortalisError::Network(format!("{giving_up}: {err}")),
+                });
+            }
+            log::debug!("retrying GET {url} in {delay_ms}ms (attempt {attempt} of {attempts})");
+            sys::sleep_ms(delay_ms).await;
+            attempt += 1;
+        }
+    };
+    runtime::with_timeout(timeout_ms, cancel::guarded(token.clone(), what, retries)).await
+}
+
+fn is_retryable_status(status: u16) -> bool {
+    status == 429 || (500..=599).contains(&status)
+}
+
+fn retry_after_ms(response: &HttpResponse) -> Option<u64> {
+    let (_, value) = response
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))?;
+    let seconds: u64 = value.trim().parse().ok()?;
+    Some(seconds.saturating_mul(1000).min(MAX_RETRY_DELAY_MS))
+}
+
+pub enum DownloadEvent {
+    /// `total` is -1 when the server does not send `Content-Length`.
+    Progress {
+        done: i64,
+        total: i64,
+    },
+    /// The server couldn't continue a resumed download, so it started over from byte 0.
+    Restarted,
+    Done,
+    Error {
+        message: String,
+    },
+}
+
+/// Digest a finished download must have.
+pub struct ExpectedHash {
+    pub algo: HashAlgo,
+    /// Hex digest; case doesn't matter.
+    pub hex: String,
+}
+
+/// Downloads `url` into `dest`, streaming progress and ending with `Done` or `Error`.
+///
+/// With `resume`, an existing `dest` is treated as the start of the file: only the rest
+/// is requested (a `Range` request) and appended. If the server answers with anything
+/// but the matching partial content, `dest` is rewritten from scratch after a
+/// `Restarted` event. Without `resume` the partially written file is removed if the
+/// download fails or is cancelled; with it, the file is kept for the next attempt.
+///
+/// When given, `expected_size` and `expected_hash` are checked once the last byte is
+/// written; a mismatch is a `Corrupted` error and removes the file either way.
+///
+/// Cancelling `token` or running past `timeout_ms` ends the stream with an `Error`
+/// event and cleans up like any other failure.
+// Flat parameters read better as Dart named arguments than an options struct would.
+#[allow(clippy::too_many_arguments)]
+pub async fn download_file(
+    url: String,
+    dest: String,
+    resume: bool,
+    expected_size: Option<u64>,
+    expected_hash: Option<ExpectedHash>,
+    timeout_ms: Option<u64>,
+    token: &CancelToken,
+    sink: StreamSink<DownloadEvent>,
+) {
+    let _timing = metrics::time_scope("download_file");
+    let token = token.clone();
+    // `StreamSink` is only `Clone` for `Clone` payloads, so share it instead.
+    let sink = Arc::new(sink);
+    let progress = sink.clone();
+    let (source, target) = (url.clone(), dest.clone());
+    let result = runtime::with_timeout(timeout_ms, async move {
+        let mut emit = |event| {
+            let _ = progress.add(event);
+        };
+        sys::download(
+            &source,
+            &target,
+            resume,
+            expected_size,
+            expected_hash.as_ref(),
+            token,
+            &mut emit,
+        )
+        .await
+    })
+    .await;
+    events::publish(AppEvent::DownloadFinished {
+        url,
+        dest,
+        succeeded: result.is_ok(),
+    });
+    let _ = sink.add(match result {
+        Ok(()) => DownloadEvent::Done,
+        Err(err) => DownloadEvent::Error {
+            message: err.to_string(),
+        },
+    });
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use reqwest::header::{CONTENT_RANGE, RANGE};
+    use reqwest::StatusCode;
+    use tokio::io::AsyncWriteExt;
+
+    use super::{client, DownloadEvent, ExpectedHash};
+    use crate::cancel::{self, CancelToken};
+    use crate::error::PortalisError;
+    use crate::hash;
+
+    pub(super) async fn sleep_ms(ms: u64) {
+        tokio::time::sleep(std::time::Duration::from_millis(ms)).await;
+    }
+
+    pub(super) fn is_transient(err: &reqwest::Error) -> bool {
+        err.is_connect() || err.is_timeout()
+    }
+
+    pub(super) async fn resolve_host(host: &str) -> Result<Vec<String>, PortalisError> {
+        let addrs = tokio::net::lookup_host((host, 0))
+            .await
+            .map_err(|err| PortalisError::Network(format!("could not resolve {host}: {err}")))?;
+        let mut ips: Vec<String> = Vec::new();
+        for ip in addrs.map(|addr| addr.ip().to_string()) {
+            // The resolver repeats an address once per socket type.
+            if !ips.contains(&ip) {
+                ips.push(ip);
+            }
+        }
+        Ok(ips)
+    }
+
+    pub(super) async fn download(
+        url: &str,
+        dest: &str,
+        resume: bool,
+        expected_size: Option<u64>,
+        expected_hash: Option<&ExpectedHash>,
+        token: CancelToken,
+        emit: &mut impl FnMut(DownloadEvent),
+    ) -> Result<(), PortalisError> {
+        // A timeout drops this future at an await, skipping the clean-up below.
+        let mut partial = RemoveOnDrop((!resume).then_some(dest));
+        // Guard only the transfer, so a cancelled download still gets cleaned up below.
+        let written = cancel::guarded(
+            token,
+            format!("download of {url}"),
+            write_body(url, dest, resume, emit),
+        );
+        let result = match written.await {
+            Ok(()) => verify(dest, expected_size, expected_hash).await,
+            // Keep what was written so the next resumed call can continue from it.
+            Err(err) if resume => return Err(err),
+            Err(err) => Err(err),
+        };
+        partial.0 = None;
+        if result.is_err() {
+            let _ = tokio::fs::remove_file(dest).await;
+        }
+        result
+    }
+
+    /// Removes a download's file if it is dropped while still armed with its path.
+    struct RemoveOnDrop<'a>(Option<&'a str>);
+
+    impl Drop for RemoveOnDrop<'_> {
+        fn drop(&mut self) {
+            if let Some(path) = self.0 {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    async fn write_body(
+        url: &str,
+        dest: &str,
+        resume: bool,
+        emit: &mut impl FnMut(DownloadEvent),
+    ) -> Result<(), PortalisError> {
+        let mut offset = match tokio::fs::metadata(dest).await {
+            Ok(meta) if resume => meta.len(),
+            _ => 0,
+        };
+        let mut response = request(url, offset).await?;
+        if offset > 0 && !resumes_at(&response, offset) {
+            emit(DownloadEvent::Restarted);
+            offset = 0;
+            // A plain 200 already carries the whole body; anything else needs a fresh request.
+            if response.status() != StatusCode::OK {
+                response = request(url, 0).await?;
+            }
+        }
+        let mut response = response.error_for_status()?;
+        let total = response
+            .content_length()
+            .map_or(-1, |len| (offset + len) as i64);
+        let mut file = if offset > 0 {
+            tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(dest)
+                .await?
+        } else {
+            tokio::fs::File::create(dest).await?
+        };
+        let mut done = offset as i64;
+        emit(DownloadEvent::Progress { done, total });
+        let streamed = async {
+            loop {
+                let Some(chunk) = response.chunk().await? else {
+                    return Ok(());
+                };
+                file.write_all(&chunk).await?;
+                done += chunk.len() as i64;
+                emit(DownloadEvent::Progress { done, total });
+            }
+        }
+        .await;
+        // Flush even on failure: a resumed download continues from what reached the disk.
+        file.flush().await?;
+        streamed
+    }
+
+    async fn request(url: &str, offset: u64) -> Result<reqwest::Response, PortalisError> {
+        let mut request = client().get(url);
+        if offset > 0 {
+            request = request.header(RANGE, format!("bytes={offset}-"));
+        }
+        Ok(request.send().await?)
+    }
+
+    /// Whether `response` is the rest of the file from `offset`, not the whole file or a 416.
+    fn resumes_at(response: &reqwest::Response, offset: u64) -> bool {
+        let range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok());
+        response.status() == StatusCode::PARTIAL_CONTENT
+            && range.is_some_and(|range| range.starts_with(&format!("bytes {offset}-")))
+    }
+
+    async fn verify(
+        dest: &str,
+        expected_size: Option<u64>,
+        expected_hash: Option<&ExpectedHash>,
+    ) -> Result<(), PortalisError> {
+        if let Some(expected) = expected_size {
+            let size = tokio::fs::metadata(dest).await?.len();
+            if size != expected {
+                return Err(PortalisError::Corrupted(format!(
+                    "downloaded {size} bytes, expected {expected}"
+                )));
+            }
+        }
+        if let Some(expected) = expected_hash {
+            let digest = hash::hash_file(dest.to_string(), expected.algo, None).await?;
+            if !digest.eq_ignore_ascii_case(expected.hex.trim()) {
+                return Err(PortalisError::Corrupted(format!(
+                    "downloaded file hashes to {digest}, expected {}",
+                    expected.hex
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::{DownloadEvent, ExpectedHash};
+    use crate::cancel::CancelToken;
+    use crate::error::PortalisError;
+
+    pub(super) async fn sleep_ms(ms: u64) {
+        gloo_timers::future::sleep(std::time::Duration::from_millis(ms)).await;
+    }
+
+    /// The browser reports every network failure as a generic failed request.
+    pub(super) fn is_transient(err: &reqwest::Error) -> bool {
+        err.is_request() || err.is_timeout()
+    }
+
+    pub(super) async fn resolve_host(_host: &str) -> Result<Vec<String>, PortalisError> {
+        Err(PortalisError::unsupported_on_web("DNS resolution"))
+    }
+
+    pub(super) async fn download(
+        _url: &str,
+        _dest: &str,
+        _resume: bool,
+        _expected_size: Option<u64>,
+        _expected_hash: Option<&ExpectedHash>,
+        _token: CancelToken,
+        _emit: &mut impl FnMut(DownloadEvent),
+    ) -> Result<(), PortalisError> {
+        Err(PortalisError::unsupported_on_web("downloading to a file"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use wiremock::matchers::{body_bytes, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get_sends_headers_and_returns_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/me"))
+            .and(header("authorization", "Bearer t0ken"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-request-id", "abc")
+                    .set_body_string("hello"),
+            )
+            .mount(&server)
+            .await;
+
+        let response = http_get(
+            format!("{}/me", server.uri()),
+            vec![("Authorization".into(), "Bearer t0ken".into())],
+            None,
+            &CancelToken::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"hello");
+        assert!(response
+            .headers
+            .contains(&("x-request-id".into(), "abc".into())));
+    }
+
+    #[tokio::test]
+    async fn post_sends_body_and_keeps_error_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/items"))
+            .and(body_bytes(b"payload".to_vec()))
+            .respond_with(ResponseTemplate::new(422).set_body_string("bad item"))
+            .mount(&server)
+            .await;
+
+        let response = http_post(
+            format!("{}/items", server.uri()),
+            vec![],
+            b"payload".to_vec(),
+            None,
+            &CancelToken::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status, 422);
+        assert_eq!(response.body, b"bad item");
+    }
+
+    #[tokio::test]
+    async fn invalid_url_is_invalid_input() {
+        let err = http_get("not a url".into(), vec![], None, &CancelToken::new())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+
+    #[tokio::test]
+    async fn slow_response_times_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(5)))
+            .mount(&server)
+            .await;
+
+        let err = http_get(server.uri(), vec![], Some(100), &CancelToken::new())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "timeout");
+    }
+
+    #[tokio::test]
+    async fn cancelling_the_token_aborts_a_slow_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(30)))
+            .mount(&server)
+            .await;
+        let token = CancelToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            canceller.cancel();
+        });
+
+        let err = http_get(server.uri(), vec![], Some(5_000), &token)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "cancelled");
+    }
+
+    fn quick_retries(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay_ms: 1,
+            jitter: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_recovers_after_two_server_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("finally"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = http_get_retry(
+            server.uri(),
+            vec![],
+            quick_retries(5),
+            None,
+            &CancelToken::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"finally");
+    }
+
+    #[tokio::test]
+    async fn retry_gives_up_on_a_status_that_never_recovers() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let err = http_get_retry(
+            server.uri(),
+            vec![],
+            quick_retries(3),
+            None,
+            &CancelToken::new(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code(), "network");
+        assert!(
+            err.to_string()
+                .ends_with("giving up after 3 attempts: HTTP 503"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_honours_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let started = std::time::Instant::now();
+        let response = http_get_retry(
+            server.uri(),
+            vec![],
+            quick_retries(2),
+            None,
+            &CancelToken::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = http_get_retry(
+            server.uri(),
+            vec![],
+            quick_retries(5),
+            None,
+            &CancelToken::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status, 404);
+    }
+
+    #[tokio::test]
+    async fn connection_failures_report_attempt_count() {
+        // Bind then drop a listener so the port is (almost certainly) closed.
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        let err = http_get_retry(
+            format!("http://{addr}/"),
+            vec![],
+            quick_retries(3),
+            None,
+            &CancelToken::new(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code(), "network");
+        assert!(err.to_string().contains("after 3 attempts"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn localhost_resolves_to_loopback() {
+        let ips = resolve_host("localhost".into(), None).await.unwrap();
+        assert!(
+            ips.iter().any(|ip| ip == "127.0.0.1" || ip == "::1"),
+            "{ips:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_host_does_not_resolve() {
+        // `.invalid` is reserved and guaranteed never to resolve.
+        let err = resolve_host("no-such-host.invalid".into(), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), "network");
+        assert!(err.to_string().contains("no-such-host.invalid"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn reachable_when_server_answers_any_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert!(check_reachable(server.uri(), 2_000).await);
+    }
+
+    #[tokio::test]
+    async fn unreachable_hosts_and_slow_servers_are_false() {
+        assert!(!check_reachable("http://no-such-host.invalid/".into(), 2_000).await);
+
+        let server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(5)))
+            .mount(&server)
+            .await;
+        assert!(!check_reachable(server.uri(), 100).await);
+    }
+
+    #[test]
+    fn backoff_doubles_and_is_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay_ms: 100,
+            jitter: false,
+        };
+        assert_eq!(policy.backoff_ms(1), 100);
+        assert_eq!(policy.backoff_ms(3), 400);
+        assert_eq!(policy.backoff_ms(40), MAX_RETRY_DELAY_MS);
+    }
+
+    fn progress(events: &[DownloadEvent]) -> Vec<(i64, i64)> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                DownloadEvent::Progress { done, total } => Some((*done, *total)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn download_writes_file_and_reports_progress() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/blob"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![7u8; 1000]))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("blob.bin");
+
+        let mut events = Vec::new();
+        sys::download(
+            &format!("{}/blob", server.uri()),
+            dest.to_str().unwrap(),
+            false,
+            None,
+            None,
+            CancelToken::new(),
+            &mut |event| events.push(event),
+        )
+        .await
+        .unwrap();
+
+        let progress = progress(&events);
+        assert_eq!(progress.first(), Some(&(0, 1000)));
+        assert_eq!(progress.last(), Some(&(1000, 1000)));
+        assert_eq!(std::fs::read(&dest).unwrap(), vec![7u8; 1000]);
+    }
+
+    #[tokio::test]
+    async fn download_without_content_length_reports_unknown_total() {
+        // wiremock always sets Content-Length, so serve a close-delimited body by hand.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\nstreamed")
+                .unwrap();
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("streamed.txt");
+
+        let mut events = Vec::new();
+        sys::download(
+            &format!("http://{addr}/"),
+            dest.to_str().unwrap(),
+            false,
+            None,
+            None,
+            CancelToken::new(),
+            &mut |event| events.push(event),
+        )
+        .await
+        .unwrap();
+
+        assert!(progress(&events).iter().all(|(_, total)| *total == -1));
+        assert_eq!(std::fs::read(&dest).unwrap(), b"streamed");
+    }
+
+    #[tokio::test]
+    async fn cancelled_download_removes_partial_file() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![1u8; 10]))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("partial.bin");
+        let token = CancelToken::new();
+        token.cancel();
+
+        let err = sys::download(
+            &server.uri(),
+            dest.to_str().unwrap(),
+            false,
+            None,
+            None,
+            token,
+            &mut |_| {},
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code(), "cancelled");
+        assert!(!dest.exists());
+    }
+
+    #[tokio::test]
+    async fn failed_status_is_an_error_and_leaves_no_file() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("missing.bin");
+
+        let result = sys::download(
+            &server.uri(),
+            dest.to_str().unwrap(),
+            false,
+            None,
+            None,
+            CancelToken::new(),
+            &mut |_| {},
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(!dest.exists());
+    }
+
+    /// Serves one response advertising `advertised` bytes but closing after `body`.
+    fn truncating_server(advertised: usize, body: Vec<u8>) -> std::net::SocketAddr {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {advertised}\r\n\r\n");
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(&body).unwrap();
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn timed_out_download_removes_partial_file() {
+        // Sends the first bytes of the body, then stalls with the connection open.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 1000\r\n\r\nfirst bytes");
+            std::thread::sleep(std::time::Duration::from_secs(5));
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("stalled.bin");
+        let target = dest.to_string_lossy().into_owned();
+
+        let err = runtime::with_timeout(Some(300), async move {
+            sys::download(
+                &format!("http://{addr}/"),
+                &target,
+                false,
+                None,
+                None,
+                CancelToken::new(),
+                &mut |_| {},
+            )
+            .await
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code(), "timeout");
+        assert!(!dest.exists());
+    }
+
+    #[tokio::test]
+    async fn interrupted_download_resumes_with_range_request() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("big.bin");
+        let dest_str = dest.to_str().unwrap();
+
+        let addr = truncating_server(data.len(), data[..400].to_vec());
+        let interrupted = sys::download(
+            &format!("http://{addr}/"),
+            dest_str,
+            true,
+            None,
+            None,
+            CancelToken::new(),
+            &mut |_| {},
+        )
+        .await;
+        assert_eq!(interrupted.unwrap_err().code(), "network");
+        assert_eq!(std::fs::read(&dest).unwrap(), &data[..400]);
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("range", "bytes=400-"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("content-range", "bytes 400-999/1000")
+                    .set_body_bytes(data[400..].to_vec()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let expected_hash = ExpectedHash {
+            algo: HashAlgo::Sha256,
+            hex: crate::hash::hash_slice(HashAlgo::Sha256, &data).to_uppercase(),
+        };
+        let mut events = Vec::new();
+        sys::download(
+            &server.uri(),
+            dest_str,
+            true,
+            Some(1000),
+            Some(&expected_hash),
+            CancelToken::new(),
+            &mut |event| events.push(event),
+        )
+        .await
+        .unwrap();
+
+        let progress = progress(&events);
+        assert_eq!(progress.first(), Some(&(400, 1000)));
+        assert_eq!(progress.last(), Some(&(1000, 1000)));
+        assert_eq!(std::fs::read(&dest).unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn server_without_range_support_restarts_from_scratch() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"fresh body".to_vec()))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("file.txt");
+        std::fs::write(&dest, "stale").unwrap();
+
+        let mut events = Vec::new();
+        sys::download(
+            &server.uri(),
+            dest.to_str().unwrap(),
+            true,
+            None,
+            None,
+            CancelToken::new(),
+            &mut |event| events.push(event),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(events[0], DownloadEvent::Restarted));
+        assert_eq!(progress(&events).first(), Some(&(0, 10)));
+        assert_eq!(std::fs::read(&dest).unwrap(), b"fresh body");
+    }
+
+    #[tokio::test]
+    async fn size_or_hash_mismatch_is_corrupted_and_removes_file() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"payload".to_vec()))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("checked.bin");
+        let wrong_hash = ExpectedHash {
+            algo: HashAlgo::Blake3,
+            hex: "00".repeat(32),
+        };
+
+        for (size, hash) in [(Some(3), None), (None, Some(&wrong_hash))] {
+            let err = sys::download(
+                &server.uri(),
+                dest.to_str().unwrap(),
+                true,
+                size,
+                hash,
+                CancelToken::new(),
+                &mut |_| {},
+            )
+            .await
+            .unwrap_err();
+            assert_eq!(err.code(), "corrupted");
+            assert!(!dest.exists());
+        }
+    }
+}
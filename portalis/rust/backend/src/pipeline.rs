@@ -0,0 +1,497 @@
+use std::sync::Mutex;
+
+use flutter_rust_bridge::frb;
+
+use crate::api::StreamSink;
+use crate::compress::{self, CompressAlgo};
+use crate::error::PortalisError;
+
+/// What a [`TransformHandle`] does to the bytes fed into it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransformOp {
+    /// Native only, like `CompressAlgo::Zstd`.
+    ZstdCompress {
+        level: i32,
+    },
+    ZstdDecompress,
+}
+
+/// Output a [`TransformHandle`] holds while no `output` stream is registered yet.
+pub const MAX_PENDING_OUTPUT: usize = 8 * 1024 * 1024;
+
+/// A running transform: Dart pushes chunks in with `feed` and reads results from `output`.
+///
+/// Each `feed` transforms its chunk before returning, so awaiting it is the
+/// backpressure: nothing queues up inside Rust beyond the codec's own window.
+/// Output produced before `output` is called is held and sent once it is, but only
+/// up to `MAX_PENDING_OUTPUT`: past that, `feed` and `finish` fail with `InvalidInput`
+/// until `output` is called. Decompression emits at most 1 MiB at a time and stops
+/// decoding at that limit too, keeping the rest of the input for the next call, so a
+/// small chunk that inflates hugely can't fill memory.
+#[frb(opaque)]
+pub struct TransformHandle {
+    state: Mutex<State>,
+}
+
+/// Where transformed chunks go; a closure so tests can collect them without Dart.
+type Output = Box<dyn FnMut(Vec<u8>) + Send>;
+
+struct State {
+    /// `None` once `finish` has run.
+    transform: Option<sys::Transform>,
+    output: Option<Output>,
+    pending: Vec<u8>,
+}
+
+impl State {
+    fn emit(&mut self, bytes: Vec<u8>) {
+        if bytes.is_empty() {
+            return;
+        }
+        match &mut self.output {
+            Some(output) => output(bytes),
+            None => self.pending.extend_from_slice(&bytes),
+        }
+    }
+
+    /// Whether more output may be produced now without growing `pending` past its cap.
+    fn has_room(&self) -> bool {
+        self.output.is_some() || self.pending.len() < MAX_PENDING_OUTPUT
+    }
+
+    /// Runs `step` on the transform, emitting its output for as long as there is room.
+    fn transform<T>(
+        &mut self,
+        step: impl FnOnce(&mut sys::Transform, &mut dyn FnMut(Vec<u8>) -> bool) -> T,
+    ) -> Result<T, PortalisError> {
+        let mut transform = self.transform.take().ok_or_else(finished)?;
+        let result = step(&mut transform, &mut |bytes| {
+            self.emit(bytes);
+            self.has_room()
+        });
+        self.transform = Some(transform);
+        Ok(result)
+    }
+}
+
+#[frb(sync)]
+pub fn open_transform(op: TransformOp) -> Result<TransformHandle, PortalisError> {
+    if let TransformOp::ZstdCompress { level } = op {
+        compress::check_level(CompressAlgo::Zstd, level)?;
+    }
+    Ok(TransformHandle {
+        state: Mutex::new(State {
+            transform: Some(sys::Transform::new(op)?),
+            output: None,
+            pending: Vec::new(),
+        }),
+    })
+}
+
+impl TransformHandle {
+    /// Registers the stream receiving transformed chunks; it closes after `finish`.
+    #[frb(sync)]
+    pub fn output(&self, sink: StreamSink<Vec<u8>>) -> Result<(), PortalisError> {
+        self.attach(Box::new(move |bytes| {
+            let _ = sink.add(bytes);
+        }))
+    }
+
+    fn attach(&self, output: Output) -> Result<(), PortalisError> {
+        let mut state = self.lock()?;
+        let pending = std::mem::take(&mut state.pending);
+        state.output = Some(output);
+        state.emit(pending);
+        Ok(())
+    }
+
+    // Not `#[frb(sync)]`: (de)compressing a chunk is CPU work for the FRB thread pool.
+
+    pub fn feed(&self, chunk: Vec<u8>) -> Result<(), PortalisError> {
+        let mut state = self.lock()?;
+        if state.transform.is_none() {
+            return Err(finished());
+        }
+        // Checked before transforming, so a rejected chunk can simply be fed again.
+        if !state.has_room() {
+            return Err(output_full());
+        }
+        state.transform(|transform, emit| transform.write(&chunk, emit))?
+    }
+
+    /// Flushes the remaining output and closes the `output` stream.
+    ///
+    /// Fails with `InvalidInput` if decompression input ends in the middle of a frame.
+    pub fn finish(&self) -> Result<(), PortalisError> {
+        let mut state = self.lock()?;
+        if !state.transform(|transform, emit| transform.drain(emit))?? {
+            return Err(output_full());
+        }
+        let transform = state.transform.take().ok_or_else(finished)?;
+        let out = transform.finish()?;
+        state.emit(out);
+        // Dropping the sink is what ends the stream on the Dart side.
+        state.output = None;
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, State>, PortalisError> {
+        self.state
+            .lock()
+            .map_err(|_| PortalisError::Internal("transform lock poisoned".into()))
+    }
+}
+
+fn finished() -> PortalisError {
+    PortalisError::InvalidInput("transform already finished".into())
+}
+
+fn output_full() -> PortalisError {
+    PortalisError::InvalidInput(format!(
+        "{MAX_PENDING_OUTPUT} bytes of output are waiting; call output before feeding more"
+    ))
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::io::Write;
+
+    use zstd::stream::raw::{InBuffer, Operation, OutBuffer};
+
+    use super::TransformOp;
+    use crate::compress::corrupted;
+    use crate::error::PortalisError;
+
+    /// Largest chunk decompression emits at once; a bigger result is split.
+    pub(super) const OUTPUT_CHUNK: usize = 1024 * 1024;
+
+    /// Streaming codec; `emit` gets its output and says whether more is welcome.
+    pub(super) enum Transform {
+        /// Output is drained after every call, so it stays about the size of the input.
+        Compress(zstd::stream::write::Encoder<'static, Vec<u8>>),
+        Decompress(Decompressor),
+    }
+
+    pub(super) struct Decompressor {
+        decoder: zstd::stream::raw::Decoder<'static>,
+        /// Input not decoded yet, because `emit` asked to stop.
+        backlog: Vec<u8>,
+        /// Whether the last frame decoded so far is still missing its end.
+        in_frame: bool,
+    }
+
+    impl Transform {
+        pub(super) fn new(op: TransformOp) -> Result<Self, PortalisError> {
+            Ok(match op {
+                TransformOp::ZstdCompress { level } => {
+                    Transform::Compress(zstd::stream::write::Encoder::new(Vec::new(), level)?)
+                }
+                TransformOp::ZstdDecompress => Transform::Decompress(Decompressor {
+                    decoder: zstd::stream::raw::Decoder::new()?,
+                    backlog: Vec::new(),
+                    in_frame: false,
+                }),
+            })
+        }
+
+        /// Runs `chunk` through the codec, handing `emit` whatever output it produced.
+        pub(super) fn write(
+            &mut self,
+            chunk: &[u8],
+            emit: &mut dyn FnMut(Vec<u8>) -> bool,
+        ) -> Result<(), PortalisError> {
+            match self {
+                Transform::Compress(encoder) => {
+                    encoder.write_all(chunk)?;
+                    emit(std::mem::take(encoder.get_mut()));
+                }
+                Transform::Decompress(decompressor) => {
+                    decompressor.backlog.extend_from_slice(chunk);
+                    decompressor.decode(emit)?;
+                }
+            }
+            Ok(())
+        }
+
+        /// Decodes input held back by an earlier `emit` refusal; `false` if some is left.
+        pub(super) fn drain(
+            &mut self,
+            emit: &mut dyn FnMut(Vec<u8>) -> bool,
+        ) -> Result<bool, PortalisError> {
+            match self {
+                Transform::Compress(_) => Ok(true),
+                Transform::Decompress(decompressor) => decompressor.decode(emit),
+            }
+        }
+
+        /// Call after `drain` has returned `true`.
+        pub(super) fn finish(self) -> Result<Vec<u8>, PortalisError> {
+            match self {
+                Transform::Compress(encoder) => Ok(encoder.finish()?),
+                Transform::Decompress(decompressor) if decompressor.in_frame => {
+                    Err(PortalisError::InvalidInput(
+                        "corrupted compressed data: input ended in the middle of a frame".into(),
+                    ))
+                }
+                Transform::Decompress(_) => Ok(Vec::new()),
+            }
+        }
+    }
+
+    impl Decompressor {
+        /// Decodes the backlog in `OUTPUT_CHUNK` pieces until it is used up or `emit`
+        /// returns `false`; returns whether it was used up.
+        fn decode(&mut self, emit: &mut dyn FnMut(Vec<u8>) -> bool) -> Result<bool, PortalisError> {
+            let mut input = InBuffer::around(&self.backlog);
+            let mut more = true;
+            // A full buffer may mean the decoder still holds output, even with no input left.
+            let mut full = false;
+            while more && (full || input.pos() < self.backlog.len()) {
+                let mut out = Vec::with_capacity(OUTPUT_CHUNK);
+                let before = input.pos();
+                let hint = self
+                    .decoder
+                    .run(&mut input, &mut OutBuffer::around(&mut out))
+                    .map_err(corrupted)?;
+                // 0 means a frame just ended and everything in it was written out. A call
+                // that did nothing says nothing about the frame (it hints the next header).
+                if input.pos() > before || !out.is_empty() {
+                    self.in_frame = hint != 0;
+                }
+                full = out.len() == OUTPUT_CHUNK;
+                if !out.is_empty() {
+                    more = emit(out);
+                }
+            }
+            let used = input.pos();
+            let done = used == self.backlog.len() && !full;
+            self.backlog.drain(..used);
+            Ok(done)
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::TransformOp;
+    use crate::error::PortalisError;
+
+    /// Never constructed: every op is zstd, which needs the native C library.
+    pub(super) enum Transform {}
+
+    impl Transform {
+        pub(super) fn new(_op: TransformOp) -> Result<Self, PortalisError> {
+            Err(PortalisError::unsupported_on_web("zstd"))
+        }
+
+        pub(super) fn write(
+            &mut self,
+            _chunk: &[u8],
+            _emit: &mut dyn FnMut(Vec<u8>) -> bool,
+        ) -> Result<(), PortalisError> {
+            match *self {}
+        }
+
+        pub(super) fn drain(
+            &mut self,
+            _emit: &mut dyn FnMut(Vec<u8>) -> bool,
+        ) -> Result<bool, PortalisError> {
+            match *self {}
+        }
+
+        pub(super) fn finish(self) -> Result<Vec<u8>, PortalisError> {
+            match self {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pushes `chunks` through a fresh transform and concatenates everything it emits.
+    fn run(op: TransformOp, chunks: &[Vec<u8>]) -> Result<Vec<u8>, PortalisError> {
+        let mut transform = sys::Transform::new(op)?;
+        let mut out = Vec::new();
+        let mut emit = |bytes: Vec<u8>| {
+            out.extend(bytes);
+            true
+        };
+        for chunk in chunks {
+            transform.write(chunk, &mut emit)?;
+        }
+        transform.drain(&mut emit)?;
+        out.extend(transform.finish()?);
+        Ok(out)
+    }
+
+    /// Splits `data` into randomly sized chunks, including empty ones.
+    fn random_chunks(data: &[u8], rng: &mut fastrand::Rng) -> Vec<Vec<u8>> {
+        let mut chunks = Vec::new();
+        let mut rest = data;
+        while !rest.is_empty() {
+            let len = rng.usize(0..=rest.len().min(20_000));
+            let (chunk, tail) = rest.split_at(len);
+            chunks.push(chunk.to_vec());
+            rest = tail;
+        }
+        chunks
+    }
+
+    #[test]
+    fn decompress_of_compress_round_trips_random_chunks() {
+        let mut rng = fastrand::Rng::with_seed(42);
+        for len in [0, 1, 4096, 300_000] {
+            let data: Vec<u8> = (0..len)
+                .map(|i| if i % 3 == 0 { b'a' } else { rng.u8(..) })
+                .collect();
+
+            let packed = run(
+                TransformOp::ZstdCompress { level: 3 },
+                &random_chunks(&data, &mut rng),
+            )
+            .unwrap();
+            let unpacked = run(
+                TransformOp::ZstdDecompress,
+                &random_chunks(&packed, &mut rng),
+            )
+            .unwrap();
+
+            assert_eq!(unpacked, data, "len={len}");
+            // The one-shot API must be able to read the streamed frame too.
+            assert_eq!(
+                compress::decompress(packed, CompressAlgo::Zstd).unwrap(),
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn garbage_input_fails_to_decompress() {
+        let err = run(TransformOp::ZstdDecompress, &[vec![0xff; 64]]).unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+
+    #[test]
+    fn truncated_frame_fails_at_finish() {
+        let packed = compress::compress(vec![7; 100_000], CompressAlgo::Zstd, 3).unwrap();
+        let half = packed[..packed.len() / 2].to_vec();
+
+        let err = run(TransformOp::ZstdDecompress, &[half]).unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+        assert!(err.to_string().contains("middle of a frame"), "{err}");
+    }
+
+    #[test]
+    fn inflating_chunk_is_emitted_in_bounded_pieces() {
+        let data = vec![0u8; 4 * MAX_PENDING_OUTPUT];
+        let packed = compress::compress(data.clone(), CompressAlgo::Zstd, 3).unwrap();
+        assert!(packed.len() < 64 * 1024);
+
+        // Without `output`, decoding stops near the cap instead of inflating it all.
+        let handle = open_transform(TransformOp::ZstdDecompress).unwrap();
+        handle.feed(packed.clone()).unwrap();
+        let pending = handle.lock().unwrap().pending.len();
+        assert!((MAX_PENDING_OUTPUT..=MAX_PENDING_OUTPUT + sys::OUTPUT_CHUNK).contains(&pending));
+        assert_eq!(handle.finish().unwrap_err().code(), "invalid_input");
+
+        let chunks = collector(&handle);
+        handle.finish().unwrap();
+        let chunks = chunks.lock().unwrap();
+        assert!(chunks.iter().skip(1).all(|c| c.len() <= sys::OUTPUT_CHUNK));
+        assert_eq!(chunks.concat(), data);
+    }
+
+    /// An `output` that appends every chunk to the returned buffer.
+    fn collector(handle: &TransformHandle) -> std::sync::Arc<Mutex<Vec<Vec<u8>>>> {
+        let chunks = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let sink = chunks.clone();
+        handle
+            .attach(Box::new(move |bytes| sink.lock().unwrap().push(bytes)))
+            .unwrap();
+        chunks
+    }
+
+    #[test]
+    fn handle_flushes_pending_output_then_streams() {
+        // Incompressible, so the encoder has output before `output` is registered.
+        let mut rng = fastrand::Rng::with_seed(3);
+        let data: Vec<u8> = (0..600_000).map(|_| rng.u8(..)).collect();
+        let (early, late) = data.split_at(400_000);
+        let handle = open_transform(TransformOp::ZstdCompress { level: 3 }).unwrap();
+
+        for chunk in early.chunks(70_000) {
+            handle.feed(chunk.to_vec()).unwrap();
+        }
+        let chunks = collector(&handle);
+        let flushed = chunks.lock().unwrap().len();
+        for chunk in late.chunks(70_000) {
+            handle.feed(chunk.to_vec()).unwrap();
+        }
+        handle.finish().unwrap();
+
+        let chunks = chunks.lock().unwrap();
+        // Whatever was held arrives as one chunk, the moment `output` is called.
+        assert_eq!(flushed, 1);
+        assert!(chunks.len() > flushed, "finish must emit the frame's tail");
+        let packed = chunks.concat();
+        assert_eq!(
+            compress::decompress(packed, CompressAlgo::Zstd).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn handle_without_output_stops_buffering_at_the_cap() {
+        let mut rng = fastrand::Rng::with_seed(7);
+        let handle = open_transform(TransformOp::ZstdCompress { level: 1 }).unwrap();
+        // Random bytes don't compress, so output grows as fast as input.
+        let mut chunk = || (0..1 << 20).map(|_| rng.u8(..)).collect::<Vec<u8>>();
+
+        let mut fed = Vec::new();
+        let rejected = loop {
+            let next = chunk();
+            match handle.feed(next.clone()) {
+                Ok(()) => fed.extend(next),
+                Err(err) => break (err, next),
+            }
+            assert!(
+                fed.len() <= 2 * MAX_PENDING_OUTPUT,
+                "feed never pushed back"
+            );
+        };
+        assert_eq!(rejected.0.code(), "invalid_input");
+        assert!(handle.lock().unwrap().pending.len() < MAX_PENDING_OUTPUT + (2 << 20));
+
+        // Once the stream is registered the same chunk goes through.
+        let chunks = collector(&handle);
+        handle.feed(rejected.1.clone()).unwrap();
+        handle.finish().unwrap();
+        fed.extend(rejected.1);
+        let packed = chunks.lock().unwrap().concat();
+        assert_eq!(
+            compress::decompress(packed, CompressAlgo::Zstd).unwrap(),
+            fed
+        );
+    }
+
+    #[test]
+    fn handle_rejects_bad_level_and_use_after_finish() {
+        let err = open_transform(TransformOp::ZstdCompress { level: 99 }).err();
+        assert_eq!(err.map(|e| e.code()).as_deref(), Some("invalid_input"));
+
+        let handle = open_transform(TransformOp::ZstdCompress { level: 1 }).unwrap();
+        handle.feed(b"hello".to_vec()).unwrap();
+        handle.finish().unwrap();
+        assert_eq!(
+            handle.feed(b"late".to_vec()).unwrap_err().code(),
+            "invalid_input"
+        );
+        assert_eq!(handle.finish().unwrap_err().code(), "invalid_input");
+    }
+}
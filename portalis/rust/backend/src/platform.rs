@@ -0,0 +1,56 @@
+use flutter_rust_bridge::frb;
+
+/// Runtime capabilities so the UI can hide features the current platform lacks.
+pub struct PlatformInfo {
+    /// `std::env::consts::OS`, or `web` for wasm builds.
+    pub os: String,
+    pub arch: String,
+    pub is_web: bool,
+    pub has_filesystem: bool,
+    pub has_threads: bool,
+    pub num_cpus: u32,
+}
+
+// Cheap and called during startup, so keep it sync like `get_version`.
+#[frb(sync)]
+pub fn get_platform_info() -> PlatformInfo {
+    let is_web = cfg!(target_family = "wasm");
+    PlatformInfo {
+        os: if is_web {
+            "web".to_string()
+        } else {
+            std::env::consts::OS.to_string()
+        },
+        arch: std::env::consts::ARCH.to_string(),
+        is_web,
+        has_filesystem: !is_web,
+        has_threads: !is_web,
+        num_cpus: num_cpus(),
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn num_cpus() -> u32 {
+    std::thread::available_parallelism().map_or(1, |n| n.get() as u32)
+}
+
+#[cfg(target_family = "wasm")]
+fn num_cpus() -> u32 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_platform_reports_capabilities() {
+        let info = get_platform_info();
+        assert_eq!(info.os, std::env::consts::OS);
+        assert_eq!(info.arch, std::env::consts::ARCH);
+        assert!(!info.is_web);
+        assert!(info.has_filesystem);
+        assert!(info.has_threads);
+        assert!(info.num_cpus >= 1);
+    }
+}
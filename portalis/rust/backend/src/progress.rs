@@ -0,0 +1,251 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::{fs, runtime};
+
+/// Progress of a multi-stage operation, reported on a single stream.
+///
+/// `percent` covers the whole operation, never decreases and stays within 0..=100.
+/// The last event of every stream has `finished` set; `error` is filled in if the
+/// operation failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationProgress {
+    pub stage: String,
+    pub stage_index: u32,
+    pub stage_count: u32,
+    pub percent: f32,
+    pub finished: bool,
+    pub error: Option<String>,
+}
+
+/// Turns per-stage progress into `OperationProgress` events, enforcing the guarantees above.
+pub(crate) struct ProgressReporter<F: FnMut(OperationProgress)> {
+    stages: &'static [&'static str],
+    index: usize,
+    percent: f32,
+    finished: bool,
+    emit: F,
+}
+
+impl<F: FnMut(OperationProgress)> ProgressReporter<F> {
+    pub(crate) fn new(stages: &'static [&'static str], emit: F) -> Self {
+        assert!(!stages.is_empty(), "an operation needs at least one stage");
+        ProgressReporter {
+            stages,
+            index: 0,
+            percent: 0.0,
+            finished: false,
+            emit,
+        }
+    }
+
+    /// Moves on to stage `index` and reports its start.
+    pub(crate) fn begin(&mut self, index: usize) {
+        self.index = index.min(self.stages.len() - 1);
+        self.report(0.0);
+    }
+
+    /// Reports that `fraction` (0.0..=1.0) of the current stage is done.
+    pub(crate) fn report(&mut self, fraction: f32) {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        let overall = (self.index as f32 + fraction) / self.stages.len() as f32 * 100.0;
+        self.percent = self.percent.max(overall).min(100.0);
+        self.send(false, None);
+    }
+
+    /// Emits the terminal event; success jumps to 100%, failure keeps the last percent.
+    ///
+    /// Anything reported after this is dropped, so the terminal event stays last.
+    pub(crate) fn finish(&mut self, result: Result<(), PortalisError>) {
+        match result {
+            Ok(()) => {
+                self.percent = 100.0;
+                self.send(true, None);
+            }
+            Err(err) => self.send(true, Some(err.to_string())),
+        }
+    }
+
+    fn send(&mut self, finished: bool, error: Option<String>) {
+        if self.finished {
+            return;
+        }
+        self.finished = finished;
+        (self.emit)(OperationProgress {
+            stage: self.stages[self.index].to_string(),
+            stage_index: self.index as u32,
+            stage_count: self.stages.len() as u32,
+            percent: self.percent,
+            finished,
+            error,
+        });
+    }
+}
+
+const IMPORT_STAGES: &[&str] = &["validate", "transform", "store"];
+
+/// Sample three-stage import of a JSON Lines file into `<path>.imported.json`.
+///
+/// Validates that the file is UTF-8, parses every non-empty line as JSON, then
+/// stores them as one JSON array with `write_file_atomic`. A timeout ends the stream
+/// with a terminal event carrying the `Timeout` error, like any other failure.
+pub async fn run_import(
+    path: String,
+    timeout_ms: Option<u64>,
+    sink: StreamSink<OperationProgress>,
+) {
+    import(path, timeout_ms, move |event| {
+        let _ = sink.add(event);
+    })
+    .await
+}
+
+async fn import<F>(path: String, timeout_ms: Option<u64>, emit: F)
+where
+    F: FnMut(OperationProgress) + Send + 'static,
+{
+    // Shared so the terminal event can still be sent after a timeout drops the stages.
+    let progress = Arc::new(Mutex::new(ProgressReporter::new(IMPORT_STAGES, emit)));
+    let stages = {
+        let progress = progress.clone();
+        async move { import_stages(&path, &progress).await }
+    };
+    let result = runtime::with_timeout(timeout_ms, stages).await;
+    lock(&progress).finish(result);
+}
+
+fn lock<F: FnMut(OperationProgress)>(
+    progress: &Mutex<ProgressReporter<F>>,
+) -> MutexGuard<'_, ProgressReporter<F>> {
+    // A panic mid-report can't leave the reporter inconsistent.
+    progress.lock().unwrap_or_else(|p| p.into_inner())
+}
+
+async fn import_stages(
+    path: &str,
+    progress: &Mutex<ProgressReporter<impl FnMut(OperationProgress)>>,
+) -> Result<(), PortalisError> {
+    lock(progress).begin(0);
+    let bytes = fs::read_file(path.to_string(), None).await?;
+    let text = String::from_utf8(bytes)
+        .map_err(|err| PortalisError::InvalidInput(format!("{path} is not UTF-8: {err}")))?;
+
+    lock(progress).begin(1);
+    let lines: Vec<&str> = text.lines().collect();
+    let mut records = Vec::with_capacity(lines.len());
+    // Enumerate before skipping blanks, so errors name the line as an editor shows it.
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: serde_json::Value = serde_json::from_str(line)
+            .map_err(|err| PortalisError::InvalidInput(format!("{path} line {}: {err}", i + 1)))?;
+        records.push(record);
+        lock(progress).report((i + 1) as f32 / lines.len() as f32);
+    }
+
+    lock(progress).begin(2);
+    let out = serde_json::to_vec(&records).expect("JSON values always serialize");
+    fs::write_file_atomic(format!("{path}.imported.json"), out, false, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn collect(path: String, timeout_ms: Option<u64>) -> Vec<OperationProgress> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        import(path, timeout_ms, move |event| {
+            let _ = tx.send(event);
+        })
+        .await;
+        rx.try_iter().collect()
+    }
+
+    fn assert_well_formed(events: &[OperationProgress]) {
+        assert!(events.windows(2).all(|w| w[0].percent <= w[1].percent));
+        assert!(events.iter().all(|e| (0.0..=100.0).contains(&e.percent)));
+        assert!(events.iter().all(|e| e.stage_count == 3));
+        let (last, rest) = events.split_last().unwrap();
+        assert!(last.finished);
+        assert!(rest.iter().all(|e| !e.finished));
+    }
+
+    #[tokio::test]
+    async fn import_walks_every_stage_and_ends_at_100() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("people.jsonl");
+        std::fs::write(&path, "{\"name\":\"ada\"}\n\n{\"name\":\"alan\"}\n").unwrap();
+        let path = path.to_string_lossy().into_owned();
+
+        let events = collect(path.clone(), None).await;
+
+        assert_well_formed(&events);
+        let stages: Vec<_> = events.iter().map(|e| e.stage_index).collect();
+        assert_eq!(stages, [0, 1, 1, 1, 2, 2]);
+        let last = events.last().unwrap();
+        assert_eq!((last.percent, last.error.as_deref()), (100.0, None));
+        let stored = std::fs::read_to_string(format!("{path}.imported.json")).unwrap();
+        assert_eq!(stored, r#"[{"name":"ada"},{"name":"alan"}]"#);
+    }
+
+    #[tokio::test]
+    async fn failed_import_still_ends_with_terminal_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.jsonl");
+        std::fs::write(&path, "{\"ok\":true}\nnot json\n").unwrap();
+
+        let events = collect(path.to_string_lossy().into_owned(), None).await;
+
+        assert_well_formed(&events);
+        let last = events.last().unwrap();
+        assert_eq!(last.stage, "transform");
+        assert!(last.percent < 100.0);
+        assert!(last.error.as_deref().unwrap().contains("line 2"));
+    }
+
+    #[tokio::test]
+    async fn error_names_the_real_line_after_blank_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gappy.jsonl");
+        std::fs::write(&path, "{\"ok\":true}\n\n   \nnot json\n").unwrap();
+
+        let events = collect(path.to_string_lossy().into_owned(), None).await;
+
+        let error = events.last().unwrap().error.clone().unwrap();
+        assert!(error.contains("line 4:"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn timed_out_import_ends_with_terminal_timeout_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("huge.jsonl");
+        let line = format!("{{\"pad\":\"{}\"}}\n", "x".repeat(1000));
+        std::fs::write(&path, line.repeat(20_000)).unwrap();
+
+        let events = collect(path.to_string_lossy().into_owned(), Some(1)).await;
+
+        assert_well_formed(&events);
+        let error = events.last().unwrap().error.clone().unwrap();
+        assert!(error.starts_with("timed out"), "{error}");
+    }
+
+    #[test]
+    fn reporter_clamps_and_never_goes_backwards() {
+        let mut events = Vec::new();
+        let mut progress = ProgressReporter::new(&["a", "b"], |e| events.push(e.percent));
+        progress.begin(1);
+        progress.report(2.0);
+        progress.begin(0);
+        progress.report(f32::NAN);
+        progress.finish(Ok(()));
+        progress.report(0.5);
+
+        assert_eq!(events, [50.0, 100.0, 100.0, 100.0, 100.0]);
+    }
+}
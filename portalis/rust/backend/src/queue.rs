@@ -0,0 +1,331 @@
+use std::sync::{Mutex, MutexGuard};
+
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+
+/// An action saved for later, e.g. a request made while offline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedOp {
+    /// Assigned by `queue_enqueue`; whatever the caller passes in is ignored.
+    pub id: u64,
+    /// App-defined tag saying how to replay `payload`.
+    pub kind: String,
+    pub payload: Vec<u8>,
+}
+
+// Native builds persist to sled; web keeps an in-memory queue behind the same API,
+// so ops queued there are lost on reload. Like the kv store, the queue is a global.
+static QUEUE: Mutex<Option<sys::Queue>> = Mutex::new(None);
+
+fn lock_queue() -> Result<MutexGuard<'static, Option<sys::Queue>>, PortalisError> {
+    QUEUE
+        .lock()
+        .map_err(|_| PortalisError::Internal("queue lock poisoned".into()))
+}
+
+fn with_queue<T>(
+    f: impl FnOnce(&mut sys::Queue) -> Result<T, PortalisError>,
+) -> Result<T, PortalisError> {
+    let mut guard = lock_queue()?;
+    match guard.as_mut() {
+        Some(queue) => f(queue),
+        None => Err(PortalisError::Internal(
+            "queue is not open; call queue_open first".into(),
+        )),
+    }
+}
+
+// Not `#[frb(sync)]`: every change is flushed to disk before returning, which can be slow.
+
+/// Opens (or reopens) the queue stored at `path`. On web it lives in memory until the
+/// page reloads, but reopening the same `path` still finds its ops.
+pub fn queue_open(path: String) -> Result<(), PortalisError> {
+    close()?;
+    let queue = sys::Queue::open(&path)?;
+    *lock_queue()? = Some(queue);
+    Ok(())
+}
+
+/// Flushes the open queue to disk and closes it; a no-op if none is open.
+///
+/// Like `kv::close`, returns once the same path can be opened again.
+pub(crate) fn close() -> Result<(), PortalisError> {
+    let queue = lock_queue()?.take();
+    match queue {
+        Some(queue) => queue.close(),
+        None => Ok(()),
+    }
+}
+
+/// Appends `op` and returns its id. Ids increase, so they also give the FIFO order.
+pub fn queue_enqueue(op: QueuedOp) -> Result<u64, PortalisError> {
+    with_queue(|queue| queue.push(&op.kind, &op.payload))
+}
+
+/// The oldest op not yet acked, without removing it.
+pub fn queue_peek() -> Result<Option<QueuedOp>, PortalisError> {
+    with_queue(|queue| queue.first())
+}
+
+/// Removes op `id` once it has been replayed. Acking an unknown or already acked id is a no-op.
+pub fn queue_ack(id: u64) -> Result<(), PortalisError> {
+    with_queue(|queue| queue.remove(id))
+}
+
+/// Streams every pending op oldest first, then closes.
+///
+/// Ops stay queued until acked, so a replay interrupted half-way resumes where it stopped.
+pub fn queue_drain(sink: StreamSink<QueuedOp>) -> Result<(), PortalisError> {
+    for op in with_queue(|queue| queue.pending())? {
+        if sink.add(op).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use super::QueuedOp;
+    use crate::error::PortalisError;
+
+    pub(super) struct Queue {
+        path: String,
+        db: sled::Db,
+    }
+
+    impl Queue {
+        pub(super) fn open(path: &str) -> Result<Self, PortalisError> {
+            Ok(Queue {
+                path: path.to_string(),
+                db: crate::kv::open_sled(path)?,
+            })
+        }
+
+        pub(super) fn push(&mut self, kind: &str, payload: &[u8]) -> Result<u64, PortalisError> {
+            // sled ids survive restarts and only grow, and big-endian keys sort like numbers.
+            let id = self.db.generate_id()?;
+            self.db.insert(id.to_be_bytes(), encode(kind, payload))?;
+            self.db.flush()?;
+            Ok(id)
+        }
+
+        pub(super) fn first(&mut self) -> Result<Option<QueuedOp>, PortalisError> {
+            match self.db.first()? {
+                Some((key, value)) => Ok(Some(decode(&key, &value)?)),
+                None => Ok(None),
+            }
+        }
+
+        pub(super) fn pending(&mut self) -> Result<Vec<QueuedOp>, PortalisError> {
+            self.db
+                .iter()
+                .map(|entry| {
+                    let (key, value) = entry?;
+                    decode(&key, &value)
+                })
+                .collect()
+        }
+
+        pub(super) fn remove(&mut self, id: u64) -> Result<(), PortalisError> {
+            if self.db.remove(id.to_be_bytes())?.is_some() {
+                self.db.flush()?;
+            }
+            Ok(())
+        }
+
+        pub(super) fn close(self) -> Result<(), PortalisError> {
+            crate::kv::close_sled(self.db, &self.path)
+        }
+    }
+
+    /// `kind` length (u32 BE), `kind`, then the payload bytes.
+    fn encode(kind: &str, payload: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(4 + kind.len() + payload.len());
+        out.extend_from_slice(&(kind.len() as u32).to_be_bytes());
+        out.extend_from_slice(kind.as_bytes());
+        out.extend_from_slice(payload);
+        out
+    }
+
+    fn decode(key: &[u8], value: &[u8]) -> Result<QueuedOp, PortalisError> {
+        let corrupted = || PortalisError::Corrupted("malformed queue entry".into());
+        let id = u64::from_be_bytes(key.try_into().map_err(|_| corrupted())?);
+        let (len, rest) = value.split_first_chunk::<4>().ok_or_else(corrupted)?;
+        let len = u32::from_be_bytes(*len) as usize;
+        if rest.len() < len {
+            return Err(corrupted());
+        }
+        let (kind, payload) = rest.split_at(len);
+        Ok(QueuedOp {
+            id,
+            kind: String::from_utf8(kind.to_vec()).map_err(|_| corrupted())?,
+            payload: payload.to_vec(),
+        })
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    use super::QueuedOp;
+    use crate::error::PortalisError;
+
+    struct Data {
+        ops: BTreeMap<u64, QueuedOp>,
+        next_id: u64,
+    }
+
+    /// Every queue opened since the page loaded, by path, so a reopen keeps its ops and
+    /// ids keep growing, as they do with sled on native.
+    static QUEUES: Mutex<BTreeMap<String, Data>> = Mutex::new(BTreeMap::new());
+
+    pub(super) struct Queue {
+        path: String,
+    }
+
+    impl Queue {
+        pub(super) fn open(path: &str) -> Result<Self, PortalisError> {
+            let queue = Queue {
+                path: path.to_string(),
+            };
+            queue.with(|_| ())?;
+            Ok(queue)
+        }
+
+        fn with<T>(&self, f: impl FnOnce(&mut Data) -> T) -> Result<T, PortalisError> {
+            let mut queues = QUEUES
+                .lock()
+                .map_err(|_| PortalisError::Internal("queue lock poisoned".into()))?;
+            let data = queues.entry(self.path.clone()).or_insert_with(|| Data {
+                ops: BTreeMap::new(),
+                next_id: 0,
+            });
+            Ok(f(data))
+        }
+
+        pub(super) fn push(&mut self, kind: &str, payload: &[u8]) -> Result<u64, PortalisError> {
+            self.with(|data| {
+                let id = data.next_id;
+                data.next_id += 1;
+                data.ops.insert(
+                    id,
+                    QueuedOp {
+                        id,
+                        kind: kind.to_string(),
+                        payload: payload.to_vec(),
+                    },
+                );
+                id
+            })
+        }
+
+        pub(super) fn first(&mut self) -> Result<Option<QueuedOp>, PortalisError> {
+            self.with(|data| data.ops.values().next().cloned())
+        }
+
+        pub(super) fn pending(&mut self) -> Result<Vec<QueuedOp>, PortalisError> {
+            self.with(|data| data.ops.values().cloned().collect())
+        }
+
+        pub(super) fn remove(&mut self, id: u64) -> Result<(), PortalisError> {
+            self.with(|data| {
+                data.ops.remove(&id);
+            })
+        }
+
+        pub(super) fn close(self) -> Result<(), PortalisError> {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // The queue is a process-wide global, so tests touching it must not interleave.
+    pub(crate) static GLOBAL_QUEUE_LOCK: Mutex<()> = Mutex::new(());
+
+    fn op(kind: &str, payload: &[u8]) -> QueuedOp {
+        QueuedOp {
+            id: 0,
+            kind: kind.into(),
+            payload: payload.to_vec(),
+        }
+    }
+
+    fn pending_kinds() -> Vec<String> {
+        with_queue(|queue| queue.pending())
+            .unwrap()
+            .into_iter()
+            .map(|op| op.kind)
+            .collect()
+    }
+
+    #[test]
+    fn ops_survive_reopen_in_fifo_order() {
+        let _lock = GLOBAL_QUEUE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().into_owned();
+
+        queue_open(path.clone()).unwrap();
+        let first = queue_enqueue(op("upload", b"photo-1")).unwrap();
+        let second = queue_enqueue(op("like", b"")).unwrap();
+        queue_enqueue(op("upload", b"photo-2")).unwrap();
+        assert!(second > first);
+
+        // Simulate an app restart.
+        queue_open(path).unwrap();
+
+        assert_eq!(
+            queue_peek().unwrap(),
+            Some(QueuedOp {
+                id: first,
+                kind: "upload".into(),
+                payload: b"photo-1".to_vec(),
+            })
+        );
+        assert_eq!(pending_kinds(), ["upload", "like", "upload"]);
+    }
+
+    #[test]
+    fn ack_removes_once_and_is_idempotent() {
+        let _lock = GLOBAL_QUEUE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        queue_open(dir.path().to_string_lossy().into_owned()).unwrap();
+
+        let first = queue_enqueue(op("a", b"1")).unwrap();
+        queue_enqueue(op("b", b"2")).unwrap();
+        queue_ack(first).unwrap();
+        queue_ack(first).unwrap();
+        queue_ack(12_345).unwrap();
+
+        assert_eq!(pending_kinds(), ["b"]);
+        let next = queue_peek().unwrap().unwrap();
+        queue_ack(next.id).unwrap();
+        assert_eq!(queue_peek().unwrap(), None);
+    }
+
+    #[test]
+    fn ids_keep_growing_after_restart() {
+        let _lock = GLOBAL_QUEUE_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().into_owned();
+
+        queue_open(path.clone()).unwrap();
+        let before = queue_enqueue(op("x", b"")).unwrap();
+        queue_ack(before).unwrap();
+        queue_open(path).unwrap();
+        let after = queue_enqueue(op("y", b"")).unwrap();
+
+        assert!(after > before);
+    }
+}
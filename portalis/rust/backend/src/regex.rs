@@ -0,0 +1,177 @@
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, OnceLock};
+
+use crate::error::PortalisError;
+
+/// One match of a pattern inside the searched text.
+///
+/// Offsets are in UTF-16 code units, the same indexing Dart `String`s use, so
+/// `text.substring(start, end)` on the Dart side yields `matched`.
+#[derive(Debug, PartialEq)]
+pub struct RegexMatch {
+    pub start: u32,
+    pub end: u32,
+    pub matched: String,
+    /// Capture groups 1.., `None` where a group did not take part in the match.
+    pub groups: Vec<Option<String>>,
+}
+
+/// Compiled patterns kept around for reuse; the least recently used is evicted first.
+const CACHE_CAPACITY: usize = 64;
+
+fn cache() -> &'static Mutex<lru::LruCache<String, ::regex::Regex>> {
+    static CACHE: OnceLock<Mutex<lru::LruCache<String, ::regex::Regex>>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        Mutex::new(lru::LruCache::new(
+            NonZeroUsize::new(CACHE_CAPACITY).expect("non-zero capacity"),
+        ))
+    })
+}
+
+fn compile(pattern: &str) -> Result<::regex::Regex, PortalisError> {
+    let mut cache = cache()
+        .lock()
+        .map_err(|_| PortalisError::Internal("regex cache lock poisoned".into()))?;
+    if let Some(regex) = cache.get(pattern) {
+        // Cloning shares the compiled program, so the lock isn't held while matching.
+        return Ok(regex.clone());
+    }
+    let regex = ::regex::Regex::new(pattern)
+        .map_err(|err| PortalisError::InvalidInput(format!("invalid regex: {err}")))?;
+    cache.put(pattern.to_string(), regex.clone());
+    Ok(regex)
+}
+
+// Not `#[frb(sync)]`: texts can be large, so these run on the FRB thread pool.
+
+/// Returns every non-overlapping match of `pattern` in `text`, left to right.
+pub fn regex_find_all(pattern: String, text: String) -> Result<Vec<RegexMatch>, PortalisError> {
+    let regex = compile(&pattern)?;
+    let mut offsets = Utf16Offsets::new(&text);
+    Ok(regex
+        .captures_iter(&text)
+        .map(|caps| {
+            let whole = caps.get(0).expect("group 0 is always present");
+            RegexMatch {
+                start: offsets.at(whole.start()),
+                end: offsets.at(whole.end()),
+                matched: whole.as_str().to_string(),
+                groups: caps
+                    .iter()
+                    .skip(1)
+                    .map(|group| group.map(|g| g.as_str().to_string()))
+                    .collect(),
+            }
+        })
+        .collect())
+}
+
+/// Replaces every match of `pattern` in `text`.
+///
+/// `replacement` may refer to groups as `$1` or `${name}`; write `$$` for a literal `$`.
+pub fn regex_replace_all(
+    pattern: String,
+    text: String,
+    replacement: String,
+) -> Result<String, PortalisError> {
+    let regex = compile(&pattern)?;
+    Ok(regex.replace_all(&text, replacement.as_str()).into_owned())
+}
+
+/// Converts increasing byte offsets into UTF-16 offsets without rescanning from the start.
+struct Utf16Offsets<'a> {
+    text: &'a str,
+    byte: usize,
+    utf16: usize,
+}
+
+impl<'a> Utf16Offsets<'a> {
+    fn new(text: &'a str) -> Self {
+        Utf16Offsets {
+            text,
+            byte: 0,
+            utf16: 0,
+        }
+    }
+
+    /// `byte` must be a char boundary no smaller than the previous call's.
+    fn at(&mut self, byte: usize) -> u32 {
+        self.utf16 += self.text[self.byte..byte].encode_utf16().count();
+        self.byte = byte;
+        self.utf16 as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_all_reports_capture_groups() {
+        let matches = regex_find_all(
+            r"(\w+)@(\w+)(\.org)?".into(),
+            "ann@example bob@site.org".into(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            matches,
+            [
+                RegexMatch {
+                    start: 0,
+                    end: 11,
+                    matched: "ann@example".into(),
+                    groups: vec![Some("ann".into()), Some("example".into()), None],
+                },
+                RegexMatch {
+                    start: 12,
+                    end: 24,
+                    matched: "bob@site.org".into(),
+                    groups: vec![Some("bob".into()), Some("site".into()), Some(".org".into())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn offsets_count_utf16_units() {
+        // "é" is one UTF-16 unit but two bytes; "🦀" is two units and four bytes.
+        let matches = regex_find_all(r"\p{Han}+".into(), "é🦀 日本 x 語".into()).unwrap();
+
+        let spans: Vec<_> = matches
+            .iter()
+            .map(|m| (m.start, m.end, m.matched.as_str()))
+            .collect();
+        assert_eq!(spans, [(4, 6, "日本"), (9, 10, "語")]);
+    }
+
+    #[test]
+    fn replace_all_expands_groups() {
+        let out = regex_replace_all(
+            r"(?<y>\d{4})-(?<m>\d{2})".into(),
+            "from 2024-03 to 2025-11".into(),
+            "${m}/$y".into(),
+        )
+        .unwrap();
+        assert_eq!(out, "from 03/2024 to 11/2025");
+
+        let out = regex_replace_all(r"\s+".into(), "naïve  café\tüber".into(), " ".into());
+        assert_eq!(out.unwrap(), "naïve café über");
+    }
+
+    #[test]
+    fn bad_pattern_is_invalid_input() {
+        for pattern in ["(unclosed", "[z-a]", r"\p{NotAScript}"] {
+            let err = regex_find_all(pattern.into(), String::new()).unwrap_err();
+            assert_eq!(err.code(), "invalid_input", "{pattern}");
+        }
+    }
+
+    #[test]
+    fn cache_stays_bounded() {
+        for i in 0..CACHE_CAPACITY * 2 {
+            compile(&format!("bounded{i}")).unwrap();
+        }
+        assert!(cache().lock().unwrap().len() <= CACHE_CAPACITY);
+    }
+}
@@ -0,0 +1,155 @@
+use flutter_rust_bridge::frb;
+
+/// Snapshot of this process's resource usage for diagnostics screens.
+///
+/// What the numbers mean depends on the OS:
+/// - Linux/Android: `VmRSS`, `VmSize` and `Threads` from `/proc/self/status`.
+/// - macOS: resident and virtual size from `proc_pidinfo`; virtual size includes the
+///   shared system regions, so it is far larger than on other platforms.
+/// - Windows: working set and pagefile-backed commit charge, threads via a toolhelp
+///   snapshot.
+/// - Web, iOS and anything else: all zeros with `available: false`.
+#[derive(Debug, Default)]
+pub struct ResourceStats {
+    pub available: bool,
+    pub rss_bytes: u64,
+    pub virtual_bytes: u64,
+    pub num_threads: u32,
+}
+
+// Cheap enough to poll from a diagnostics screen, so sync like `get_platform_info`.
+#[frb(sync)]
+pub fn get_resource_stats() -> ResourceStats {
+    sys::read().unwrap_or_default()
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use super::ResourceStats;
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(super) fn read() -> Option<ResourceStats> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let field = |name: &str| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+                .and_then(|rest| rest.split_whitespace().next()?.parse::<u64>().ok())
+        };
+        Some(ResourceStats {
+            available: true,
+            rss_bytes: field("VmRSS")? * 1024,
+            virtual_bytes: field("VmSize")? * 1024,
+            num_threads: field("Threads")? as u32,
+        })
+    }
+
+    #[cfg(target_os = "macos")]
+    pub(super) fn read() -> Option<ResourceStats> {
+        let mut info: libc::proc_taskinfo = unsafe { std::mem::zeroed() };
+        let size = std::mem::size_of::<libc::proc_taskinfo>() as libc::c_int;
+        // SAFETY: `info` is a writable buffer of exactly `size` bytes.
+        let written = unsafe {
+            libc::proc_pidinfo(
+                libc::getpid(),
+                libc::PROC_PIDTASKINFO,
+                0,
+                (&mut info as *mut libc::proc_taskinfo).cast(),
+                size,
+            )
+        };
+        if written != size {
+            return None;
+        }
+        Some(ResourceStats {
+            available: true,
+            rss_bytes: info.pti_resident_size,
+            virtual_bytes: info.pti_virtual_size,
+            num_threads: info.pti_threadnum as u32,
+        })
+    }
+
+    #[cfg(windows)]
+    pub(super) fn read() -> Option<ResourceStats> {
+        use windows_sys::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
+        use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+        };
+        use windows_sys::Win32::System::ProcessStatus::{
+            GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS,
+        };
+        use windows_sys::Win32::System::Threading::{GetCurrentProcess, GetCurrentProcessId};
+
+        let mut counters: PROCESS_MEMORY_COUNTERS = unsafe { std::mem::zeroed() };
+        counters.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+        // SAFETY: `counters` is a writable struct whose size is passed alongside it.
+        if unsafe { GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, counters.cb) } == 0 {
+            return None;
+        }
+
+        let pid = unsafe { GetCurrentProcessId() };
+        let mut num_threads = 0;
+        // SAFETY: the snapshot handle is checked and closed; `entry.dwSize` is set as
+        // the toolhelp API requires before the first call.
+        unsafe {
+            let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0);
+            if snapshot != INVALID_HANDLE_VALUE {
+                let mut entry: THREADENTRY32 = std::mem::zeroed();
+                entry.dwSize = std::mem::size_of::<THREADENTRY32>() as u32;
+                let mut more = Thread32First(snapshot, &mut entry) != 0;
+                while more {
+                    if entry.th32OwnerProcessID == pid {
+                        num_threads += 1;
+                    }
+                    more = Thread32Next(snapshot, &mut entry) != 0;
+                }
+                CloseHandle(snapshot);
+            }
+        }
+
+        Some(ResourceStats {
+            available: true,
+            rss_bytes: counters.WorkingSetSize as u64,
+            virtual_bytes: counters.PagefileUsage as u64,
+            num_threads,
+        })
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        windows
+    )))]
+    pub(super) fn read() -> Option<ResourceStats> {
+        None
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::ResourceStats;
+
+    pub(super) fn read() -> Option<ResourceStats> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_stats_are_populated() {
+        let stats = get_resource_stats();
+        assert!(stats.available);
+        assert!(stats.rss_bytes > 0);
+        assert!(stats.virtual_bytes >= stats.rss_bytes);
+        assert!(stats.num_threads >= 1);
+    }
+}
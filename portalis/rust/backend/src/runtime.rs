@@ -0,0 +1,397 @@
+use flutter_rust_bridge::frb;
+
+use crate::error::PortalisError;
+
+/// Starts the shared async runtime with `worker_threads` workers (0 picks one per CPU).
+///
+/// Optional: the first async call starts it with the default size. Fails if it is
+/// already running; call `runtime_shutdown` first to resize it. A no-op on web,
+/// where everything runs on the browser's event loop.
+#[frb(sync)]
+pub fn runtime_init(worker_threads: u32) -> Result<(), PortalisError> {
+    sys::init(worker_threads as usize)
+}
+
+/// Stops the shared runtime, cancelling every task still running on it.
+///
+/// Calls waiting on those tasks fail with `PortalisError::Cancelled`; the next async
+/// call (or `runtime_init`) starts a fresh runtime. Returns `false` if none was running.
+#[frb(sync)]
+pub fn runtime_shutdown() -> bool {
+    sys::shutdown()
+}
+
+// For tests that need a runtime of their own to stop.
+#[cfg(test)]
+pub(crate) use io::{run_on, Slot};
+pub(crate) use sys::{drain, par_map, run, with_timeout};
+
+fn timed_out(timeout_ms: u64) -> PortalisError {
+    PortalisError::Timeout(format!("gave up after {timeout_ms} ms"))
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Mutex, OnceLock};
+    use std::time::{Duration, Instant};
+
+    use crate::error::PortalisError;
+
+    static RUNTIME: Slot = Slot::new();
+
+    /// A multi-thread tokio runtime that can be stopped and started again.
+    pub(crate) struct Slot {
+        runtime: Mutex<Option<tokio::runtime::Runtime>>,
+        /// Calls started through `run_on` that haven't finished or been cancelled.
+        in_flight: AtomicUsize,
+    }
+
+    /// Counts one call as in flight for as long as it lives.
+    struct InFlight(&'static AtomicUsize);
+
+    impl Drop for InFlight {
+        fn drop(&mut self) {
+            self.0.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    impl Slot {
+        pub(crate) const fn new() -> Self {
+            Slot {
+                runtime: Mutex::new(None),
+                in_flight: AtomicUsize::new(0),
+            }
+        }
+
+        /// Waits up to `timeout` for in-flight calls to finish, then shuts down and
+        /// returns how many were still running (those are cancelled).
+        pub(crate) fn drain(&self, timeout: Duration) -> usize {
+            let deadline = Instant::now() + timeout;
+            while self.in_flight.load(Ordering::SeqCst) > 0 && Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            let remaining = self.in_flight.load(Ordering::SeqCst);
+            self.shutdown();
+            remaining
+        }
+
+        pub(super) fn init(&self, worker_threads: usize) -> Result<(), PortalisError> {
+            let mut runtime = self.lock()?;
+            if runtime.is_some() {
+                return Err(PortalisError::InvalidInput(
+                    "runtime is already running; call runtime_shutdown first".into(),
+                ));
+            }
+            *runtime = Some(build(worker_threads)?);
+            Ok(())
+        }
+
+        pub(super) fn shutdown(&self) -> bool {
+            let Ok(mut runtime) = self.lock() else {
+                return false;
+            };
+            match runtime.take() {
+                // Dropping a runtime blocks, which panics if the caller is itself async.
+                Some(runtime) => {
+                    runtime.shutdown_background();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        fn handle(&self) -> Result<tokio::runtime::Handle, PortalisError> {
+            let mut runtime = self.lock()?;
+            if runtime.is_none() {
+                *runtime = Some(build(0)?);
+            }
+            Ok(runtime.as_ref().unwrap().handle().clone())
+        }
+
+        fn lock(
+            &self,
+        ) -> Result<std::sync::MutexGuard<'_, Option<tokio::runtime::Runtime>>, PortalisError>
+        {
+            self.runtime
+                .lock()
+                .map_err(|_| PortalisError::Internal("runtime lock poisoned".into()))
+        }
+    }
+
+    fn build(worker_threads: usize) -> Result<tokio::runtime::Runtime, PortalisError> {
+        let worker_threads = match worker_threads {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        };
+        Ok(tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(worker_threads)
+            .thread_name("portalis-rt")
+            .enable_all()
+            .build()?)
+    }
+
+    /// Runs `fut` on `slot`'s runtime, starting it first if needed.
+    pub(crate) async fn run_on<F, T>(slot: &'static Slot, fut: F) -> Result<T, PortalisError>
+    where
+        F: Future<Output = Result<T, PortalisError>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let handle = slot.handle()?;
+        slot.in_flight.fetch_add(1, Ordering::SeqCst);
+        let in_flight = InFlight(&slot.in_flight);
+        let task = handle.spawn(async move {
+            let _in_flight = in_flight;
+            fut.await
+        });
+        match task.await {
+            Ok(result) => result,
+            Err(err) if err.is_cancelled() => Err(PortalisError::Cancelled(
+                "the async runtime was shut down".into(),
+            )),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Runs an async API call on the shared runtime so `runtime_shutdown` can stop it.
+    pub(crate) async fn run<F, T>(fut: F) -> Result<T, PortalisError>
+    where
+        F: Future<Output = Result<T, PortalisError>> + Send + 'static,
+        T: Send + 'static,
+    {
+        run_on(&RUNTIME, fut).await
+    }
+
+    /// Like `run`, but fails with `PortalisError::Timeout` once `timeout_ms` elapses.
+    ///
+    /// `None` waits indefinitely. Cancellation on timeout is best effort: the future is
+    /// dropped at its next await point, but blocking work it already handed to a thread
+    /// runs to completion, and nothing it wrote so far is rolled back.
+    ///
+    /// Every finite async API call takes a `timeout_ms` routed through here. Streams that
+    /// only end when Dart closes them (`tail_file`, `watch_path`, `subscribe_events`,
+    /// `watch_config`) deliberately don't: a deadline would just cut them off.
+    pub(crate) async fn with_timeout<F, T>(
+        timeout_ms: Option<u64>,
+        fut: F,
+    ) -> Result<T, PortalisError>
+    where
+        F: Future<Output = Result<T, PortalisError>> + Send + 'static,
+        T: Send + 'static,
+    {
+        run(async move {
+            let Some(ms) = timeout_ms else {
+                return fut.await;
+            };
+            tokio::time::timeout(Duration::from_millis(ms), fut)
+                .await
+                .unwrap_or_else(|_| Err(super::timed_out(ms)))
+        })
+        .await
+    }
+
+    /// Maps `items` through `f` on a CPU pool sized to the machine, keeping input order.
+    ///
+    /// Stops handing out new items after the first error and returns that error.
+    pub(crate) fn par_map<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>, PortalisError>
+    where
+        T: Send,
+        U: Send,
+        F: Fn(T) -> Result<U, PortalisError> + Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        static POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+        let pool = POOL.get_or_init(|| {
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .thread_name(|i| format!("portalis-par-{i}"))
+                .build()
+                .expect("spawning the CPU pool")
+        });
+        pool.install(|| items.into_par_iter().map(f).collect())
+    }
+
+    pub(super) fn init(worker_threads: usize) -> Result<(), PortalisError> {
+        RUNTIME.init(worker_threads)
+    }
+
+    pub(super) fn shutdown() -> bool {
+        RUNTIME.shutdown()
+    }
+
+    /// Lets running async calls finish for up to `timeout`, then stops the shared runtime.
+    ///
+    /// Returns how many calls were cancelled because they were still running, e.g. open
+    /// streams like `tail_file`, which only end when Dart closes them.
+    pub(crate) fn drain(timeout: Duration) -> usize {
+        RUNTIME.drain(timeout)
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use std::future::Future;
+    use std::task::Poll;
+    use std::time::Duration;
+
+    use crate::error::PortalisError;
+
+    // The browser event loop is the runtime, so there is nothing to start or stop and
+    // futures need not be `Send`.
+
+    pub(crate) async fn run<F, T>(fut: F) -> Result<T, PortalisError>
+    where
+        F: Future<Output = Result<T, PortalisError>>,
+    {
+        fut.await
+    }
+
+    /// Races `fut` against a browser timer; see the native version for the semantics.
+    pub(crate) async fn with_timeout<F, T>(
+        timeout_ms: Option<u64>,
+        fut: F,
+    ) -> Result<T, PortalisError>
+    where
+        F: Future<Output = Result<T, PortalisError>>,
+    {
+        let Some(ms) = timeout_ms else {
+            return fut.await;
+        };
+        let mut fut = std::pin::pin!(fut);
+        let mut timer = std::pin::pin!(gloo_timers::future::sleep(Duration::from_millis(ms)));
+        std::future::poll_fn(|cx| {
+            if let Poll::Ready(result) = fut.as_mut().poll(cx) {
+                return Poll::Ready(result);
+            }
+            timer.as_mut().poll(cx).map(|()| Err(super::timed_out(ms)))
+        })
+        .await
+    }
+
+    /// No threads on web, so this is a plain sequential map.
+    pub(crate) fn par_map<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>, PortalisError>
+    where
+        F: Fn(T) -> Result<U, PortalisError>,
+    {
+        items.into_iter().map(f).collect()
+    }
+
+    pub(super) fn init(_worker_threads: usize) -> Result<(), PortalisError> {
+        Ok(())
+    }
+
+    pub(super) fn shutdown() -> bool {
+        false
+    }
+
+    /// Nothing to drain: pending futures belong to the browser's event loop.
+    pub(crate) fn drain(_timeout: Duration) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::io::{run_on, Slot};
+
+    #[tokio::test]
+    async fn shutdown_cancels_outstanding_tasks_and_reinit_works() {
+        // A private slot, so this doesn't cancel other tests using the global runtime.
+        static SLOT: Slot = Slot::new();
+        SLOT.init(2).unwrap();
+        assert!(SLOT.init(2).is_err());
+
+        let pending = tokio::spawn(run_on(&SLOT, async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        }));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(SLOT.shutdown());
+        assert!(!SLOT.shutdown());
+
+        let result = tokio::time::timeout(Duration::from_secs(5), pending)
+            .await
+            .expect("shutdown should cancel the sleeping task")
+            .unwrap();
+        assert_eq!(result.unwrap_err().code(), "cancelled");
+
+        SLOT.init(1).unwrap();
+        assert_eq!(run_on(&SLOT, async { Ok(42) }).await.unwrap(), 42);
+        SLOT.shutdown();
+    }
+
+    #[tokio::test]
+    async fn slow_call_times_out_and_fast_call_does_not() {
+        let slow = super::with_timeout(Some(50), async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+        let err = tokio::time::timeout(Duration::from_secs(5), slow)
+            .await
+            .expect("the timeout should fire long before the sleep ends")
+            .unwrap_err();
+        assert_eq!(err.code(), "timeout");
+
+        let fast = super::with_timeout(Some(5_000), async { Ok(7) });
+        assert_eq!(fast.await.unwrap(), 7);
+        assert_eq!(super::with_timeout(None, async { Ok(8) }).await.unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn timed_out_future_is_dropped() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let finished = Arc::new(AtomicBool::new(false));
+        let flag = finished.clone();
+        let result = super::with_timeout(Some(20), async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            flag.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .await;
+
+        assert_eq!(result.unwrap_err().code(), "timeout");
+        tokio::time::sleep(Duration::from_millis(400)).await;
+        assert!(!finished.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn drain_waits_for_short_calls_and_cancels_long_ones() {
+        static SLOT: Slot = Slot::new();
+        let aux = tokio::runtime::Runtime::new().unwrap();
+        let short = aux.spawn(run_on(&SLOT, async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok("done")
+        }));
+        let long = aux.spawn(run_on(&SLOT, async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok("never")
+        }));
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(SLOT.drain(Duration::from_millis(500)), 1);
+
+        assert_eq!(aux.block_on(short).unwrap().unwrap(), "done");
+        let cancelled = aux.block_on(long).unwrap().unwrap_err();
+        assert_eq!(cancelled.code(), "cancelled");
+        assert_eq!(SLOT.drain(Duration::from_millis(10)), 0);
+    }
+
+    #[tokio::test]
+    async fn first_call_starts_runtime_lazily() {
+        static SLOT: Slot = Slot::new();
+        assert_eq!(run_on(&SLOT, async { Ok("lazy") }).await.unwrap(), "lazy");
+        assert!(SLOT.shutdown());
+    }
+}
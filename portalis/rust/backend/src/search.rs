@@ -0,0 +1,275 @@
+use std::sync::Mutex;
+
+use crate::error::PortalisError;
+
+#[derive(Debug)]
+pub struct SearchHit {
+    pub id: String,
+    /// BM25 relevance; only meaningful relative to other hits of the same query.
+    pub score: f32,
+}
+
+// One index per process, like the kv store, so Dart never holds a handle.
+static INDEX: Mutex<Option<sys::Index>> = Mutex::new(None);
+
+fn with_index<T>(
+    f: impl FnOnce(&mut sys::Index) -> Result<T, PortalisError>,
+) -> Result<T, PortalisError> {
+    let mut guard = INDEX
+        .lock()
+        .map_err(|_| PortalisError::Internal("search index lock poisoned".into()))?;
+    match guard.as_mut() {
+        Some(index) => f(index),
+        None => Err(sys::not_open()),
+    }
+}
+
+// Not `#[frb(sync)]`: indexing and querying touch disk, so they run on the FRB thread pool.
+
+/// Opens (or creates) the index stored in the directory `path`.
+pub fn index_open(path: String) -> Result<(), PortalisError> {
+    let mut guard = INDEX
+        .lock()
+        .map_err(|_| PortalisError::Internal("search index lock poisoned".into()))?;
+    // Drop the old writer first so reopening the same path doesn't hit tantivy's lock file.
+    *guard = None;
+    *guard = Some(sys::Index::open(&path)?);
+    Ok(())
+}
+
+/// Queues `text` under `id`, replacing any document already indexed with that id.
+///
+/// Nothing becomes searchable until `index_commit`.
+pub fn index_add(id: String, text: String) -> Result<(), PortalisError> {
+    with_index(|index| index.add(&id, &text))
+}
+
+/// Persists queued documents and makes them visible to `search_query`.
+pub fn index_commit() -> Result<(), PortalisError> {
+    with_index(|index| index.commit())
+}
+
+/// Returns the best `limit` matches, highest score first.
+///
+/// `query` uses tantivy's syntax: words are OR-ed, `+word` is required, `"a b"` is a phrase.
+pub fn search_query(query: String, limit: u32) -> Result<Vec<SearchHit>, PortalisError> {
+    with_index(|index| index.search(&query, limit as usize))
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use tantivy::collector::TopDocs;
+    use tantivy::query::QueryParser;
+    use tantivy::schema::{Field, Schema, Value, STORED, STRING, TEXT};
+    use tantivy::{doc, IndexReader, IndexWriter, TantivyDocument, Term};
+
+    use super::SearchHit;
+    use crate::error::PortalisError;
+
+    /// Memory the writer may buffer before flushing a segment to disk.
+    const WRITER_HEAP_BYTES: usize = 50 * 1024 * 1024;
+
+    impl From<tantivy::TantivyError> for PortalisError {
+        fn from(err: tantivy::TantivyError) -> Self {
+            match err {
+                tantivy::TantivyError::IoError(err) => {
+                    std::io::Error::new(err.kind(), err.to_string()).into()
+                }
+                tantivy::TantivyError::DataCorruption(_) => {
+                    PortalisError::Corrupted(err.to_string())
+                }
+                other => PortalisError::Internal(other.to_string()),
+            }
+        }
+    }
+
+    pub(super) fn not_open() -> PortalisError {
+        PortalisError::Internal("search index is not open; call index_open first".into())
+    }
+
+    pub(super) struct Index {
+        index: tantivy::Index,
+        writer: IndexWriter,
+        reader: IndexReader,
+        id: Field,
+        text: Field,
+    }
+
+    impl Index {
+        pub(super) fn open(path: &str) -> Result<Self, PortalisError> {
+            let mut schema = Schema::builder();
+            let id = schema.add_text_field("id", STRING | STORED);
+            let text = schema.add_text_field("text", TEXT);
+            std::fs::create_dir_all(path)?;
+            let dir = tantivy::directory::MmapDirectory::open(path)
+                .map_err(|e| PortalisError::Io(e.to_string()))?;
+            let index = tantivy::Index::open_or_create(dir, schema.build())?;
+            Ok(Index {
+                writer: index.writer(WRITER_HEAP_BYTES)?,
+                reader: index.reader()?,
+                index,
+                id,
+                text,
+            })
+        }
+
+        pub(super) fn add(&mut self, id: &str, text: &str) -> Result<(), PortalisError> {
+            self.writer.delete_term(Term::from_field_text(self.id, id));
+            self.writer
+                .add_document(doc!(self.id => id, self.text => text))?;
+            Ok(())
+        }
+
+        pub(super) fn commit(&mut self) -> Result<(), PortalisError> {
+            self.writer.commit()?;
+            self.reader.reload()?;
+            Ok(())
+        }
+
+        pub(super) fn search(
+            &mut self,
+            query: &str,
+            limit: usize,
+        ) -> Result<Vec<SearchHit>, PortalisError> {
+            if limit == 0 {
+                return Ok(Vec::new());
+            }
+            let query = QueryParser::for_index(&self.index, vec![self.text])
+                .parse_query(query)
+                .map_err(|e| PortalisError::InvalidInput(format!("invalid search query: {e}")))?;
+            let searcher = self.reader.searcher();
+            searcher
+                .search(&query, &TopDocs::with_limit(limit))?
+                .into_iter()
+                .map(|(score, address)| {
+                    let doc: TantivyDocument = searcher.doc(address)?;
+                    let id = doc
+                        .get_first(self.id)
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string();
+                    Ok(SearchHit { id, score })
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::SearchHit;
+    use crate::error::PortalisError;
+
+    fn unsupported() -> PortalisError {
+        PortalisError::unsupported_on_web("full-text search")
+    }
+
+    pub(super) fn not_open() -> PortalisError {
+        unsupported()
+    }
+
+    /// Never constructed: `open` always fails on web.
+    pub(super) enum Index {}
+
+    impl Index {
+        pub(super) fn open(_path: &str) -> Result<Self, PortalisError> {
+            Err(unsupported())
+        }
+
+        pub(super) fn add(&mut self, _id: &str, _text: &str) -> Result<(), PortalisError> {
+            match *self {}
+        }
+
+        pub(super) fn commit(&mut self) -> Result<(), PortalisError> {
+            match *self {}
+        }
+
+        pub(super) fn search(
+            &mut self,
+            _query: &str,
+            _limit: usize,
+        ) -> Result<Vec<SearchHit>, PortalisError> {
+            match *self {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The index is a process-wide global, so tests touching it must not interleave.
+    static GLOBAL_INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+    fn open_with(docs: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        index_open(dir.path().to_string_lossy().into_owned()).unwrap();
+        for (id, text) in docs {
+            index_add(id.to_string(), text.to_string()).unwrap();
+        }
+        index_commit().unwrap();
+        dir
+    }
+
+    fn ids(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter().map(|hit| hit.id.as_str()).collect()
+    }
+
+    #[test]
+    fn most_relevant_document_ranks_first() {
+        let _lock = GLOBAL_INDEX_LOCK.lock().unwrap();
+        let _dir = open_with(&[
+            ("garden", "tomatoes and basil grow in the garden"),
+            (
+                "rust",
+                "rust rust rust: the rust borrow checker keeps rust code safe",
+            ),
+            ("mixed", "the garden fence started to rust"),
+        ]);
+
+        let hits = search_query("rust".into(), 10).unwrap();
+        assert_eq!(ids(&hits), ["rust", "mixed"]);
+        assert!(hits[0].score > hits[1].score);
+
+        assert_eq!(ids(&search_query("rust".into(), 1).unwrap()), ["rust"]);
+        assert!(search_query("zebra".into(), 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn re_adding_an_id_replaces_the_document() {
+        let _lock = GLOBAL_INDEX_LOCK.lock().unwrap();
+        let _dir = open_with(&[("note", "first draft about apples")]);
+
+        index_add("note".into(), "final version about pears".into()).unwrap();
+        index_commit().unwrap();
+
+        assert!(search_query("apples".into(), 10).unwrap().is_empty());
+        assert_eq!(ids(&search_query("pears".into(), 10).unwrap()), ["note"]);
+    }
+
+    #[test]
+    fn documents_survive_reopen_but_uncommitted_ones_do_not_show() {
+        let _lock = GLOBAL_INDEX_LOCK.lock().unwrap();
+        let dir = open_with(&[("kept", "persistent words")]);
+        index_add("pending".into(), "persistent but uncommitted".into()).unwrap();
+
+        index_open(dir.path().to_string_lossy().into_owned()).unwrap();
+        assert_eq!(
+            ids(&search_query("persistent".into(), 10).unwrap()),
+            ["kept"]
+        );
+    }
+
+    #[test]
+    fn malformed_query_is_invalid_input() {
+        let _lock = GLOBAL_INDEX_LOCK.lock().unwrap();
+        let _dir = open_with(&[]);
+        let err = search_query("text:(unbalanced".into(), 10).unwrap_err();
+        assert_eq!(err.code(), "invalid_input");
+    }
+}
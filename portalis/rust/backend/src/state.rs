@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+use flutter_rust_bridge::frb;
+
+// Process-wide, so every Dart isolate calling in sees the same values. Nothing is
+// persisted: all counters read 0 and all flags `false` after a restart.
+
+fn counters() -> &'static Registry<AtomicI64> {
+    static COUNTERS: OnceLock<Registry<AtomicI64>> = OnceLock::new();
+    COUNTERS.get_or_init(Registry::new)
+}
+
+fn flags() -> &'static Registry<AtomicBool> {
+    static FLAGS: OnceLock<Registry<AtomicBool>> = OnceLock::new();
+    FLAGS.get_or_init(Registry::new)
+}
+
+// All sync: each call is a single atomic operation, far cheaper than a worker hop.
+
+/// Adds `by` (which may be negative) to counter `name` and returns the new value.
+///
+/// Counters start at 0 and wrap around on overflow.
+#[frb(sync)]
+pub fn counter_incr(name: String, by: i64) -> i64 {
+    counters().with(&name, |counter| {
+        counter.fetch_add(by, Ordering::SeqCst).wrapping_add(by)
+    })
+}
+
+#[frb(sync)]
+pub fn counter_get(name: String) -> i64 {
+    counters()
+        .read(&name, |counter| counter.load(Ordering::SeqCst))
+        .unwrap_or(0)
+}
+
+#[frb(sync)]
+pub fn flag_set(name: String, value: bool) {
+    flags().with(&name, |flag| flag.store(value, Ordering::SeqCst));
+}
+
+/// `false` for flags never set.
+#[frb(sync)]
+pub fn flag_get(name: String) -> bool {
+    flags()
+        .read(&name, |flag| flag.load(Ordering::SeqCst))
+        .unwrap_or(false)
+}
+
+/// Named atomics. The map lock is only taken for writing the first time a name is
+/// used; after that every operation shares a read lock and touches just its atomic.
+struct Registry<T> {
+    map: RwLock<HashMap<String, T>>,
+}
+
+impl<T: Default> Registry<T> {
+    fn new() -> Self {
+        Registry {
+            map: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn read<R>(&self, name: &str, f: impl FnOnce(&T) -> R) -> Option<R> {
+        // The map only ever gains entries, so a panic can't leave it inconsistent.
+        let map = self.map.read().unwrap_or_else(|p| p.into_inner());
+        map.get(name).map(f)
+    }
+
+    /// Runs `f` on the atomic for `name`, creating it first if needed.
+    fn with<R>(&self, name: &str, f: impl FnOnce(&T) -> R) -> R {
+        let map = self.map.read().unwrap_or_else(|p| p.into_inner());
+        if let Some(value) = map.get(name) {
+            return f(value);
+        }
+        drop(map);
+        let mut map = self.map.write().unwrap_or_else(|p| p.into_inner());
+        // Another thread may have created it between dropping the read lock and now.
+        f(map.entry(name.to_string()).or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_increments_are_not_lost() {
+        const THREADS: i64 = 16;
+        const PER_THREAD: i64 = 10_000;
+        std::thread::scope(|s| {
+            for t in 0..THREADS {
+                s.spawn(move || {
+                    for _ in 0..PER_THREAD {
+                        counter_incr("test.stress".into(), 1);
+                        // Other names force the map to grow while the shared counter is hot.
+                        counter_incr(format!("test.stress.{t}"), 2);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(counter_get("test.stress".into()), THREADS * PER_THREAD);
+        assert_eq!(counter_get("test.stress.3".into()), 2 * PER_THREAD);
+    }
+
+    #[test]
+    fn counters_start_at_zero_and_accept_negative_steps() {
+        assert_eq!(counter_get("test.fresh".into()), 0);
+        assert_eq!(counter_incr("test.fresh".into(), 5), 5);
+        assert_eq!(counter_incr("test.fresh".into(), -7), -2);
+        assert_eq!(counter_get("test.fresh".into()), -2);
+    }
+
+    #[test]
+    fn flags_default_to_false_and_keep_the_last_value() {
+        assert!(!flag_get("test.flag".into()));
+        flag_set("test.flag".into(), true);
+        assert!(flag_get("test.flag".into()));
+        flag_set("test.flag".into(), false);
+        assert!(!flag_get("test.flag".into()));
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use flutter_rust_bridge::frb;
+
+use crate::error::PortalisError;
+
+// All sync: these are pure computations over a few bytes, cheaper than a worker hop.
+
+/// Milliseconds since the Unix epoch, read from the system clock.
+#[frb(sync)]
+pub fn now_unix_ms() -> i64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+/// Formats `unix_ms` with a strftime-style `fmt` (e.g. `%Y-%m-%d %H:%M %Z`) in the
+/// IANA timezone `tz` (e.g. `Europe/Rome`; `UTC` also works).
+#[frb(sync)]
+pub fn format_timestamp(unix_ms: i64, fmt: String, tz: String) -> Result<String, PortalisError> {
+    let tz: Tz = tz
+        .parse()
+        .map_err(|_| PortalisError::InvalidInput(format!("unknown timezone {tz:?}")))?;
+    let items = format_items(&fmt)?;
+    let instant = Utc.timestamp_millis_opt(unix_ms).single().ok_or_else(|| {
+        PortalisError::InvalidInput(format!("timestamp {unix_ms} is out of range"))
+    })?;
+    Ok(instant
+        .with_timezone(&tz)
+        .format_with_items(items.iter())
+        .to_string())
+}
+
+/// Parses `s` with strftime-style `fmt` into milliseconds since the Unix epoch.
+///
+/// If `fmt` has no offset (`%z`/`%:z`), the time is taken to be UTC.
+#[frb(sync)]
+pub fn parse_timestamp(s: String, fmt: String) -> Result<i64, PortalisError> {
+    format_items(&fmt)?;
+    let invalid = |e: chrono::ParseError| {
+        PortalisError::InvalidInput(format!("{s:?} does not match {fmt:?}: {e}"))
+    };
+    match DateTime::parse_from_str(&s, &fmt) {
+        Ok(dt) => Ok(dt.timestamp_millis()),
+        // Retry as a naive time only if the input parsed except for the missing offset.
+        Err(e) if e.kind() == chrono::format::ParseErrorKind::NotEnough => {
+            let naive = NaiveDateTime::parse_from_str(&s, &fmt).map_err(invalid)?;
+            Ok(naive.and_utc().timestamp_millis())
+        }
+        Err(e) => Err(invalid(e)),
+    }
+}
+
+/// chrono panics while formatting with a bad pattern, so reject it up front.
+fn format_items(fmt: &str) -> Result<Vec<Item<'_>>, PortalisError> {
+    StrftimeItems::new(fmt)
+        .parse()
+        .map_err(|_| PortalisError::InvalidInput(format!("invalid time format {fmt:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: i64 = 60_000;
+    /// 2024-03-10T07:00:00Z, when New York springs forward from 02:00 EST to 03:00 EDT.
+    const NY_SPRING_FORWARD: i64 = 1_710_054_000_000;
+    /// 2024-11-03T05:30:00Z and 06:30:00Z, both 01:30 in New York on either side of fall back.
+    const NY_FALL_BACK_EDT: i64 = 1_730_611_800_000;
+    const NY_FALL_BACK_EST: i64 = 1_730_615_400_000;
+
+    fn ny(unix_ms: i64) -> String {
+        format_timestamp(
+            unix_ms,
+            "%Y-%m-%d %H:%M %Z".into(),
+            "America/New_York".into(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn spring_forward_skips_an_hour() {
+        assert_eq!(ny(NY_SPRING_FORWARD - MINUTE), "2024-03-10 01:59 EST");
+        assert_eq!(ny(NY_SPRING_FORWARD), "2024-03-10 03:00 EDT");
+    }
+
+    #[test]
+    fn fall_back_repeats_an_hour_with_different_offsets() {
+        assert_eq!(ny(NY_FALL_BACK_EDT), "2024-11-03 01:30 EDT");
+        assert_eq!(ny(NY_FALL_BACK_EST), "2024-11-03 01:30 EST");
+    }
+
+    #[test]
+    fn parsing_with_offsets_disambiguates_dst() {
+        let fmt = "%Y-%m-%d %H:%M %z".to_string();
+        assert_eq!(
+            parse_timestamp("2024-11-03 01:30 -0400".into(), fmt.clone()).unwrap(),
+            NY_FALL_BACK_EDT
+        );
+        assert_eq!(
+            parse_timestamp("2024-11-03 01:30 -0500".into(), fmt).unwrap(),
+            NY_FALL_BACK_EST
+        );
+    }
+
+    #[test]
+    fn parsing_without_offset_assumes_utc() {
+        let ms = parse_timestamp("2024-03-10 07:00:00".into(), "%Y-%m-%d %H:%M:%S".into());
+        assert_eq!(ms.unwrap(), NY_SPRING_FORWARD);
+    }
+
+    #[test]
+    fn format_and_parse_round_trip() {
+        let fmt = "%Y-%m-%dT%H:%M:%S%.3f%:z".to_string();
+        let text = format_timestamp(NY_SPRING_FORWARD + 123, fmt.clone(), "Europe/Rome".into());
+        assert_eq!(text.as_deref().unwrap(), "2024-03-10T08:00:00.123+01:00");
+        assert_eq!(
+            parse_timestamp(text.unwrap(), fmt).unwrap(),
+            NY_SPRING_FORWARD + 123
+        );
+    }
+
+    #[test]
+    fn bad_inputs_are_invalid_input() {
+        let errors = [
+            format_timestamp(0, "%Y".into(), "Mars/Olympus_Mons".into()).unwrap_err(),
+            format_timestamp(0, "%Q".into(), "UTC".into()).unwrap_err(),
+            format_timestamp(i64::MAX, "%Y".into(), "UTC".into()).unwrap_err(),
+            parse_timestamp("yesterday".into(), "%Y-%m-%d".into()).unwrap_err(),
+        ];
+        for err in errors {
+            assert_eq!(err.code(), "invalid_input", "{err}");
+        }
+    }
+
+    #[test]
+    fn now_is_after_2024() {
+        assert!(now_unix_ms() > NY_SPRING_FORWARD);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::api::StreamSink;
+use crate::error::PortalisError;
+use crate::runtime;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchEventKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// A change to one path, standing for every raw event it saw within the debounce window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchEvent {
+    pub path: String,
+    pub kind: WatchEventKind,
+}
+
+/// Streams changes under `path` (a file, or a directory and, with `recursive`, its subtree).
+///
+/// The first raw event opens a `debounce_ms` window; everything that arrives within it
+/// is merged into one event per path, emitted when the window closes. A file created
+/// and then written reports `Created`; one deleted and recreated reports `Modified`.
+/// Runs until the Dart stream is closed. Not available on web.
+pub async fn watch_path(
+    path: String,
+    recursive: bool,
+    debounce_ms: u64,
+    sink: StreamSink<WatchEvent>,
+) -> Result<(), PortalisError> {
+    runtime::run(async move {
+        sys::watch(&path, recursive, debounce_ms, |event| {
+            sink.add(event).is_ok()
+        })
+        .await
+    })
+    .await
+}
+
+#[cfg(not(target_family = "wasm"))]
+use io as sys;
+#[cfg(target_family = "wasm")]
+use web as sys;
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use notify::event::{EventKind, ModifyKind, RenameMode};
+    use notify::{RecursiveMode, Watcher};
+
+    use super::{WatchEvent, WatchEventKind};
+    use crate::error::PortalisError;
+
+    /// Runs until `emit` returns `false` (the Dart stream was closed) or the watcher fails.
+    pub(super) async fn watch(
+        path: &str,
+        recursive: bool,
+        debounce_ms: u64,
+        mut emit: impl FnMut(WatchEvent) -> bool,
+    ) -> Result<(), PortalisError> {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        })?;
+        let mode = if recursive {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        watcher.watch(Path::new(path), mode)?;
+
+        // First-seen order, so a burst touching several files is reported as it happened.
+        let mut pending: Vec<WatchEvent> = Vec::new();
+        let mut deadline = tokio::time::Instant::now();
+        loop {
+            let received = if pending.is_empty() {
+                rx.recv().await
+            } else {
+                match tokio::time::timeout_at(deadline, rx.recv()).await {
+                    Ok(event) => event,
+                    Err(_) => {
+                        for event in pending.drain(..) {
+                            if !emit(event) {
+                                return Ok(());
+                            }
+                        }
+                        continue;
+                    }
+                }
+            };
+            let Some(event) = received else {
+                return Ok(());
+            };
+            let event: notify::Event = event?;
+            if pending.is_empty() {
+                deadline = tokio::time::Instant::now() + Duration::from_millis(debounce_ms);
+            }
+            for (changed, kind) in changes(&event) {
+                let changed = changed.to_string_lossy().into_owned();
+                match pending.iter_mut().find(|e| e.path == changed) {
+                    Some(existing) => existing.kind = merge(existing.kind, kind),
+                    None => pending.push(WatchEvent {
+                        path: changed,
+                        kind,
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Folds a later change to the same path into the one already pending.
+    pub(super) fn merge(earlier: WatchEventKind, later: WatchEventKind) -> WatchEventKind {
+        use WatchEventKind::*;
+        match (earlier, later) {
+            (Created, Modified) => Created,
+            (Removed, Created | Modified) => Modified,
+            (_, later) => later,
+        }
+    }
+
+    /// What `event` did to each of its paths; reads and metadata-only access are dropped.
+    fn changes(event: &notify::Event) -> Vec<(&Path, WatchEventKind)> {
+        use WatchEventKind::*;
+        let kind = match event.kind {
+            EventKind::Create(_) => Created,
+            EventKind::Remove(_) => Removed,
+            // A rename reports the old name going away and the new one appearing.
+            EventKind::Modify(ModifyKind::Name(RenameMode::From)) => Removed,
+            EventKind::Modify(ModifyKind::Name(RenameMode::To)) => Created,
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                return event
+                    .paths
+                    .iter()
+                    .zip([Removed, Created])
+                    .map(|(path, kind)| (path.as_path(), kind))
+                    .collect();
+            }
+            EventKind::Modify(_) | EventKind::Any | EventKind::Other => Modified,
+            EventKind::Access(_) => return Vec::new(),
+        };
+        event
+            .paths
+            .iter()
+            .map(|path| (path.as_path(), kind))
+            .collect()
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod web {
+    use super::WatchEvent;
+    use crate::error::PortalisError;
+
+    pub(super) async fn watch(
+        _path: &str,
+        _recursive: bool,
+        _debounce_ms: u64,
+        _emit: impl FnMut(WatchEvent) -> bool,
+    ) -> Result<(), PortalisError> {
+        Err(PortalisError::unsupported_on_web("file watching"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Starts watching `path`, forwarding events to the returned channel.
+    async fn start(
+        path: &std::path::Path,
+        recursive: bool,
+        debounce_ms: u64,
+    ) -> (
+        tokio::task::JoinHandle<Result<(), PortalisError>>,
+        tokio::sync::mpsc::UnboundedReceiver<WatchEvent>,
+    ) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let path = path.to_string_lossy().into_owned();
+        let task = tokio::spawn(async move {
+            sys::watch(&path, recursive, debounce_ms, |e| tx.send(e).is_ok()).await
+        });
+        // Give the watcher a moment to register before the test starts writing.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        (task, rx)
+    }
+
+    #[tokio::test]
+    async fn burst_of_writes_is_coalesced_into_one_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("notes.txt");
+        std::fs::write(&file, "0").unwrap();
+        let (task, mut rx) = start(dir.path(), false, 500).await;
+
+        for i in 0..20 {
+            std::fs::write(&file, i.to_string()).unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("coalesced event")
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(800)).await;
+
+        assert_eq!(event.kind, WatchEventKind::Modified);
+        assert!(event.path.ends_with("notes.txt"), "{}", event.path);
+        assert!(rx.try_recv().is_err(), "expected a single event");
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn recursive_watch_sees_nested_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("a/b");
+        std::fs::create_dir_all(&nested).unwrap();
+        let (task, mut rx) = start(dir.path(), true, 100).await;
+
+        std::fs::write(nested.join("new.txt"), "x").unwrap();
+        let event = loop {
+            let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+                .await
+                .expect("nested event")
+                .unwrap();
+            if event.path.ends_with("new.txt") {
+                break event;
+            }
+        };
+
+        assert_eq!(event.kind, WatchEventKind::Created);
+        task.abort();
+    }
+
+    #[test]
+    fn later_changes_fold_into_pending_ones() {
+        use WatchEventKind::*;
+        assert_eq!(sys::merge(Created, Modified), Created);
+        assert_eq!(sys::merge(Removed, Created), Modified);
+        assert_eq!(sys::merge(Modified, Removed), Removed);
+        assert_eq!(sys::merge(Created, Removed), Removed);
+    }
+}